@@ -1,25 +1,34 @@
 //! checks for attributes
 
+use crate::utils::report;
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::macros::{is_panic, macro_backtrace};
 use clippy_utils::msrvs;
 use clippy_utils::source::{first_line_of_span, is_present_in_source, snippet_opt, without_block_comments};
 use clippy_utils::{extract_msrv_attr, meets_msrv};
 use if_chain::if_chain;
+use itertools::Itertools;
 use rustc_ast::{AttrKind, AttrStyle, Attribute, Lit, LitKind, MetaItemKind, NestedMetaItem};
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
+use rustc_hir::def_id::LOCAL_CRATE;
 use rustc_hir::{
     Block, Expr, ExprKind, ImplItem, ImplItemKind, Item, ItemKind, StmtKind, TraitFn, TraitItem, TraitItemKind,
+    CRATE_HIR_ID,
 };
-use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintContext};
+use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, Level, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
 use rustc_semver::RustcVersion;
-use rustc_session::{declare_lint_pass, declare_tool_lint, impl_lint_pass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Span;
 use rustc_span::sym;
 use rustc_span::symbol::Symbol;
 use semver::Version;
+use serde::Serialize;
+use std::env;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 static UNIX_SYSTEMS: &[&str] = &[
     "android",
@@ -294,12 +303,74 @@ declare_clippy_lint! {
     "ensures that all `allow` and `expect` attributes have a reason"
 }
 
-declare_lint_pass!(Attributes => [
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks `allow` and `expect` attributes against the lint suppression policy configured in
+    /// `clippy.toml`. The `reason` of the attribute is read as a list of `key=value` fields:
+    /// * the date in the `until` field (configurable with `allow-reason-expiry-key`) must not be
+    ///   in the past,
+    /// * all keys listed in `allow-reason-required-keys`, e.g. `ticket`, must be present,
+    /// * lints listed in `non-suppressible-lints` must not be suppressed at all.
+    ///
+    /// (This requires the `lint_reasons` feature)
+    ///
+    /// ### Why is this bad?
+    /// Suppressed lints tend to stay suppressed long after the reason for the suppression is gone.
+    /// Attaching an expiry date or a ticket reference to every suppression keeps them visible.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// // clippy.toml: allow-reason-required-keys = ["ticket"]
+    /// #![feature(lint_reasons)]
+    ///
+    /// #[allow(clippy::some_lint, reason = "until=2020-01-01")]
+    /// fn foo() {}
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// #![feature(lint_reasons)]
+    ///
+    /// #[allow(clippy::some_lint, reason = "false positive, until=2030-01-01 ticket=ABC-123")]
+    /// fn foo() {}
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub DISALLOWED_LINT_SUPPRESSIONS,
+    restriction,
+    "`allow` and `expect` attributes violating the configured suppression policy"
+}
+
+pub struct Attributes {
+    allow_reason_expiry_key: String,
+    allow_reason_required_keys: Vec<String>,
+    non_suppressible_lints: FxHashSet<String>,
+    /// The date expiring lint suppressions are compared to, as `(year, month, day)`.
+    today: (u64, u64, u64),
+}
+
+impl Attributes {
+    pub fn new(
+        allow_reason_expiry_key: String,
+        allow_reason_required_keys: Vec<String>,
+        non_suppressible_lints: FxHashSet<String>,
+        today: (u64, u64, u64),
+    ) -> Self {
+        Self {
+            allow_reason_expiry_key,
+            allow_reason_required_keys,
+            non_suppressible_lints,
+            today,
+        }
+    }
+}
+
+impl_lint_pass!(Attributes => [
     ALLOW_ATTRIBUTES_WITHOUT_REASON,
     INLINE_ALWAYS,
     DEPRECATED_SEMVER,
     USELESS_ATTRIBUTE,
     BLANKET_CLIPPY_RESTRICTION_LINTS,
+    DISALLOWED_LINT_SUPPRESSIONS,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Attributes {
@@ -311,6 +382,7 @@ impl<'tcx> LateLintPass<'tcx> for Attributes {
                 }
                 if matches!(ident.name, sym::allow | sym::expect) {
                     check_lint_reason(cx, ident.name, items, attr);
+                    self.check_suppression_policy(cx, items, attr);
                 }
                 if items.is_empty() || !attr.has_name(sym::deprecated) {
                     return;
@@ -417,6 +489,137 @@ impl<'tcx> LateLintPass<'tcx> for Attributes {
     }
 }
 
+/// A single lint suppression, as listed by `cargo clippy --report-suppressions`.
+#[derive(Serialize)]
+struct Suppression {
+    /// Where the suppression comes from: `attribute`, `clippy.toml` or `command line`.
+    source: &'static str,
+    lint: String,
+    level: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    /// The path of the item the attribute is attached to, absent for crate level attributes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    item: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+    /// The `clippy.toml` option causing the suppression.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    option: Option<String>,
+}
+
+#[derive(Serialize)]
+struct SuppressionInventory<'a> {
+    #[serde(rename = "crate")]
+    krate: &'a str,
+    suppressions: &'a [Suppression],
+}
+
+/// Collects every suppression of a Clippy lint or lint group in the crate and writes them as JSON
+/// to the report file once the crate has been checked. This pass is only registered by
+/// `--report-suppressions`.
+pub struct SuppressionReport {
+    path: PathBuf,
+    /// `clippy.toml` options which are set to suppress a lint, along with the suppressed lint.
+    conf_suppressions: Vec<(String, String)>,
+    suppressions: Vec<Suppression>,
+}
+
+impl SuppressionReport {
+    pub fn new(path: PathBuf, conf_suppressions: Vec<(String, String)>) -> Self {
+        Self {
+            path,
+            conf_suppressions,
+            suppressions: Vec::new(),
+        }
+    }
+}
+
+impl_lint_pass!(SuppressionReport => []);
+
+impl<'tcx> LateLintPass<'tcx> for SuppressionReport {
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute]) {
+        // `check_attribute` runs once the whole crate has been walked, so the owner of each attribute
+        // is only known here.
+        let hir_id = cx.last_node_with_lint_attrs;
+        let item = (hir_id != CRATE_HIR_ID).then(|| {
+            let def_id = cx
+                .tcx
+                .hir()
+                .opt_local_def_id(hir_id)
+                .unwrap_or_else(|| cx.tcx.hir().get_parent_item(hir_id));
+            cx.tcx.def_path_str(def_id.to_def_id())
+        });
+
+        for attr in attrs {
+            let level = match attr.ident() {
+                Some(ident) if ident.name == sym::allow => "allow",
+                Some(ident) if ident.name == sym::expect => "expect",
+                _ => continue,
+            };
+            let Some(items) = attr.meta_item_list() else { continue };
+            let reason = items
+                .iter()
+                .filter_map(NestedMetaItem::meta_item)
+                .find(|item| item.has_name(sym::reason))
+                .and_then(rustc_ast::MetaItem::value_str)
+                .map(|reason| reason.to_string());
+            let loc = cx.sess().source_map().lookup_char_pos(attr.span.lo());
+
+            for lint_name in items.iter().filter_map(extract_clippy_lint) {
+                self.suppressions.push(Suppression {
+                    source: "attribute",
+                    lint: format!("clippy::{}", lint_name),
+                    level,
+                    file: Some(loc.file.name.prefer_remapped().to_string()),
+                    line: Some(loc.line),
+                    item: item.clone(),
+                    reason: reason.clone(),
+                    option: None,
+                });
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (option, lint) in &self.conf_suppressions {
+            self.suppressions.push(Suppression {
+                source: "clippy.toml",
+                lint: format!("clippy::{}", lint),
+                level: "allow",
+                file: None,
+                line: None,
+                item: None,
+                reason: None,
+                option: Some(option.clone()),
+            });
+        }
+        for (lint, level) in &cx.sess().opts.lint_opts {
+            if *level == Level::Allow && lint.starts_with("clippy::") {
+                self.suppressions.push(Suppression {
+                    source: "command line",
+                    lint: lint.clone(),
+                    level: "allow",
+                    file: None,
+                    line: None,
+                    item: None,
+                    reason: None,
+                    option: None,
+                });
+            }
+        }
+
+        let crate_name = cx.tcx.crate_name(LOCAL_CRATE);
+        let inventory = SuppressionInventory {
+            krate: crate_name.as_str(),
+            suppressions: &self.suppressions,
+        };
+        report::append(cx, &self.path, &inventory);
+    }
+}
+
 /// Returns the lint name if it is clippy lint.
 fn extract_clippy_lint(lint: &NestedMetaItem) -> Option<Symbol> {
     if_chain! {
@@ -473,6 +676,134 @@ fn check_lint_reason(cx: &LateContext<'_>, name: Symbol, items: &[NestedMetaItem
     );
 }
 
+impl Attributes {
+    fn check_suppression_policy(&self, cx: &LateContext<'_>, items: &[NestedMetaItem], attr: &'_ Attribute) {
+        let mut reason = None;
+        for item in items {
+            let Some(meta_item) = item.meta_item() else { continue };
+            if meta_item.has_name(sym::reason) {
+                reason = meta_item.value_str().map(|value| (value, item.span()));
+                continue;
+            }
+            let path = meta_item
+                .path
+                .segments
+                .iter()
+                .map(|segment| segment.ident.as_str())
+                .join("::");
+            if self.non_suppressible_lints.contains(&path) {
+                span_lint(
+                    cx,
+                    DISALLOWED_LINT_SUPPRESSIONS,
+                    item.span(),
+                    &format!("`{}` must not be suppressed", path),
+                );
+            }
+        }
+
+        let (fields, span) = match &reason {
+            Some((reason, span)) => (parse_reason_fields(reason.as_str()), *span),
+            None => (Vec::new(), attr.span),
+        };
+
+        for key in &self.allow_reason_required_keys {
+            if !fields.iter().any(|(k, _)| *k == key.as_str()) {
+                span_lint_and_help(
+                    cx,
+                    DISALLOWED_LINT_SUPPRESSIONS,
+                    span,
+                    &format!("reason of this lint suppression is missing the required `{}` key", key),
+                    None,
+                    &format!("add `{}=...` to the reason", key),
+                );
+            }
+        }
+
+        if let Some((_, date)) = fields.iter().find(|(k, _)| *k == self.allow_reason_expiry_key) {
+            match parse_date(date) {
+                Some(until) if until < self.today => span_lint_and_help(
+                    cx,
+                    DISALLOWED_LINT_SUPPRESSIONS,
+                    span,
+                    &format!("this lint suppression expired on `{}`", date),
+                    None,
+                    &format!(
+                        "remove the attribute or update the `{}` date",
+                        self.allow_reason_expiry_key
+                    ),
+                ),
+                Some(_) => {},
+                None => span_lint_and_help(
+                    cx,
+                    DISALLOWED_LINT_SUPPRESSIONS,
+                    span,
+                    &format!("`{}` is not a valid `{}` date", date, self.allow_reason_expiry_key),
+                    None,
+                    "use a valid date in the `YYYY-MM-DD` format",
+                ),
+            }
+        }
+    }
+}
+
+/// Splits a lint reason into its `key=value` fields. Any other text is ignored.
+fn parse_reason_fields(reason: &str) -> Vec<(&str, &str)> {
+    reason
+        .split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+        .filter_map(|field| field.split_once('='))
+        .collect()
+}
+
+/// Parses a `YYYY-MM-DD` date.
+fn parse_date(date: &str) -> Option<(u64, u64, u64)> {
+    let mut parts = date.splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+    (1..=days_in_month(year, month)?)
+        .contains(&day)
+        .then_some((year, month, day))
+}
+
+fn days_in_month(year: u64, month: u64) -> Option<u64> {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => Some(29),
+        2 => Some(28),
+        _ => None,
+    }
+}
+
+/// Returns the current (UTC) date as `(year, month, day)`. The UI tests set the internal
+/// `__CLIPPY_INTERNAL_TODAY` environment variable to a fixed `YYYY-MM-DD` date instead.
+pub fn today() -> (u64, u64, u64) {
+    if let Some(today) = env::var("__CLIPPY_INTERNAL_TODAY")
+        .ok()
+        .and_then(|date| parse_date(&date))
+    {
+        return today;
+    }
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() / 86_400);
+    // See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
 fn is_relevant_item(cx: &LateContext<'_>, item: &Item<'_>) -> bool {
     if let ItemKind::Fn(_, _, eid) = item.kind {
         is_relevant_expr(cx, cx.tcx.typeck_body(eid), &cx.tcx.hir().body(eid).value)
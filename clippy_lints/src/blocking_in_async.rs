@@ -0,0 +1,238 @@
+use crate::utils::conf::BlockingFunction;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{def_path_res, fn_def_id, match_def_path, path_def_id};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::{DefId, DefIdSet};
+use rustc_hir::intravisit::{walk_expr, FnKind, Visitor};
+use rustc_hir::{AsyncGeneratorKind, Body, Closure, Expr, ExprKind, FnDecl, GeneratorKind, HirId, IsAsync, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::nested_filter;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to blocking functions, like `std::thread::sleep` or
+    /// `std::fs::read`, inside of `async` functions, blocks and closures, and
+    /// inside of closures passed to a spawn function.
+    ///
+    /// ### Why is this bad?
+    /// Async executors run many tasks on a few threads. A blocking call stalls
+    /// the whole thread, and every other task scheduled on it.
+    ///
+    /// ### Configuration
+    /// The blocking functions can be configured with `blocking-functions`, and
+    /// the spawn functions with `future-spawn-functions`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// async fn load() -> std::io::Result<String> {
+    ///     std::fs::read_to_string("config.toml")
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// async fn load() -> std::io::Result<String> {
+    ///     tokio::fs::read_to_string("config.toml").await
+    /// }
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub BLOCKING_IN_ASYNC,
+    pedantic,
+    "calls to blocking functions in an async context"
+}
+
+/// Functions running their argument on a thread where blocking is fine.
+const OFFLOAD_FUNCTIONS: &[&str] = &[
+    "async_std::task::spawn_blocking",
+    "std::thread::spawn",
+    "std::thread::Builder::spawn",
+    "std::thread::Scope::spawn",
+    "tokio::task::block_in_place",
+    "tokio::task::spawn_blocking",
+];
+
+pub struct BlockingInAsync {
+    blocking_functions: Vec<BlockingFunction>,
+    spawn_functions: Vec<String>,
+    /// Maps the resolved blocking functions to their index in `blocking_functions`.
+    blocking_def_ids: FxHashMap<DefId, usize>,
+    spawn_def_ids: DefIdSet,
+    offload_def_ids: DefIdSet,
+}
+
+impl BlockingInAsync {
+    #[must_use]
+    pub fn new(blocking_functions: Vec<BlockingFunction>, spawn_functions: Vec<String>) -> Self {
+        Self {
+            blocking_functions,
+            spawn_functions,
+            blocking_def_ids: FxHashMap::default(),
+            spawn_def_ids: DefIdSet::default(),
+            offload_def_ids: DefIdSet::default(),
+        }
+    }
+
+    fn check_async_body<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        BlockingCallVisitor { cx, lint: self }.visit_expr(expr);
+    }
+
+    fn blocking_function(&self, def_id: DefId) -> Option<&BlockingFunction> {
+        let idx = *self.blocking_def_ids.get(&def_id)?;
+        Some(&self.blocking_functions[idx])
+    }
+
+    fn is_spawn_function(&self, cx: &LateContext<'_>, callee: &Expr<'_>) -> bool {
+        path_def_id(cx, callee).map_or(false, |def_id| self.spawn_def_ids.contains(&def_id))
+    }
+}
+
+impl_lint_pass!(BlockingInAsync => [BLOCKING_IN_ASYNC]);
+
+fn resolve(cx: &LateContext<'_>, path: &str) -> Option<DefId> {
+    let segs: Vec<_> = path.split("::").collect();
+    if let Res::Def(_, id) = def_path_res(cx, &segs) {
+        Some(id)
+    } else {
+        None
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for BlockingInAsync {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        let mut unresolved_blocking_functions = Vec::new();
+        for (idx, function) in self.blocking_functions.iter().enumerate() {
+            if let Some(id) = resolve(cx, function.path()) {
+                self.blocking_def_ids.insert(id, idx);
+            } else {
+                unresolved_blocking_functions.push(idx);
+            }
+        }
+        let mut unresolved_spawn_functions = Vec::new();
+        for path in &self.spawn_functions {
+            if let Some(id) = resolve(cx, path) {
+                self.spawn_def_ids.insert(id);
+            } else {
+                unresolved_spawn_functions.push(path);
+            }
+        }
+        self.offload_def_ids
+            .extend(OFFLOAD_FUNCTIONS.iter().filter_map(|path| resolve(cx, path)));
+
+        // `def_path_res` doesn't resolve the functions of the current crate, look them up once here.
+        if !unresolved_blocking_functions.is_empty() || !unresolved_spawn_functions.is_empty() {
+            for def_id in cx.tcx.hir().body_owners() {
+                let def_id = def_id.to_def_id();
+                let matches = |path: &str| match_def_path(cx, def_id, &path.split("::").collect::<Vec<_>>());
+                if let Some(&idx) = unresolved_blocking_functions
+                    .iter()
+                    .find(|&&idx| matches(self.blocking_functions[idx].path()))
+                {
+                    self.blocking_def_ids.insert(def_id, idx);
+                }
+                if unresolved_spawn_functions.iter().any(|path| matches(path)) {
+                    self.spawn_def_ids.insert(def_id);
+                }
+            }
+        }
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        fn_kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        _: Span,
+        _: HirId,
+    ) {
+        // The body of an async function is desugared to a call wrapping the generator closure.
+        if fn_kind.asyncness() == IsAsync::Async
+            && let ExprKind::Call(_, [Expr { kind: ExprKind::Closure(&Closure { body, .. }), .. }]) = body.value.kind
+        {
+            self.check_async_body(cx, &cx.tcx.hir().body(body).value);
+        }
+    }
+
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &'tcx Body<'tcx>) {
+        // The bodies of async functions are checked in `check_fn`.
+        if let Some(GeneratorKind::Async(AsyncGeneratorKind::Block | AsyncGeneratorKind::Closure)) = body.generator_kind
+        {
+            self.check_async_body(cx, &body.value);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        // Closures passed to a spawn function inside of an async context are already checked with the
+        // enclosing body.
+        if let ExprKind::Call(callee, args) = expr.kind
+            && self.is_spawn_function(cx, callee)
+            && !in_async_body(cx, expr.hir_id)
+        {
+            for arg in args {
+                if let ExprKind::Closure(&Closure { body, .. }) = arg.kind {
+                    let body = cx.tcx.hir().body(body);
+                    if body.generator_kind.is_none() {
+                        self.check_async_body(cx, &body.value);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn in_async_body(cx: &LateContext<'_>, hir_id: HirId) -> bool {
+    cx.tcx.hir().parent_iter(hir_id).any(|(_, node)| {
+        matches!(node, Node::Expr(Expr { kind: ExprKind::Closure(&Closure { body, .. }), .. })
+            if cx.tcx.hir().body(body).generator_kind.is_some())
+    })
+}
+
+struct BlockingCallVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    lint: &'a BlockingInAsync,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for BlockingCallVisitor<'a, 'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            // Nested async blocks and closures are checked on their own.
+            ExprKind::Closure(&Closure { body, .. }) if self.cx.tcx.hir().body(body).generator_kind.is_some() => {
+                return;
+            },
+            ExprKind::Call(..) | ExprKind::MethodCall(..) if !expr.span.from_expansion() => {
+                if let Some(def_id) = fn_def_id(self.cx, expr) {
+                    if self.lint.offload_def_ids.contains(&def_id) {
+                        return;
+                    }
+                    if let Some(function) = self.lint.blocking_function(def_id) {
+                        let help = match function.alternative() {
+                            Some(alternative) => format!("use `{}` instead", alternative),
+                            None => {
+                                "run it on a thread where blocking is fine, e.g. with `tokio::task::spawn_blocking`"
+                                    .to_owned()
+                            },
+                        };
+                        span_lint_and_help(
+                            self.cx,
+                            BLOCKING_IN_ASYNC,
+                            expr.span,
+                            &format!("call to blocking function `{}` in an async context", function.path()),
+                            None,
+                            &help,
+                        );
+                    }
+                }
+            },
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+}
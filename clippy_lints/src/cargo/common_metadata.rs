@@ -1,47 +1,121 @@
 //! lint on missing cargo common metadata
 
-use cargo_metadata::Metadata;
+use cargo_metadata::{Metadata, Package};
 use clippy_utils::diagnostics::span_lint;
 use rustc_lint::LateContext;
 use rustc_span::source_map::DUMMY_SP;
+use rustc_span::Span;
 
-use super::CARGO_COMMON_METADATA;
+use super::manifest::Manifest;
+use super::{MetadataPolicy, CARGO_COMMON_METADATA};
 
-pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata, ignore_publish: bool) {
+pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata, ignore_publish: bool, policy: &MetadataPolicy) {
+    let root_path = metadata.workspace_root.join("Cargo.toml");
+    let mut root = None;
     for package in &metadata.packages {
         // only run the lint if publish is `None` (`publish = true` or skipped entirely)
         // or if the vector isn't empty (`publish = ["something"]`)
         if package.publish.as_ref().filter(|publish| publish.is_empty()).is_none() || ignore_publish {
-            if is_empty_str(&package.description) {
-                missing_warning(cx, package, "package.description");
-            }
-
-            if is_empty_str(&package.license) && is_empty_str(&package.license_file) {
-                missing_warning(cx, package, "either package.license or package.license_file");
-            }
-
-            if is_empty_str(&package.repository) {
-                missing_warning(cx, package, "package.repository");
-            }
+            let manifest = Manifest::load(cx, package.manifest_path.as_std_path());
+            let header_span = manifest
+                .as_ref()
+                .and_then(|manifest| manifest.header_span("package"))
+                .unwrap_or(DUMMY_SP);
+            let entry = |field: &str| manifest.as_ref().and_then(|manifest| manifest.entry("package", field));
+            // Fields like `edition.workspace = true` take the value of `[workspace.package]`.
+            let mut field = |field: &str| {
+                let value = entry(field)?.value;
+                if !value.is_inherited() {
+                    return Some(value.to_toml());
+                }
+                let root = if package.manifest_path == root_path {
+                    manifest.as_ref()
+                } else {
+                    root.get_or_insert_with(|| Manifest::load(cx, root_path.as_std_path()))
+                        .as_ref()
+                };
+                Some(root?.entry("workspace.package", field)?.value.to_toml())
+            };
 
-            if is_empty_str(&package.readme) {
-                missing_warning(cx, package, "package.readme");
+            for required in &policy.required_fields {
+                if !is_present(package, required, field(required).as_ref()) {
+                    let name = if required == "license" {
+                        "either package.license or package.license_file".to_owned()
+                    } else {
+                        format!("package.{}", required)
+                    };
+                    missing_warning(cx, header_span, package, &name);
+                }
             }
 
-            if is_empty_vec(&package.keywords) {
-                missing_warning(cx, package, "package.keywords");
+            for forbidden in &policy.forbidden_fields {
+                if field(forbidden).is_some() {
+                    span_lint(
+                        cx,
+                        CARGO_COMMON_METADATA,
+                        entry(forbidden).map_or(header_span, |entry| entry.key_span),
+                        &format!(
+                            "package `{}` has forbidden `package.{}` metadata",
+                            package.name, forbidden
+                        ),
+                    );
+                }
             }
 
-            if is_empty_vec(&package.categories) {
-                missing_warning(cx, package, "package.categories");
+            for constraint in &policy.field_values {
+                match field(&constraint.field) {
+                    Some(value) if value == constraint.value => {},
+                    Some(value) => span_lint(
+                        cx,
+                        CARGO_COMMON_METADATA,
+                        entry(&constraint.field).map_or(header_span, |entry| {
+                            if entry.value.is_inherited() {
+                                entry.key_span.to(entry.value_span)
+                            } else {
+                                entry.value_span
+                            }
+                        }),
+                        &format!(
+                            "package `{}` has `package.{}` set to `{}`, but it must be `{}`",
+                            package.name, constraint.field, value, constraint.value
+                        ),
+                    ),
+                    None => span_lint(
+                        cx,
+                        CARGO_COMMON_METADATA,
+                        header_span,
+                        &format!(
+                            "package `{}` is missing `package.{}` metadata, which must be `{}`",
+                            package.name, constraint.field, constraint.value
+                        ),
+                    ),
+                }
             }
         }
     }
 }
 
-fn missing_warning(cx: &LateContext<'_>, package: &cargo_metadata::Package, field: &str) {
+/// Checks if the field is set to a non-empty value. The common fields are read from the
+/// metadata, which also includes defaults like a `README.md` file.
+fn is_present(package: &Package, field: &str, value: Option<&toml::Value>) -> bool {
+    match field {
+        "description" => !is_empty_str(&package.description),
+        "license" => !is_empty_str(&package.license) || !is_empty_str(&package.license_file),
+        "repository" => !is_empty_str(&package.repository),
+        "readme" => !is_empty_str(&package.readme),
+        "keywords" => !is_empty_vec(&package.keywords),
+        "categories" => !is_empty_vec(&package.categories),
+        _ => value.map_or(false, |value| match value {
+            toml::Value::String(s) => !s.is_empty(),
+            toml::Value::Array(values) => !values.is_empty(),
+            _ => true,
+        }),
+    }
+}
+
+fn missing_warning(cx: &LateContext<'_>, span: Span, package: &cargo_metadata::Package, field: &str) {
     let message = format!("package `{}` is missing `{}` metadata", package.name, field);
-    span_lint(cx, CARGO_COMMON_METADATA, DUMMY_SP, &message);
+    span_lint(cx, CARGO_COMMON_METADATA, span, &message);
 }
 
 fn is_empty_str<T: AsRef<std::ffi::OsStr>>(value: &Option<T>) -> bool {
@@ -0,0 +1,187 @@
+//! lint on dependencies which violate the license and source policy of the configuration
+
+use cargo_metadata::{Metadata, Package, PackageId, Resolve};
+use clippy_utils::diagnostics::span_lint_and_then;
+use if_chain::if_chain;
+use itertools::Itertools;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_lint::LateContext;
+use rustc_span::source_map::DUMMY_SP;
+use std::iter::Peekable;
+
+use super::manifest::Manifest;
+use super::{DependencyPolicy, DISALLOWED_DEPENDENCIES};
+
+const CRATES_IO_INDEX: &str = "https://github.com/rust-lang/crates.io-index";
+
+pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata, policy: &DependencyPolicy) {
+    if policy.is_empty() {
+        return;
+    }
+    let local_name = cx.tcx.crate_name(LOCAL_CRATE);
+
+    if_chain! {
+        if let Some(resolve) = &metadata.resolve;
+        // `-` in package names is replaced with `_` in crate names
+        if let Some(local_package) = metadata
+            .packages
+            .iter()
+            .find(|p| p.name.replace('-', "_") == local_name.as_str());
+        then {
+            let manifest = Manifest::load(cx, local_package.manifest_path.as_std_path());
+            let header_span = manifest
+                .as_ref()
+                .and_then(|manifest| manifest.header_span("dependencies"))
+                .unwrap_or(DUMMY_SP);
+
+            let deps = dependencies(resolve, &local_package.id);
+            for package in metadata.packages.iter().filter(|package| deps.contains(&package.id)) {
+                if metadata.workspace_members.contains(&package.id) {
+                    continue;
+                }
+                let Some(msg) = violation(policy, package) else { continue };
+
+                let direct_span = local_package
+                    .dependencies
+                    .iter()
+                    .filter(|dep| dep.name == package.name && dep.req.matches(&package.version))
+                    .find_map(|dep| manifest.as_ref()?.dependency_span(dep));
+                let span = direct_span.unwrap_or(header_span);
+                span_lint_and_then(cx, DISALLOWED_DEPENDENCIES, span, &msg, |diag| {
+                    if direct_span.is_none() {
+                        let dependents = resolve
+                            .nodes
+                            .iter()
+                            .filter(|node| deps.contains(&node.id) && node.deps.iter().any(|dep| dep.pkg == package.id))
+                            .filter_map(|node| metadata.packages.iter().find(|p| p.id == node.id))
+                            .map(|p| format!("`{}` {}", p.name, p.version))
+                            .join(", ");
+                        diag.note(&format!("`{}` is required by {}", package.name, dependents));
+                    }
+                });
+            }
+        }
+    }
+}
+
+/// Returns the packages the local package depends on, directly or through other dependencies.
+fn dependencies<'a>(resolve: &'a Resolve, local_id: &'a PackageId) -> FxHashSet<&'a PackageId> {
+    let mut seen = FxHashSet::default();
+    let mut stack = vec![local_id];
+    while let Some(id) = stack.pop() {
+        if seen.insert(id) {
+            if let Some(node) = resolve.nodes.iter().find(|node| node.id == *id) {
+                stack.extend(node.deps.iter().map(|dep| &dep.pkg));
+            }
+        }
+    }
+    seen.remove(local_id);
+    seen
+}
+
+/// Returns the message for the first part of the policy the package violates.
+fn violation(policy: &DependencyPolicy, package: &Package) -> Option<String> {
+    if !policy.allowed_licenses.is_empty() || !policy.denied_licenses.is_empty() {
+        match &package.license {
+            Some(license) if !is_license_allowed(policy, license) => {
+                return Some(format!(
+                    "`{}` {} is licensed under `{}`, which is not allowed",
+                    package.name, package.version, license
+                ));
+            },
+            // A custom license in a `license-file` can't be checked.
+            None if package.license_file.is_none() && !policy.allowed_licenses.is_empty() => {
+                return Some(format!(
+                    "`{}` {} has no license expression",
+                    package.name, package.version
+                ));
+            },
+            _ => {},
+        }
+    }
+
+    let source = package.source.as_ref()?;
+    if source.repr.starts_with("git+") {
+        if !policy.allow_git_dependencies {
+            return Some(format!(
+                "`{}` {} is a git dependency: `{}`",
+                package.name, package.version, source.repr
+            ));
+        }
+    } else if !policy.allowed_registries.is_empty() {
+        let url = source.repr.split_once('+').map_or(source.repr.as_str(), |(_, url)| url);
+        let is_allowed = policy
+            .allowed_registries
+            .iter()
+            .any(|registry| registry == url || (registry == "crates-io" && url == CRATES_IO_INDEX));
+        if !is_allowed {
+            return Some(format!(
+                "`{}` {} comes from a registry which is not allowed: `{}`",
+                package.name, package.version, url
+            ));
+        }
+    }
+    None
+}
+
+/// Checks if the dependency can be used under one of the allowed licenses, and none of the denied
+/// ones.
+fn is_license_allowed(policy: &DependencyPolicy, expr: &str) -> bool {
+    let is_allowed = |license: &str| {
+        let license = license.trim_end_matches('+');
+        (policy.allowed_licenses.is_empty() || policy.allowed_licenses.iter().any(|l| l == license))
+            && !policy.denied_licenses.iter().any(|l| l == license)
+    };
+    // `MIT/Apache-2.0` is an old way of writing `MIT OR Apache-2.0`
+    let expr = expr.replace('/', " OR ").replace('(', " ( ").replace(')', " ) ");
+    let mut parser = LicenseParser {
+        tokens: expr.split_whitespace().peekable(),
+        is_allowed,
+    };
+    match parser.or() {
+        Some(allowed) => allowed && parser.tokens.next().is_none(),
+        None => false,
+    }
+}
+
+/// Evaluates a SPDX license expression, with `OR` having a lower precedence than `AND`.
+struct LicenseParser<I: Iterator, F> {
+    tokens: Peekable<I>,
+    is_allowed: F,
+}
+
+impl<'a, I: Iterator<Item = &'a str>, F: Fn(&str) -> bool> LicenseParser<I, F> {
+    fn or(&mut self) -> Option<bool> {
+        let mut allowed = self.and()?;
+        while self.tokens.next_if_eq(&"OR").is_some() {
+            allowed |= self.and()?;
+        }
+        Some(allowed)
+    }
+
+    fn and(&mut self) -> Option<bool> {
+        let mut allowed = self.license()?;
+        while self.tokens.next_if_eq(&"AND").is_some() {
+            allowed &= self.license()?;
+        }
+        Some(allowed)
+    }
+
+    fn license(&mut self) -> Option<bool> {
+        let allowed = match self.tokens.next()? {
+            "(" => {
+                let allowed = self.or()?;
+                self.tokens.next_if_eq(&")")?;
+                allowed
+            },
+            ")" | "OR" | "AND" | "WITH" => return None,
+            license => (self.is_allowed)(license),
+        };
+        // The exception only grants additional permissions
+        if self.tokens.next_if_eq(&"WITH").is_some() {
+            self.tokens.next()?;
+        }
+        Some(allowed)
+    }
+}
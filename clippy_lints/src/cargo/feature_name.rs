@@ -1,8 +1,10 @@
 use cargo_metadata::Metadata;
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use rustc_errors::Applicability;
 use rustc_lint::LateContext;
 use rustc_span::source_map::DUMMY_SP;
 
+use super::manifest::Manifest;
 use super::{NEGATIVE_FEATURE_NAMES, REDUNDANT_FEATURE_NAMES};
 
 static PREFIXES: [&str; 8] = ["no-", "no_", "not-", "not_", "use-", "use_", "with-", "with_"];
@@ -10,6 +12,7 @@ static SUFFIXES: [&str; 2] = ["-support", "_support"];
 
 pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata) {
     for package in &metadata.packages {
+        let manifest = Manifest::load(cx, package.manifest_path.as_std_path());
         let mut features: Vec<&String> = package.features.keys().collect();
         features.sort();
         for feature in features {
@@ -22,7 +25,7 @@ pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata) {
                 }
             };
             if let Some(prefix) = prefix_opt {
-                lint(cx, feature, prefix, true);
+                lint(cx, manifest.as_ref(), feature, prefix, true);
             }
 
             let suffix_opt: Option<&str> = {
@@ -36,7 +39,7 @@ pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata) {
                 }
             };
             if let Some(suffix) = suffix_opt {
-                lint(cx, feature, suffix, false);
+                lint(cx, manifest.as_ref(), feature, suffix, false);
             }
         }
     }
@@ -46,39 +49,57 @@ fn is_negative_prefix(s: &str) -> bool {
     s.starts_with("no")
 }
 
-fn lint(cx: &LateContext<'_>, feature: &str, substring: &str, is_prefix: bool) {
+fn lint(cx: &LateContext<'_>, manifest: Option<&Manifest>, feature: &str, substring: &str, is_prefix: bool) {
     let is_negative = is_prefix && is_negative_prefix(substring);
-    span_lint_and_help(
-        cx,
-        if is_negative {
-            NEGATIVE_FEATURE_NAMES
-        } else {
-            REDUNDANT_FEATURE_NAMES
-        },
-        DUMMY_SP,
-        &format!(
-            "the \"{}\" {} in the feature name \"{}\" is {}",
-            substring,
-            if is_prefix { "prefix" } else { "suffix" },
-            feature,
-            if is_negative { "negative" } else { "redundant" }
-        ),
-        None,
-        &format!(
-            "consider renaming the feature to \"{}\"{}",
-            if is_prefix {
-                feature.strip_prefix(substring)
-            } else {
-                feature.strip_suffix(substring)
-            }
-            .unwrap(),
+    let new_name = if is_prefix {
+        feature.strip_prefix(substring)
+    } else {
+        feature.strip_suffix(substring)
+    }
+    .unwrap();
+    let lint = if is_negative {
+        NEGATIVE_FEATURE_NAMES
+    } else {
+        REDUNDANT_FEATURE_NAMES
+    };
+    let msg = format!(
+        "the \"{}\" {} in the feature name \"{}\" is {}",
+        substring,
+        if is_prefix { "prefix" } else { "suffix" },
+        feature,
+        if is_negative { "negative" } else { "redundant" }
+    );
+
+    if let Some(entry) = manifest.and_then(|manifest| manifest.entry("features", feature)) {
+        span_lint_and_then(cx, lint, entry.key_span, &msg, |diag| {
+            diag.span_suggestion(
+                entry.key_span,
+                "consider renaming the feature",
+                new_name,
+                Applicability::MaybeIncorrect,
+            );
             if is_negative {
-                ", but make sure the feature adds functionality"
-            } else {
-                ""
+                diag.note("make sure the feature adds functionality");
             }
-        ),
-    );
+        });
+    } else {
+        span_lint_and_help(
+            cx,
+            lint,
+            DUMMY_SP,
+            &msg,
+            None,
+            &format!(
+                "consider renaming the feature to \"{}\"{}",
+                new_name,
+                if is_negative {
+                    ", but make sure the feature adds functionality"
+                } else {
+                    ""
+                }
+            ),
+        );
+    }
 }
 
 #[test]
@@ -0,0 +1,240 @@
+//! Loads `Cargo.toml` into the source map, so the cargo lints can point at its keys and values.
+
+use cargo_metadata::{Dependency, DependencyKind};
+use rustc_data_structures::sync::Lrc;
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::{BytePos, FileName, Pos, RealFileName, SourceFile, Span};
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use std::fmt;
+use std::fs;
+use std::iter;
+use std::path::{Component, Path, PathBuf};
+use toml::Spanned;
+
+pub(super) struct Manifest {
+    file: Lrc<SourceFile>,
+    root: Table,
+}
+
+/// A table, with the spans of its keys and values. Tables defined by a `[table]` header don't
+/// have a span, as `toml` doesn't track them.
+pub(super) struct Table(Vec<(Spanned<String>, Spanned<Value>)>);
+
+pub(super) enum Value {
+    Table(Table),
+    Array(Vec<Spanned<Value>>),
+    Other(toml::Value),
+}
+
+/// A `key = value` pair, or a `[table]` header.
+pub(super) struct Entry<'a> {
+    pub key_span: Span,
+    /// The span of the value, or of the key for tables defined by a header.
+    pub value_span: Span,
+    pub value: &'a Value,
+}
+
+impl Manifest {
+    pub(super) fn load(cx: &LateContext<'_>, path: &Path) -> Option<Self> {
+        let src = fs::read_to_string(path).ok()?;
+        let Value::Table(root) = toml::from_str(&src).ok()? else { return None };
+        // Use the same relative paths as for the source files of the crate.
+        let RealFileName::LocalPath(working_dir) = &cx.sess().opts.working_dir else { return None };
+        let name = relative_path(path, working_dir).unwrap_or_else(|| path.to_owned());
+        let file = cx
+            .sess()
+            .source_map()
+            .new_source_file(FileName::Real(RealFileName::LocalPath(name)), src);
+        Some(Self { file, root })
+    }
+
+    fn span<T>(&self, spanned: &Spanned<T>) -> Option<Span> {
+        let (lo, hi) = spanned.span();
+        (hi != 0).then(|| {
+            Span::with_root_ctxt(
+                self.file.start_pos + BytePos::from_usize(lo),
+                self.file.start_pos + BytePos::from_usize(hi),
+            )
+        })
+    }
+
+    fn to_entry<'a>(&self, (key, value): &'a (Spanned<String>, Spanned<Value>)) -> Option<Entry<'a>> {
+        let key_span = self.span(key)?;
+        Some(Entry {
+            key_span,
+            value_span: self.span(value).unwrap_or(key_span),
+            value: value.get_ref(),
+        })
+    }
+
+    /// Looks up the (dotted) path of keys, e.g. `workspace.dependencies`.
+    fn lookup(&self, path: &str) -> Option<&(Spanned<String>, Spanned<Value>)> {
+        let mut keys = path.split('.');
+        let first = self.root.get(keys.next()?)?;
+        keys.try_fold(first, |(_, value), key| value.get_ref().as_table()?.get(key))
+    }
+
+    pub(super) fn table(&self, path: &str) -> Option<&Table> {
+        self.lookup(path)?.1.get_ref().as_table()
+    }
+
+    /// Returns the span of the name of the table, e.g. of `package` in `[package]`.
+    pub(super) fn header_span(&self, table: &str) -> Option<Span> {
+        self.span(&self.lookup(table)?.0)
+    }
+
+    pub(super) fn entry(&self, table: &str, key: &str) -> Option<Entry<'_>> {
+        self.to_entry(self.table(table)?.get(key)?)
+    }
+
+    /// Returns the entry declaring the dependency, either in the `[dependencies]` of its kind or
+    /// in those of a `[target]`.
+    fn dependency(&self, dep: &Dependency) -> Option<Entry<'_>> {
+        let kind = match dep.kind {
+            DependencyKind::Development => "dev-dependencies",
+            DependencyKind::Build => "build-dependencies",
+            _ => "dependencies",
+        };
+        let name = dep.rename.as_ref().unwrap_or(&dep.name);
+        let targets = self
+            .table("target")
+            .into_iter()
+            .flat_map(Table::values)
+            .filter_map(|target| target.as_table()?.get(kind));
+        iter::once(self.root.get(kind))
+            .flatten()
+            .chain(targets)
+            .find_map(|(_, deps)| deps.get_ref().as_table()?.get(name))
+            .and_then(|entry| self.to_entry(entry))
+    }
+
+    /// Returns the span of the name of the dependency, i.e. of its key or in its table header.
+    pub(super) fn dependency_span(&self, dep: &Dependency) -> Option<Span> {
+        Some(self.dependency(dep)?.key_span)
+    }
+
+    /// Returns the span of the version requirement of the dependency, in any of the forms
+    /// `foo = "*"`, `foo = { version = "*" }` or `[dependencies.foo] version = "*"`.
+    pub(super) fn dependency_version_span(&self, dep: &Dependency) -> Option<Span> {
+        let entry = self.dependency(dep)?;
+        match entry.value {
+            Value::Other(toml::Value::String(_)) => Some(entry.value_span),
+            Value::Table(table) => Some(self.to_entry(table.get("version")?)?.value_span),
+            _ => None,
+        }
+    }
+}
+
+impl Table {
+    fn get(&self, key: &str) -> Option<&(Spanned<String>, Spanned<Value>)> {
+        self.0.iter().find(|(name, _)| name.get_ref() == key)
+    }
+
+    pub(super) fn keys(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|(key, _)| key.get_ref().as_str())
+    }
+
+    fn values(&self) -> impl Iterator<Item = &Value> {
+        self.0.iter().map(|(_, value)| value.get_ref())
+    }
+}
+
+impl Value {
+    pub(super) fn as_table(&self) -> Option<&Table> {
+        match self {
+            Self::Table(table) => Some(table),
+            _ => None,
+        }
+    }
+
+    /// Checks if the value is inherited from the workspace, i.e. if it's `{ workspace = true }`.
+    pub(super) fn is_inherited(&self) -> bool {
+        self.as_table()
+            .and_then(|table| table.get("workspace"))
+            .map_or(false, |(_, value)| {
+                matches!(value.get_ref(), Self::Other(toml::Value::Boolean(true)))
+            })
+    }
+
+    pub(super) fn to_toml(&self) -> toml::Value {
+        match self {
+            Self::Table(table) => toml::Value::Table(
+                table
+                    .0
+                    .iter()
+                    .map(|(key, value)| (key.get_ref().clone(), value.get_ref().to_toml()))
+                    .collect(),
+            ),
+            Self::Array(values) => toml::Value::Array(values.iter().map(|value| value.get_ref().to_toml()).collect()),
+            Self::Other(value) => value.clone(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a TOML value")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Value, E> {
+        Ok(Value::Other(toml::Value::Boolean(value)))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Value, E> {
+        Ok(Value::Other(toml::Value::Integer(value)))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Value, E> {
+        let value = i64::try_from(value).map_err(|_| E::custom("integer out of range"))?;
+        Ok(Value::Other(toml::Value::Integer(value)))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Value, E> {
+        Ok(Value::Other(toml::Value::Float(value)))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Value, E> {
+        Ok(Value::Other(toml::Value::String(value.to_owned())))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(Value::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut entries = Vec::new();
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(Value::Table(Table(entries)))
+    }
+}
+
+/// Returns `path` relative to `base`, e.g. `../Cargo.toml`.
+fn relative_path(path: &Path, base: &Path) -> Option<PathBuf> {
+    let common = path
+        .components()
+        .zip(base.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+    if common == 0 {
+        return None;
+    }
+    let mut relative: PathBuf = base.components().skip(common).map(|_| Component::ParentDir).collect();
+    relative.extend(path.components().skip(common));
+    Some(relative)
+}
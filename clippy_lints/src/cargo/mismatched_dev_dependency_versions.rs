@@ -0,0 +1,46 @@
+//! lint on dependencies with a different version requirement in `[dev-dependencies]`
+
+use cargo_metadata::{DependencyKind, Metadata};
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_lint::LateContext;
+
+use super::manifest::Manifest;
+use super::MISMATCHED_DEV_DEPENDENCY_VERSIONS;
+
+pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata) {
+    for package in &metadata.packages {
+        let mut manifest = None;
+        for dev_dep in &package.dependencies {
+            if dev_dep.kind != DependencyKind::Development {
+                continue;
+            }
+            let Some(dep) = package.dependencies.iter().find(|dep| {
+                dep.kind == DependencyKind::Normal && dep.name == dev_dep.name && dep.source == dev_dep.source
+            }) else {
+                continue;
+            };
+            if dep.req == dev_dep.req {
+                continue;
+            }
+
+            let manifest = manifest.get_or_insert_with(|| Manifest::load(cx, package.manifest_path.as_std_path()));
+            let Some(manifest) = manifest else { break };
+            if let Some(span) = manifest.dependency_version_span(dev_dep) {
+                span_lint_and_then(
+                    cx,
+                    MISMATCHED_DEV_DEPENDENCY_VERSIONS,
+                    span,
+                    &format!(
+                        "`{}` is required as `{}` in `[dev-dependencies]`, but as `{}` in `[dependencies]`",
+                        dev_dep.name, dev_dep.req, dep.req
+                    ),
+                    |diag| {
+                        if let Some(dep_span) = manifest.dependency_version_span(dep) {
+                            diag.span_note(dep_span, "the dependency is declared here");
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! lint on dependencies which don't inherit the version the workspace defines for them
+
+use cargo_metadata::Metadata;
+use clippy_utils::diagnostics::span_lint_and_help;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_lint::LateContext;
+
+use super::manifest::{Manifest, Table};
+use super::MISSING_WORKSPACE_INHERITANCE;
+
+pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata) {
+    let root_path = metadata.workspace_root.join("Cargo.toml");
+    let Some(root) = Manifest::load(cx, root_path.as_std_path()) else { return };
+    let workspace_deps: FxHashSet<&str> = root
+        .table("workspace.dependencies")
+        .into_iter()
+        .flat_map(Table::keys)
+        .collect();
+    if workspace_deps.is_empty() {
+        return;
+    }
+
+    for package in &metadata.packages {
+        let deps = package
+            .dependencies
+            .iter()
+            .filter(|dep| workspace_deps.contains(dep.rename.as_ref().unwrap_or(&dep.name).as_str()));
+        let mut manifest = None;
+        for dep in deps {
+            let manifest = manifest.get_or_insert_with(|| Manifest::load(cx, package.manifest_path.as_std_path()));
+            let Some(manifest) = manifest else { break };
+            // Inherited dependencies don't have a version requirement of their own.
+            if let Some(span) = manifest.dependency_version_span(dep) {
+                span_lint_and_help(
+                    cx,
+                    MISSING_WORKSPACE_INHERITANCE,
+                    span,
+                    &format!("the version of `{}` is defined by the workspace", dep.name),
+                    None,
+                    "use `workspace = true` to inherit it instead",
+                );
+            }
+        }
+    }
+}
@@ -6,9 +6,17 @@ use rustc_lint::{LateContext, LateLintPass, Lint};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::DUMMY_SP;
 
+use crate::utils::conf::{AllowedDuplicateCrate, MetadataFieldValue};
+
 mod common_metadata;
+mod disallowed_dependencies;
 mod feature_name;
+mod manifest;
+mod mismatched_dev_dependency_versions;
+mod missing_workspace_inheritance;
 mod multiple_crate_versions;
+mod ungated_optional_dependencies;
+mod unused_dependencies;
 mod wildcard_dependencies;
 
 declare_clippy_lint! {
@@ -20,6 +28,11 @@ declare_clippy_lint! {
     /// It will be more difficult for users to discover the
     /// purpose of the crate, and key information related to it.
     ///
+    /// ### Configuration
+    /// The required fields can be changed with `required-metadata-fields`. Fields can also be
+    /// forbidden with `forbidden-metadata-fields`, or restricted to a single value with
+    /// `metadata-field-values`.
+    ///
     /// ### Example
     /// ```toml
     /// # This `Cargo.toml` is missing a description field:
@@ -127,7 +140,8 @@ declare_clippy_lint! {
     ///
     /// ### Known problems
     /// Because this can be caused purely by the dependencies
-    /// themselves, it's not always possible to fix this issue.
+    /// themselves, it's not always possible to fix this issue. Such duplicates can be accepted with
+    /// the `allowed-duplicate-crates` configuration.
     ///
     /// ### Example
     /// ```toml
@@ -156,14 +170,193 @@ declare_clippy_lint! {
     /// [dependencies]
     /// regex = "*"
     /// ```
+    /// Use instead:
+    /// ```toml
+    /// # The version currently locked in `Cargo.lock`
+    /// [dependencies]
+    /// regex = "1.5.6"
+    /// ```
     #[clippy::version = "1.32.0"]
     pub WILDCARD_DEPENDENCIES,
     cargo,
     "wildcard dependencies being used"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for dependencies in `Cargo.toml` which are never used by the crate.
+    ///
+    /// ### Why is this bad?
+    /// Unused dependencies slow down the build and bloat `Cargo.lock` for nothing.
+    ///
+    /// ### Known problems
+    /// Only the library of the package, or its only binary, is checked. Dependencies which have
+    /// a library of a different name, and dependencies of a specific target aren't checked.
+    ///
+    /// ### Example
+    /// ```toml
+    /// # `regex` is never used in the crate
+    /// [dependencies]
+    /// regex = "1.5"
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub UNUSED_DEPENDENCIES,
+    cargo,
+    "dependencies which are never used"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for dependencies declared in both `[dependencies]` and `[dev-dependencies]`
+    /// with different version requirements.
+    ///
+    /// ### Why is this bad?
+    /// Both declarations resolve to the same version, so one of the requirements is
+    /// misleading, and they can easily get out of sync.
+    ///
+    /// ### Example
+    /// ```toml
+    /// [dependencies]
+    /// regex = "1.5"
+    ///
+    /// [dev-dependencies]
+    /// regex = "1.4"
+    /// ```
+    /// Use instead:
+    /// ```toml
+    /// [dependencies]
+    /// regex = "1.5"
+    ///
+    /// [dev-dependencies]
+    /// regex = "1.5"
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub MISMATCHED_DEV_DEPENDENCY_VERSIONS,
+    cargo,
+    "dependencies with a different version requirement in `[dev-dependencies]`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for dependencies of workspace members which specify their own version
+    /// requirement, even though the workspace defines one in `[workspace.dependencies]`.
+    ///
+    /// ### Why is this bad?
+    /// The version of the dependency has to be updated in several places, and the members
+    /// may end up requiring different versions.
+    ///
+    /// ### Example
+    /// ```toml
+    /// # In the `Cargo.toml` of the workspace
+    /// [workspace.dependencies]
+    /// regex = "1.5"
+    ///
+    /// # In the `Cargo.toml` of a member
+    /// [dependencies]
+    /// regex = "1.5"
+    /// ```
+    /// Use instead:
+    /// ```toml
+    /// # In the `Cargo.toml` of a member
+    /// [dependencies]
+    /// regex = { workspace = true }
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub MISSING_WORKSPACE_INHERITANCE,
+    cargo,
+    "dependencies which don't inherit the version defined by the workspace"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for optional dependencies which no feature declared in `Cargo.toml` enables, i.e.
+    /// which are only enabled by the feature Cargo implicitly adds for them. A weak `foo?/bar`
+    /// entry doesn't enable the dependency.
+    ///
+    /// ### Why is this bad?
+    /// The implicit feature exposes the name of the dependency as part of the public features of
+    /// the crate, instead of a feature describing what it provides.
+    ///
+    /// ### Example
+    /// ```toml
+    /// [dependencies]
+    /// regex = { version = "1.5", optional = true }
+    /// ```
+    /// Use instead:
+    /// ```toml
+    /// [dependencies]
+    /// regex = { version = "1.5", optional = true }
+    ///
+    /// [features]
+    /// patterns = ["dep:regex"]
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub UNGATED_OPTIONAL_DEPENDENCIES,
+    cargo,
+    "optional dependencies which are exposed as implicit features"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks the licenses and sources of all dependencies in the resolved dependency graph
+    /// against the policy in the configuration: `allowed-licenses`, `denied-licenses`,
+    /// `allow-git-dependencies` and `allowed-registries`.
+    ///
+    /// ### Why is this bad?
+    /// Dependencies under an unacceptable license, or from an untrusted source, may be added
+    /// to the project without anyone noticing, e.g. as a dependency of a dependency.
+    ///
+    /// ### Known problems
+    /// Only the license expressions in the manifests of the dependencies are checked, not the
+    /// license files.
+    ///
+    /// ### Example
+    /// ```toml
+    /// # clippy.toml
+    /// allowed-licenses = ["MIT", "Apache-2.0"]
+    /// allow-git-dependencies = false
+    ///
+    /// # Cargo.toml
+    /// [dependencies]
+    /// # Licensed under `GPL-3.0-only`
+    /// copyleft = "1.0"
+    /// foo = { git = "https://github.com/foo/foo" }
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub DISALLOWED_DEPENDENCIES,
+    cargo,
+    "dependencies which violate the configured license or source policy"
+}
+
 pub struct Cargo {
     pub ignore_publish: bool,
+    pub metadata_policy: MetadataPolicy,
+    pub allowed_duplicate_crates: Vec<AllowedDuplicateCrate>,
+    pub dependency_policy: DependencyPolicy,
+}
+
+/// The configuration of the `CARGO_COMMON_METADATA` lint.
+pub struct MetadataPolicy {
+    pub required_fields: Vec<String>,
+    pub forbidden_fields: Vec<String>,
+    pub field_values: Vec<MetadataFieldValue>,
+}
+
+/// The configuration of the `DISALLOWED_DEPENDENCIES` lint.
+pub struct DependencyPolicy {
+    pub allowed_licenses: Vec<String>,
+    pub denied_licenses: Vec<String>,
+    pub allow_git_dependencies: bool,
+    pub allowed_registries: Vec<String>,
+}
+
+impl DependencyPolicy {
+    fn is_empty(&self) -> bool {
+        self.allowed_licenses.is_empty()
+            && self.denied_licenses.is_empty()
+            && self.allow_git_dependencies
+            && self.allowed_registries.is_empty()
+    }
 }
 
 impl_lint_pass!(Cargo => [
@@ -171,7 +364,12 @@ impl_lint_pass!(Cargo => [
     REDUNDANT_FEATURE_NAMES,
     NEGATIVE_FEATURE_NAMES,
     MULTIPLE_CRATE_VERSIONS,
-    WILDCARD_DEPENDENCIES
+    WILDCARD_DEPENDENCIES,
+    UNUSED_DEPENDENCIES,
+    MISMATCHED_DEV_DEPENDENCY_VERSIONS,
+    MISSING_WORKSPACE_INHERITANCE,
+    UNGATED_OPTIONAL_DEPENDENCIES,
+    DISALLOWED_DEPENDENCIES
 ]);
 
 impl LateLintPass<'_> for Cargo {
@@ -181,8 +379,12 @@ impl LateLintPass<'_> for Cargo {
             REDUNDANT_FEATURE_NAMES,
             NEGATIVE_FEATURE_NAMES,
             WILDCARD_DEPENDENCIES,
+            UNUSED_DEPENDENCIES,
+            MISMATCHED_DEV_DEPENDENCY_VERSIONS,
+            MISSING_WORKSPACE_INHERITANCE,
+            UNGATED_OPTIONAL_DEPENDENCIES,
         ];
-        static WITH_DEPS_LINTS: &[&Lint] = &[MULTIPLE_CRATE_VERSIONS];
+        static WITH_DEPS_LINTS: &[&Lint] = &[MULTIPLE_CRATE_VERSIONS, DISALLOWED_DEPENDENCIES];
 
         if !NO_DEPS_LINTS
             .iter()
@@ -190,9 +392,13 @@ impl LateLintPass<'_> for Cargo {
         {
             match MetadataCommand::new().no_deps().exec() {
                 Ok(metadata) => {
-                    common_metadata::check(cx, &metadata, self.ignore_publish);
+                    common_metadata::check(cx, &metadata, self.ignore_publish, &self.metadata_policy);
                     feature_name::check(cx, &metadata);
                     wildcard_dependencies::check(cx, &metadata);
+                    unused_dependencies::check(cx, &metadata);
+                    mismatched_dev_dependency_versions::check(cx, &metadata);
+                    missing_workspace_inheritance::check(cx, &metadata);
+                    ungated_optional_dependencies::check(cx, &metadata);
                 },
                 Err(e) => {
                     for lint in NO_DEPS_LINTS {
@@ -208,7 +414,8 @@ impl LateLintPass<'_> for Cargo {
         {
             match MetadataCommand::new().exec() {
                 Ok(metadata) => {
-                    multiple_crate_versions::check(cx, &metadata);
+                    multiple_crate_versions::check(cx, &metadata, &self.allowed_duplicate_crates);
+                    disallowed_dependencies::check(cx, &metadata, &self.dependency_policy);
                 },
                 Err(e) => {
                     for lint in WITH_DEPS_LINTS {
@@ -1,43 +1,67 @@
 //! lint on multiple versions of a crate being used
 
 use cargo_metadata::{DependencyKind, Metadata, Node, Package, PackageId};
-use clippy_utils::diagnostics::span_lint;
+use clippy_utils::diagnostics::span_lint_and_then;
 use if_chain::if_chain;
 use itertools::Itertools;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_hir::def_id::LOCAL_CRATE;
 use rustc_lint::LateContext;
 use rustc_span::source_map::DUMMY_SP;
+use std::collections::VecDeque;
 
+use super::manifest::Manifest;
 use super::MULTIPLE_CRATE_VERSIONS;
+use crate::utils::conf::AllowedDuplicateCrate;
 
-pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata) {
+pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata, allowed_duplicate_crates: &[AllowedDuplicateCrate]) {
     let local_name = cx.tcx.crate_name(LOCAL_CRATE);
     let mut packages = metadata.packages.clone();
     packages.sort_by(|a, b| a.name.cmp(&b.name));
 
     if_chain! {
         if let Some(resolve) = &metadata.resolve;
-        if let Some(local_id) = packages
-            .iter()
-            .find_map(|p| if p.name == local_name.as_str() { Some(&p.id) } else { None });
+        if let Some(local_package) = packages.iter().find(|p| p.name == local_name.as_str());
         then {
+            let local_id = &local_package.id;
+            let span = Manifest::load(cx, local_package.manifest_path.as_std_path())
+                .and_then(|manifest| manifest.header_span("dependencies"))
+                .unwrap_or(DUMMY_SP);
+            let graph = DepGraph::new(&resolve.nodes, &packages);
+
             for (name, group) in &packages.iter().group_by(|p| p.name.clone()) {
-                let group: Vec<&Package> = group.collect();
+                let mut group: Vec<&Package> = group.collect();
 
-                if group.len() <= 1 {
+                if group.len() <= 1 || is_allowed(allowed_duplicate_crates, &name, &group) {
                     continue;
                 }
 
                 if group.iter().all(|p| is_normal_dep(&resolve.nodes, local_id, &p.id)) {
-                    let mut versions: Vec<_> = group.into_iter().map(|p| &p.version).collect();
-                    versions.sort();
-                    let versions = versions.iter().join(", ");
+                    group.sort_by(|a, b| a.version.cmp(&b.version));
+                    let versions = group.iter().map(|p| &p.version).join(", ");
 
-                    span_lint(
+                    span_lint_and_then(
                         cx,
                         MULTIPLE_CRATE_VERSIONS,
-                        DUMMY_SP,
+                        span,
                         &format!("multiple versions for dependency `{}`: {}", name, versions),
+                        |diag| {
+                            for package in &group {
+                                if let Some(path) = graph.shortest_path(local_id, &package.id) {
+                                    diag.note(&format!(
+                                        "version {} is required by: {}",
+                                        package.version,
+                                        path.iter().map(|p| format!("{} {}", p.name, p.version)).join(" -> ")
+                                    ));
+                                }
+                            }
+                            if let Some(direct) = graph.unifying_dependency(local_id, &group) {
+                                diag.help(&format!(
+                                    "the older versions are only required through `{}`, updating it may unify them",
+                                    direct.name
+                                ));
+                            }
+                        },
                     );
                 }
             }
@@ -45,6 +69,24 @@ pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata) {
     }
 }
 
+/// Checks if the configuration accepts all versions of the crate.
+fn is_allowed(allowed_duplicate_crates: &[AllowedDuplicateCrate], name: &str, group: &[&Package]) -> bool {
+    allowed_duplicate_crates
+        .iter()
+        .filter(|allowed| allowed.name() == name)
+        .any(|allowed| {
+            let versions = allowed.versions();
+            versions.is_empty()
+                || group.iter().all(|package| {
+                    // Invalid requirements are reported when the configuration is read.
+                    versions
+                        .iter()
+                        .filter_map(|req| semver::VersionReq::parse(req).ok())
+                        .any(|req| req.matches(&package.version))
+                })
+        })
+}
+
 fn is_normal_dep(nodes: &[Node], local_id: &PackageId, dep_id: &PackageId) -> bool {
     fn depends_on(node: &Node, dep_id: &PackageId) -> bool {
         node.deps.iter().any(|dep| {
@@ -61,3 +103,88 @@ fn is_normal_dep(nodes: &[Node], local_id: &PackageId, dep_id: &PackageId) -> bo
         .filter(|node| depends_on(node, dep_id))
         .any(|node| node.id == *local_id || is_normal_dep(nodes, local_id, &node.id))
 }
+
+/// The graph of the normal dependencies in the resolved dependency tree.
+struct DepGraph<'a> {
+    deps: FxHashMap<&'a PackageId, Vec<&'a PackageId>>,
+    packages: FxHashMap<&'a PackageId, &'a Package>,
+}
+
+impl<'a> DepGraph<'a> {
+    fn new(nodes: &'a [Node], packages: &'a [Package]) -> Self {
+        let deps = nodes
+            .iter()
+            .map(|node| {
+                let deps = node
+                    .deps
+                    .iter()
+                    .filter(|dep| {
+                        dep.dep_kinds
+                            .iter()
+                            .any(|info| matches!(info.kind, DependencyKind::Normal))
+                    })
+                    .map(|dep| &dep.pkg)
+                    .collect();
+                (&node.id, deps)
+            })
+            .collect();
+        let packages = packages.iter().map(|package| (&package.id, package)).collect();
+        Self { deps, packages }
+    }
+
+    fn deps(&self, id: &PackageId) -> impl Iterator<Item = &'a PackageId> + '_ {
+        self.deps.get(id).into_iter().flatten().copied()
+    }
+
+    /// Finds the shortest chain of dependencies from `from` to `to`, both included.
+    fn shortest_path(&self, from: &'a PackageId, to: &PackageId) -> Option<Vec<&'a Package>> {
+        let mut parents: FxHashMap<&PackageId, &PackageId> = FxHashMap::default();
+        let mut queue = VecDeque::from([from]);
+        while let Some(id) = queue.pop_front() {
+            if id == to {
+                let mut path = vec![self.packages[id]];
+                let mut id = id;
+                while let Some(&parent) = parents.get(id) {
+                    path.push(self.packages[parent]);
+                    id = parent;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for dep in self.deps(id) {
+                if dep != from && !parents.contains_key(dep) {
+                    parents.insert(dep, id);
+                    queue.push_back(dep);
+                }
+            }
+        }
+        None
+    }
+
+    fn reachable(&self, from: &'a PackageId) -> FxHashSet<&'a PackageId> {
+        let mut seen = FxHashSet::default();
+        let mut stack = vec![from];
+        while let Some(id) = stack.pop() {
+            if seen.insert(id) {
+                stack.extend(self.deps(id));
+            }
+        }
+        seen
+    }
+
+    /// Returns the direct dependency all versions but the newest one of the crate are only
+    /// required through, if there is one.
+    fn unifying_dependency(&self, local_id: &PackageId, group: &[&Package]) -> Option<&'a Package> {
+        let (_, older) = group.split_last()?;
+        let mut direct = self
+            .deps(local_id)
+            .filter(|&dep| {
+                let reachable = self.reachable(dep);
+                older.iter().any(|package| reachable.contains(&package.id))
+            })
+            .unique();
+        let dep = direct.next()?;
+        // If an old version is a direct dependency itself, its own requirement has to be updated.
+        (direct.next().is_none() && older.iter().all(|package| package.id != *dep)).then(|| self.packages[dep])
+    }
+}
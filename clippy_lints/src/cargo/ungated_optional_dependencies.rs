@@ -0,0 +1,51 @@
+//! lint on optional dependencies which Cargo exposes as implicit features
+
+use cargo_metadata::Metadata;
+use clippy_utils::diagnostics::span_lint_and_help;
+use rustc_lint::LateContext;
+
+use super::manifest::Manifest;
+use super::UNGATED_OPTIONAL_DEPENDENCIES;
+
+pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata) {
+    for package in &metadata.packages {
+        if !package.dependencies.iter().any(|dep| dep.optional) {
+            continue;
+        }
+        let Some(manifest) = Manifest::load(cx, package.manifest_path.as_std_path()) else { continue };
+
+        // Cargo adds an implicit feature for every optional dependency which isn't enabled with
+        // the `dep:` syntax, so only the features declared in the manifest are considered.
+        let features: Vec<&String> = package
+            .features
+            .iter()
+            .filter(|(feature, _)| manifest.entry("features", feature).is_some())
+            .flat_map(|(_, enables)| enables)
+            .collect();
+
+        for dep in package.dependencies.iter().filter(|dep| dep.optional) {
+            let name = dep.rename.as_ref().unwrap_or(&dep.name);
+            if features.iter().any(|enables| enables_dependency(enables, name)) {
+                continue;
+            }
+            if let Some(span) = manifest.dependency_span(dep) {
+                span_lint_and_help(
+                    cx,
+                    UNGATED_OPTIONAL_DEPENDENCIES,
+                    span,
+                    &format!("optional dependency `{}` is exposed as an implicit feature", name),
+                    None,
+                    &format!("add a feature which enables it with `\"dep:{}\"`", name),
+                );
+            }
+        }
+    }
+}
+
+/// Checks if the entry of a feature enables the dependency: `dep:name`, the old style `name`, or
+/// `name/feature`. A weak `name?/feature` only enables a feature of the dependency if it's already
+/// enabled.
+fn enables_dependency(entry: &str, name: &str) -> bool {
+    let enabled = entry.strip_prefix("dep:").unwrap_or(entry);
+    enabled.split_once('/').map_or(enabled, |(dep, _)| dep) == name
+}
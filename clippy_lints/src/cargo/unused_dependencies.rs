@@ -0,0 +1,98 @@
+//! lint on dependencies which are never used by the crate
+
+use cargo_metadata::{DependencyKind, Metadata, Package};
+use clippy_utils::diagnostics::span_lint_and_help;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_lint::{LateContext, LintContext};
+use rustc_session::config::{CrateType, ExternLocation};
+use rustc_session::cstore::ExternCrate;
+use rustc_span::def_id::LOCAL_CRATE;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::manifest::Manifest;
+use super::UNUSED_DEPENDENCIES;
+
+pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata) {
+    let Some(package) = current_package(metadata) else { return };
+    if !is_main_target(cx, package) {
+        return;
+    }
+
+    // The crates which are used by this crate, not only by one of its dependencies.
+    let used: FxHashSet<PathBuf> = cx
+        .tcx
+        .crates(())
+        .iter()
+        .filter(|&&cnum| {
+            cx.tcx
+                .extern_crate(cnum.as_def_id())
+                .map_or(false, ExternCrate::is_direct)
+        })
+        .flat_map(|&cnum| cx.tcx.used_crate_source(cnum).paths())
+        .map(|path| canonicalize(path))
+        .collect();
+
+    let externs = &cx.sess().opts.externs;
+    let mut manifest = None;
+    for dep in &package.dependencies {
+        if dep.kind != DependencyKind::Normal || dep.target.is_some() {
+            continue;
+        }
+        let name = dep.rename.as_ref().unwrap_or(&dep.name).replace('-', "_");
+        // Optional dependencies which aren't enabled and dependencies whose library has a
+        // different name aren't passed to the compiler at all.
+        let Some(entry) = externs.get(&name) else { continue };
+        let ExternLocation::ExactPaths(paths) = &entry.location else { continue };
+        if !entry.add_prelude || paths.iter().any(|path| used.contains(&canonicalize(path.original()))) {
+            continue;
+        }
+
+        let manifest = manifest.get_or_insert_with(|| Manifest::load(cx, package.manifest_path.as_std_path()));
+        if let Some(span) = manifest.as_ref().and_then(|manifest| manifest.dependency_span(dep)) {
+            span_lint_and_help(
+                cx,
+                UNUSED_DEPENDENCIES,
+                span,
+                &format!("dependency `{}` is never used", dep.name),
+                None,
+                "remove it from `Cargo.toml`",
+            );
+        }
+    }
+}
+
+/// Returns the package cargo is currently building.
+fn current_package(metadata: &Metadata) -> Option<&Package> {
+    let dir = canonicalize(Path::new(&env::var_os("CARGO_MANIFEST_DIR")?));
+    metadata.packages.iter().find(|package| {
+        package
+            .manifest_path
+            .parent()
+            .map_or(false, |parent| canonicalize(parent.as_std_path()) == dir)
+    })
+}
+
+/// Checks if the crate is the one all dependencies are meant for: the only library or binary of
+/// the package. Tests and examples may not need every dependency, and the other targets of a
+/// package with a library and binaries, or with several binaries, aren't checked together.
+fn is_main_target(cx: &LateContext<'_>, package: &Package) -> bool {
+    if cx.sess().opts.test {
+        return false;
+    }
+    let mut main_targets = package.targets.iter().filter(|target| {
+        target
+            .kind
+            .iter()
+            .any(|kind| !matches!(kind.as_str(), "example" | "test" | "bench" | "custom-build"))
+    });
+    let (Some(target), None) = (main_targets.next(), main_targets.next()) else { return false };
+    let is_bin = target.kind.iter().any(|kind| kind == "bin");
+    target.name.replace('-', "_") == cx.tcx.crate_name(LOCAL_CRATE).as_str()
+        && is_bin == cx.sess().crate_types().contains(&CrateType::Executable)
+}
+
+fn canonicalize(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_owned())
+}
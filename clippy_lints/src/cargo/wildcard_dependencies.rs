@@ -1,13 +1,19 @@
 use cargo_metadata::Metadata;
-use clippy_utils::diagnostics::span_lint;
+use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
 use if_chain::if_chain;
+use rustc_errors::Applicability;
 use rustc_lint::LateContext;
 use rustc_span::source_map::DUMMY_SP;
+use std::fs;
 
+use super::manifest::Manifest;
 use super::WILDCARD_DEPENDENCIES;
 
 pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata) {
-    for dep in &metadata.packages[0].dependencies {
+    let package = &metadata.packages[0];
+    let mut manifest = None;
+    let mut lock = None;
+    for dep in &package.dependencies {
         // VersionReq::any() does not work
         if_chain! {
             if let Ok(wildcard_ver) = semver::VersionReq::parse("*");
@@ -15,13 +21,41 @@ pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata) {
             if !source.starts_with("git");
             if dep.req == wildcard_ver;
             then {
-                span_lint(
-                    cx,
-                    WILDCARD_DEPENDENCIES,
-                    DUMMY_SP,
-                    &format!("wildcard dependency for `{}`", dep.name),
-                );
+                let msg = format!("wildcard dependency for `{}`", dep.name);
+                let manifest = manifest.get_or_insert_with(|| Manifest::load(cx, package.manifest_path.as_std_path()));
+                let Some(span) = manifest.as_ref().and_then(|manifest| manifest.dependency_version_span(dep)) else {
+                    span_lint(cx, WILDCARD_DEPENDENCIES, DUMMY_SP, &msg);
+                    continue;
+                };
+                let lock = lock.get_or_insert_with(|| read_lock(metadata));
+                span_lint_and_then(cx, WILDCARD_DEPENDENCIES, span, &msg, |diag| {
+                    if let Some(version) = lock.as_ref().and_then(|lock| locked_version(lock, &dep.name)) {
+                        diag.span_suggestion(
+                            span,
+                            "pin it to the locked version",
+                            format!("\"{}\"", version),
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                });
             }
         }
     }
 }
+
+fn read_lock(metadata: &Metadata) -> Option<toml::Value> {
+    let src = fs::read_to_string(metadata.workspace_root.join("Cargo.lock")).ok()?;
+    toml::from_str(&src).ok()
+}
+
+/// Returns the locked version of the crate, if there is exactly one.
+fn locked_version<'a>(lock: &'a toml::Value, name: &str) -> Option<&'a str> {
+    let mut versions = lock
+        .get("package")?
+        .as_array()?
+        .iter()
+        .filter(|package| package.get("name").and_then(toml::Value::as_str) == Some(name))
+        .filter_map(|package| package.get("version")?.as_str());
+    let version = versions.next()?;
+    versions.next().is_none().then_some(version)
+}
@@ -2,12 +2,13 @@ use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::span_lint;
 use clippy_utils::expr_or_init;
 use clippy_utils::ty::{get_discriminant_value, is_isize_or_usize};
+use clippy_utils::value_range::{expr_range, IntRange};
 use rustc_ast::ast;
 use rustc_attr::IntType;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::{BinOpKind, Expr, ExprKind};
 use rustc_lint::LateContext;
-use rustc_middle::ty::{self, FloatTy, Ty};
+use rustc_middle::ty::{self, FloatTy, IntTy, Ty, UintTy};
 
 use super::{utils, CAST_ENUM_TRUNCATION, CAST_POSSIBLE_TRUNCATION};
 
@@ -75,9 +76,42 @@ fn apply_reductions(cx: &LateContext<'_>, nbits: u64, expr: &Expr<'_>, signed: b
     }
 }
 
-pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, cast_expr: &Expr<'_>, cast_from: Ty<'_>, cast_to: Ty<'_>) {
+/// Checks if the value of the expression provably fits in the target type. `usize` and `isize` are
+/// assumed to be 32 bits wide, so this holds on every target.
+///
+/// Casts of plain constants are left to the type-based check, the type of the constant can be
+/// changed instead.
+fn value_fits<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, cast_to: Ty<'tcx>) -> bool {
+    if constant_int(cx, expr).is_some() {
+        return false;
+    }
+    let cast_to = match cast_to.kind() {
+        ty::Uint(UintTy::Usize) => cx.tcx.types.u32,
+        ty::Int(IntTy::Isize) => cx.tcx.types.i32,
+        _ => cast_to,
+    };
+    if let Some(range) = expr_range(cx, expr)
+        && let Some(to_range) = IntRange::of_ty(cx.tcx, cast_to)
+    {
+        to_range.contains_range(range)
+    } else {
+        false
+    }
+}
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    cast_expr: &Expr<'_>,
+    cast_from: Ty<'_>,
+    cast_to: Ty<'tcx>,
+) {
     let msg = match (cast_from.kind(), cast_to.is_integral()) {
         (ty::Int(_) | ty::Uint(_), true) => {
+            if value_fits(cx, cast_expr, cast_to) {
+                return;
+            }
+
             let from_nbits = apply_reductions(
                 cx,
                 utils::int_ty_to_nbits(cast_from, cx.tcx),
@@ -1,5 +1,6 @@
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::span_lint;
+use clippy_utils::value_range::expr_range;
 use clippy_utils::{method_chain_args, sext};
 use if_chain::if_chain;
 use rustc_hir::{Expr, ExprKind};
@@ -40,6 +41,11 @@ fn should_lint(cx: &LateContext<'_>, cast_op: &Expr<'_>, cast_from: Ty<'_>, cast
                 }
             }
 
+            // Don't lint if the value is provably non-negative.
+            if expr_range(cx, cast_op).map_or(false, |range| range.lo >= 0) {
+                return false;
+            }
+
             // Don't lint for the result of methods that always return non-negative values.
             if let ExprKind::MethodCall(path, _, _) = cast_op.kind {
                 let mut method_name = path.ident.name.as_str();
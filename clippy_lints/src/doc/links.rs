@@ -0,0 +1,465 @@
+//! Resolution of intra-doc links, for `DOC_UNRESOLVED_LINKS` and `DOC_LINKS_TO_PRIVATE_ITEMS`.
+//!
+//! This only approximates the resolution rustdoc does, so whenever a part of the path can't be
+//! resolved with certainty, e.g. because of a glob import, the link is assumed to be valid.
+
+use clippy_utils::diagnostics::span_lint;
+use pulldown_cmark::LinkType;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{DefId, LocalDefId, CRATE_DEF_ID};
+use rustc_hir::{ItemKind, UseKind};
+use rustc_lint::{LateContext, LintContext};
+use rustc_middle::ty::{self, AdtDef, DefIdTree};
+use rustc_span::{sym, Span};
+
+use super::{DOC_LINKS_TO_PRIVATE_ITEMS, DOC_UNRESOLVED_LINKS};
+
+/// Names in the standard prelude and the macros of `std`, which are in scope everywhere.
+const PRELUDE: &[&str] = &[
+    "AsMut",
+    "AsRef",
+    "Box",
+    "Clone",
+    "Copy",
+    "Debug",
+    "Default",
+    "DoubleEndedIterator",
+    "Drop",
+    "Eq",
+    "Err",
+    "ExactSizeIterator",
+    "Extend",
+    "Fn",
+    "FnMut",
+    "FnOnce",
+    "From",
+    "FromIterator",
+    "Hash",
+    "Into",
+    "IntoIterator",
+    "Iterator",
+    "None",
+    "Ok",
+    "Option",
+    "Ord",
+    "PartialEq",
+    "PartialOrd",
+    "Result",
+    "Send",
+    "Sized",
+    "Some",
+    "String",
+    "Sync",
+    "ToOwned",
+    "ToString",
+    "TryFrom",
+    "TryInto",
+    "Unpin",
+    "Vec",
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "cfg",
+    "column",
+    "compile_error",
+    "concat",
+    "dbg",
+    "debug_assert",
+    "debug_assert_eq",
+    "debug_assert_ne",
+    "drop",
+    "env",
+    "eprint",
+    "eprintln",
+    "file",
+    "format",
+    "format_args",
+    "include",
+    "include_bytes",
+    "include_str",
+    "line",
+    "matches",
+    "module_path",
+    "option_env",
+    "panic",
+    "print",
+    "println",
+    "stringify",
+    "todo",
+    "unimplemented",
+    "unreachable",
+    "vec",
+    "write",
+    "writeln",
+];
+
+const PRIMITIVES: &[&str] = &[
+    "array",
+    "bool",
+    "char",
+    "f32",
+    "f64",
+    "fn",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "isize",
+    "never",
+    "pointer",
+    "reference",
+    "slice",
+    "str",
+    "tuple",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+    "unit",
+    "usize",
+];
+
+const DISAMBIGUATORS: &[&str] = &[
+    "const",
+    "constant",
+    "derive",
+    "enum",
+    "field",
+    "fn",
+    "function",
+    "macro",
+    "method",
+    "mod",
+    "module",
+    "prim",
+    "primitive",
+    "static",
+    "struct",
+    "tyalias",
+    "trait",
+    "type",
+    "union",
+    "value",
+    "variant",
+];
+
+/// Returns the path of an intra-doc link, i.e. of a link whose destination is a Rust path,
+/// like `[Vec]`, `[text](std::vec::Vec)`, `` [`Vec::new()`] `` or `` [`vec!`] ``. `source` is
+/// the Markdown source of the whole link.
+pub(super) fn link_path<'a>(kind: LinkType, url: &'a str, source: &'a str) -> Option<&'a str> {
+    let link = match kind {
+        LinkType::Inline | LinkType::Reference | LinkType::Collapsed | LinkType::Shortcut => url,
+        // Broken references, which rustdoc resolves as intra-doc links
+        LinkType::ReferenceUnknown | LinkType::CollapsedUnknown | LinkType::ShortcutUnknown => {
+            let source = source.strip_suffix("[]").unwrap_or(source);
+            let reference = match source.rsplit_once("][") {
+                Some((_, reference)) if kind == LinkType::ReferenceUnknown => reference,
+                _ => source.strip_prefix('[')?,
+            };
+            let reference = reference.strip_suffix(']')?;
+            // Only consider links which look like code, not text in brackets
+            let is_code = reference.starts_with('`') && reference.ends_with('`');
+            if !is_code && !reference.contains("::") {
+                return None;
+            }
+            reference
+        },
+        LinkType::Autolink | LinkType::Email => return None,
+    };
+    let link = link.trim_matches('`');
+    let link = link.split_once('#').map_or(link, |(link, _)| link);
+    let link = match link.split_once('@') {
+        Some((disambiguator, link)) if DISAMBIGUATORS.contains(&disambiguator) => link,
+        Some(_) => return None,
+        None => link,
+    };
+    let link = link.strip_suffix("()").unwrap_or(link);
+    let link = link.strip_suffix('!').unwrap_or(link);
+    let is_path = !link.is_empty() && link.strip_prefix("::").unwrap_or(link).split("::").all(is_ident);
+    is_path.then_some(link)
+}
+
+fn is_ident(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().map_or(false, |c| c.is_alphabetic() || c == '_') && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+pub(super) fn check_link(cx: &LateContext<'_>, owner: LocalDefId, path: &str, span: Span) {
+    let Some(scope) = Scope::of(cx, owner) else { return };
+    let resolution = scope
+        .modules
+        .iter()
+        .map(|&module| resolve(cx, &scope, module, path))
+        .reduce(Resolution::or)
+        .unwrap_or(Resolution::Unknown);
+
+    match resolution {
+        Resolution::Unresolved => span_lint(
+            cx,
+            DOC_UNRESOLVED_LINKS,
+            span,
+            &format!("unresolved link to `{}`", path),
+        ),
+        Resolution::Def(def_id) => {
+            if let Some(target) = def_id.as_local()
+                && cx.access_levels.is_exported(owner)
+                && !cx.access_levels.is_exported(target)
+            {
+                span_lint(
+                    cx,
+                    DOC_LINKS_TO_PRIVATE_ITEMS,
+                    span,
+                    &format!("public documentation links to private item `{}`", path),
+                );
+            }
+        },
+        Resolution::Unknown => {},
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Resolution {
+    Def(DefId),
+    /// The path refers to something which can't be resolved further, e.g. a primitive type.
+    Unknown,
+    Unresolved,
+}
+
+impl Resolution {
+    fn or(self, other: Self) -> Self {
+        match self {
+            Self::Unresolved => other,
+            _ => self,
+        }
+    }
+}
+
+struct Scope {
+    /// The modules the link is resolved in. The documentation of a module is resolved in both
+    /// the module and its parent.
+    modules: Vec<LocalDefId>,
+    /// What `Self` refers to.
+    self_def: Option<DefId>,
+    generics: Vec<String>,
+}
+
+impl Scope {
+    fn of(cx: &LateContext<'_>, owner: LocalDefId) -> Option<Self> {
+        if owner == CRATE_DEF_ID {
+            return Some(Self {
+                modules: vec![CRATE_DEF_ID],
+                self_def: None,
+                generics: Vec::new(),
+            });
+        }
+        let tcx = cx.tcx;
+        let parent = tcx.parent(owner.to_def_id());
+        let (container, self_def) = match tcx.def_kind(parent) {
+            DefKind::Impl => (tcx.parent(parent), tcx.type_of(parent).ty_adt_def().map(AdtDef::did)),
+            DefKind::Trait => (tcx.parent(parent), Some(parent)),
+            _ => {
+                let self_def = match tcx.def_kind(owner) {
+                    DefKind::Struct | DefKind::Enum | DefKind::Union | DefKind::Trait => Some(owner.to_def_id()),
+                    DefKind::Impl => tcx.type_of(owner).ty_adt_def().map(AdtDef::did),
+                    _ => None,
+                };
+                (parent, self_def)
+            },
+        };
+        // Items in function bodies can refer to other items of the body
+        if tcx.def_kind(container) != DefKind::Mod {
+            return None;
+        }
+
+        let modules = if tcx.def_kind(owner) == DefKind::Mod {
+            vec![owner, container.expect_local()]
+        } else {
+            vec![tcx.parent_module_from_def_id(owner)]
+        };
+        // The generic parameters of the item and of the items it's nested in, e.g. the `impl`
+        let mut generics = Vec::new();
+        if matches!(
+            tcx.def_kind(owner),
+            DefKind::Fn
+                | DefKind::AssocFn
+                | DefKind::AssocConst
+                | DefKind::Struct
+                | DefKind::Enum
+                | DefKind::Union
+                | DefKind::Trait
+                | DefKind::TyAlias
+                | DefKind::AssocTy
+                | DefKind::Impl
+        ) {
+            let mut def_generics = Some(tcx.generics_of(owner));
+            while let Some(current) = def_generics {
+                generics.extend(current.params.iter().map(|param| param.name.to_string()));
+                def_generics = current.parent.map(|parent| tcx.generics_of(parent));
+            }
+        }
+        Some(Self {
+            modules,
+            self_def,
+            generics,
+        })
+    }
+}
+
+fn resolve(cx: &LateContext<'_>, scope: &Scope, module: LocalDefId, path: &str) -> Resolution {
+    let tcx = cx.tcx;
+    let (first, rest) = if let Some(path) = path.strip_prefix("::") {
+        let (krate, rest) = path.split_once("::").unwrap_or((path, ""));
+        (crate_root(cx, krate), rest)
+    } else {
+        let (first, rest) = path.split_once("::").unwrap_or((path, ""));
+        let first = match first {
+            "crate" => Resolution::Def(CRATE_DEF_ID.to_def_id()),
+            "self" => Resolution::Def(module.to_def_id()),
+            "super" if module == CRATE_DEF_ID => Resolution::Unresolved,
+            "super" => Resolution::Def(tcx.parent(module.to_def_id())),
+            "Self" => scope.self_def.map_or(Resolution::Unknown, Resolution::Def),
+            _ => resolve_in_scope(cx, scope, module, first),
+        };
+        (first, rest)
+    };
+
+    let mut resolution = first;
+    for segment in rest.split("::").filter(|segment| !segment.is_empty()) {
+        resolution = match resolution {
+            Resolution::Def(def_id) => child(cx, def_id, segment),
+            _ => return resolution,
+        };
+    }
+    resolution
+}
+
+fn resolve_in_scope(cx: &LateContext<'_>, scope: &Scope, module: LocalDefId, name: &str) -> Resolution {
+    let resolution = child(cx, module.to_def_id(), name);
+    if !matches!(resolution, Resolution::Unresolved) {
+        return resolution;
+    }
+    if PRELUDE.contains(&name) || PRIMITIVES.contains(&name) || scope.generics.iter().any(|param| param == name) {
+        return Resolution::Unknown;
+    }
+    crate_root(cx, name)
+}
+
+fn crate_root(cx: &LateContext<'_>, name: &str) -> Resolution {
+    if let Some(&cnum) = cx
+        .tcx
+        .crates(())
+        .iter()
+        .find(|&&cnum| cx.tcx.crate_name(cnum).as_str() == name)
+    {
+        Resolution::Def(cnum.as_def_id())
+    } else if matches!(name, "std" | "core" | "alloc") || cx.sess().opts.externs.get(name).is_some() {
+        Resolution::Unknown
+    } else {
+        Resolution::Unresolved
+    }
+}
+
+/// Resolves the item, field or variant named `name` in the module, type or trait.
+fn child(cx: &LateContext<'_>, parent: DefId, name: &str) -> Resolution {
+    let tcx = cx.tcx;
+    match tcx.def_kind(parent) {
+        DefKind::Mod => match parent.as_local() {
+            Some(module) => local_module_child(cx, module, name),
+            None => tcx
+                .module_children(parent)
+                .iter()
+                .find(|child| child.ident.name.as_str() == name)
+                .map_or(Resolution::Unresolved, |child| {
+                    child.res.opt_def_id().map_or(Resolution::Unknown, Resolution::Def)
+                }),
+        },
+        DefKind::Struct | DefKind::Enum | DefKind::Union => {
+            let adt = tcx.adt_def(parent);
+            if let Some(variant) = adt.variants().iter().find(|variant| variant.name.as_str() == name) {
+                return Resolution::Def(variant.def_id);
+            }
+            if let Some(field) = adt.all_fields().find(|field| field.name.as_str() == name) {
+                return Resolution::Def(field.did);
+            }
+            if let Some(item) = tcx
+                .inherent_impls(parent)
+                .iter()
+                .find_map(|&id| associated_item(cx, id, name))
+            {
+                return Resolution::Def(item);
+            }
+            // Only the trait implementations of local types are known
+            if parent.is_local() && !has_trait_item(cx, parent, name) {
+                Resolution::Unresolved
+            } else {
+                Resolution::Unknown
+            }
+        },
+        DefKind::Trait => associated_item(cx, parent, name).map_or(Resolution::Unresolved, Resolution::Def),
+        DefKind::Variant => tcx
+            .adt_def(tcx.parent(parent))
+            .variant_with_id(parent)
+            .fields
+            .iter()
+            .find(|field| field.name.as_str() == name)
+            .map_or(Resolution::Unresolved, |field| Resolution::Def(field.did)),
+        _ => Resolution::Unknown,
+    }
+}
+
+fn local_module_child(cx: &LateContext<'_>, module: LocalDefId, name: &str) -> Resolution {
+    let (module, ..) = cx.tcx.hir().get_module(module);
+    let mut has_glob = false;
+    for &id in module.item_ids {
+        let item = cx.tcx.hir().item(id);
+        match item.kind {
+            ItemKind::Use(path, UseKind::Single) if item.ident.name.as_str() == name => {
+                return path.res.opt_def_id().map_or(Resolution::Unknown, Resolution::Def);
+            },
+            // The prelude is handled by `resolve_in_scope`
+            ItemKind::Use(_, UseKind::Glob) => {
+                has_glob |= !cx
+                    .tcx
+                    .hir()
+                    .attrs(item.hir_id())
+                    .iter()
+                    .any(|attr| attr.has_name(sym::prelude_import));
+            },
+            ItemKind::Use(..) => {},
+            ItemKind::ForeignMod { items, .. } => {
+                if let Some(foreign) = items.iter().find(|foreign| foreign.ident.name.as_str() == name) {
+                    return Resolution::Def(foreign.id.def_id.to_def_id());
+                }
+            },
+            _ if item.ident.name.as_str() == name => return Resolution::Def(item.def_id.to_def_id()),
+            _ => {},
+        }
+    }
+    if has_glob {
+        Resolution::Unknown
+    } else {
+        Resolution::Unresolved
+    }
+}
+
+fn associated_item(cx: &LateContext<'_>, container: DefId, name: &str) -> Option<DefId> {
+    cx.tcx
+        .associated_items(container)
+        .in_definition_order()
+        .find(|item| item.name.as_str() == name)
+        .map(|item| item.def_id)
+}
+
+/// Checks if a trait the type implements, either directly or through a blanket implementation,
+/// has an item named `name`.
+fn has_trait_item(cx: &LateContext<'_>, adt: DefId, name: &str) -> bool {
+    cx.tcx.all_traits().any(|trait_id| {
+        associated_item(cx, trait_id, name).is_some()
+            && cx.tcx.all_impls(trait_id).any(|impl_id| {
+                let self_ty = cx.tcx.type_of(impl_id);
+                matches!(self_ty.kind(), ty::Param(_)) || self_ty.ty_adt_def().map_or(false, |def| def.did() == adt)
+            })
+    })
+}
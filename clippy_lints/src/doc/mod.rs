@@ -1,11 +1,15 @@
+mod links;
+mod spelling;
+
 use clippy_utils::attrs::is_doc_hidden;
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_note, span_lint_and_then};
 use clippy_utils::macros::{is_panic, root_macro_call_first_node};
 use clippy_utils::source::{first_line_of_span, snippet_with_applicability};
 use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
-use clippy_utils::{is_entrypoint_fn, method_chain_args, return_ty};
+use clippy_utils::{is_entrypoint_fn, is_lint_allowed, method_chain_args, return_ty};
 use if_chain::if_chain;
 use itertools::Itertools;
+use pulldown_cmark::HeadingLevel;
 use rustc_ast::ast::{Async, AttrKind, Attribute, Fn, FnRetTy, ItemKind};
 use rustc_ast::token::CommentKind;
 use rustc_data_structures::fx::FxHashSet;
@@ -14,7 +18,7 @@ use rustc_errors::emitter::EmitterWriter;
 use rustc_errors::{Applicability, Handler, MultiSpan, SuggestionStyle};
 use rustc_hir as hir;
 use rustc_hir::intravisit::{self, Visitor};
-use rustc_hir::{AnonConst, Expr};
+use rustc_hir::{AnonConst, BinOpKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::nested_filter;
 use rustc_middle::lint::in_external_macro;
@@ -23,15 +27,17 @@ use rustc_parse::maybe_new_parser_from_source_str;
 use rustc_parse::parser::ForceCollect;
 use rustc_session::parse::ParseSess;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
-use rustc_span::def_id::LocalDefId;
+use rustc_span::def_id::{LocalDefId, CRATE_DEF_ID};
 use rustc_span::edition::Edition;
 use rustc_span::source_map::{BytePos, FilePathMapping, SourceMap, Span};
-use rustc_span::{sym, FileName, Pos};
+use rustc_span::{sym, FileName, Pos, Symbol};
 use std::io;
 use std::ops::Range;
 use std::thread;
 use url::Url;
 
+use crate::utils::conf::{DocSectionItems, DocSectionRule};
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for the presence of `_`, `::` or camel-case words
@@ -198,35 +204,267 @@ declare_clippy_lint! {
     "presence of `fn main() {` in code examples"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for intra-doc links which don't resolve to an item.
+    ///
+    /// ### Why is this bad?
+    /// rustdoc renders them as plain text, so the reader can't follow the link. This usually
+    /// happens when the linked item was renamed or removed.
+    ///
+    /// ### Known problems
+    /// The resolution is only approximated, links through glob imports or macros are assumed to
+    /// be valid.
+    ///
+    /// ### Example
+    /// ```rust
+    /// /// Creates a [`Wdiget`].
+    /// fn create() {}
+    /// # struct Widget;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// /// Creates a [`Widget`].
+    /// fn create() {}
+    /// # struct Widget;
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub DOC_UNRESOLVED_LINKS,
+    pedantic,
+    "intra-doc links which don't resolve to an item"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for documentation of publicly exported items which links to items that aren't
+    /// exported.
+    ///
+    /// ### Why is this bad?
+    /// The private item isn't part of the rendered documentation, so the link is broken for the
+    /// users of the crate.
+    ///
+    /// ### Example
+    /// ```rust
+    /// /// Uses a [`Cache`] internally.
+    /// pub fn lookup() {}
+    ///
+    /// struct Cache;
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub DOC_LINKS_TO_PRIVATE_ITEMS,
+    pedantic,
+    "public documentation which links to private items"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `# Examples` sections in which no Rust code block mentions the documented
+    /// item.
+    ///
+    /// ### Why is this bad?
+    /// Such examples were usually copied from another item, or the item was renamed and the
+    /// examples weren't updated.
+    ///
+    /// ### Example
+    /// ```rust
+    /// /// # Examples
+    /// ///
+    /// /// ```
+    /// /// let x = my_crate::double(2);
+    /// /// ```
+    /// pub fn triple(x: i32) -> i32 {
+    ///     x * 3
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// /// # Examples
+    /// ///
+    /// /// ```
+    /// /// let x = my_crate::triple(2);
+    /// /// ```
+    /// pub fn triple(x: i32) -> i32 {
+    ///     x * 3
+    /// }
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub UNRELATED_DOC_EXAMPLES,
+    pedantic,
+    "`# Examples` sections which don't use the documented item"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for code blocks marked `ignore` or `no_run` which don't start with a comment
+    /// explaining why.
+    ///
+    /// ### Why is this bad?
+    /// Ignored examples aren't checked, so they easily get out of date. The reason helps to judge
+    /// whether the attribute is still needed.
+    ///
+    /// ### Example
+    /// ```rust
+    /// /// ```ignore
+    /// /// connect("localhost");
+    /// /// ```
+    /// fn connect(_: &str) {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// /// ```ignore
+    /// /// // needs a running server
+    /// /// connect("localhost");
+    /// /// ```
+    /// fn connect(_: &str) {}
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub DOCTEST_ATTRIBUTES_WITHOUT_REASON,
+    restriction,
+    "`ignore` or `no_run` code blocks without an explanation"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks the documentation of exported items for the sections configured with
+    /// `doc-sections`, and that they appear in the configured order.
+    ///
+    /// ### Why is this bad?
+    /// Consistently structured documentation is easier to read, and required sections like
+    /// `# Cancel safety` for `async fn`s are easily forgotten.
+    ///
+    /// ### Example
+    /// With `doc-sections = [{ items = "async-fn", sections = ["Cancel safety"] }]`:
+    /// ```rust
+    /// /// Reads the next message.
+    /// pub async fn next_message() {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// /// Reads the next message.
+    /// ///
+    /// /// # Cancel safety
+    /// ///
+    /// /// No message is lost if the future is dropped.
+    /// pub async fn next_message() {}
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub MISSING_DOC_SECTIONS,
+    pedantic,
+    "documentation missing the sections configured for the item"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `# Panics`, `# Errors` and `# Safety` sections in the documentation of
+    /// functions which don't panic, don't return a `Result` or aren't `unsafe`.
+    ///
+    /// ### Why is this bad?
+    /// The section documents something the function doesn't do, usually because the function
+    /// was changed and the documentation wasn't updated.
+    ///
+    /// ### Known problems
+    /// Only panics in the function itself are found, not the ones of the functions it calls.
+    ///
+    /// ### Example
+    /// ```rust
+    /// /// Returns the first element.
+    /// ///
+    /// /// # Panics
+    /// ///
+    /// /// Panics if the slice is empty.
+    /// pub fn first(values: &[u32]) -> Option<u32> {
+    ///     values.first().copied()
+    /// }
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub UNNECESSARY_DOC_SECTIONS,
+    nursery,
+    "documentation sections for behavior the function doesn't have"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks the words of the documentation against a bundled English word list and the
+    /// dictionaries configured with `doc-spelling-dictionaries`. Code, identifiers and words in
+    /// `doc-valid-idents` are skipped.
+    ///
+    /// ### Why is this bad?
+    /// Typos in the documentation of public items end up in the rendered documentation.
+    ///
+    /// ### Known problems
+    /// Names and technical terms are usually missing from the word list, and have to be added
+    /// to a project dictionary.
+    ///
+    /// ### Example
+    /// ```rust
+    /// /// Returns the lenght of the message.
+    /// fn len() -> usize { 0 }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// /// Returns the length of the message.
+    /// fn len() -> usize { 0 }
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub DOC_SPELLING,
+    restriction,
+    "possibly misspelled words in documentation"
+}
+
 #[expect(clippy::module_name_repetitions)]
 #[derive(Clone)]
 pub struct DocMarkdown {
     valid_idents: FxHashSet<String>,
+    doc_sections: Vec<DocSectionRule>,
+    spelling_dictionaries: Vec<String>,
+    /// The words of the spelling dictionaries, read when checking the crate
+    spelling_words: FxHashSet<String>,
     in_trait_impl: bool,
 }
 
 impl DocMarkdown {
-    pub fn new(valid_idents: FxHashSet<String>) -> Self {
+    pub fn new(
+        valid_idents: FxHashSet<String>,
+        doc_sections: Vec<DocSectionRule>,
+        spelling_dictionaries: Vec<String>,
+    ) -> Self {
         Self {
             valid_idents,
+            doc_sections,
+            spelling_dictionaries,
+            spelling_words: FxHashSet::default(),
             in_trait_impl: false,
         }
     }
 }
 
-impl_lint_pass!(DocMarkdown =>
-    [DOC_MARKDOWN, MISSING_SAFETY_DOC, MISSING_ERRORS_DOC, MISSING_PANICS_DOC, NEEDLESS_DOCTEST_MAIN]
-);
+impl_lint_pass!(DocMarkdown => [
+    DOC_MARKDOWN,
+    MISSING_SAFETY_DOC,
+    MISSING_ERRORS_DOC,
+    MISSING_PANICS_DOC,
+    NEEDLESS_DOCTEST_MAIN,
+    DOC_UNRESOLVED_LINKS,
+    DOC_LINKS_TO_PRIVATE_ITEMS,
+    UNRELATED_DOC_EXAMPLES,
+    DOCTEST_ATTRIBUTES_WITHOUT_REASON,
+    MISSING_DOC_SECTIONS,
+    UNNECESSARY_DOC_SECTIONS,
+    DOC_SPELLING,
+]);
 
 impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
     fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        if !is_lint_allowed(cx, DOC_SPELLING, hir::CRATE_HIR_ID) {
+            self.spelling_words = spelling::read_dictionaries(cx, &self.spelling_dictionaries);
+        }
         let attrs = cx.tcx.hir().attrs(hir::CRATE_HIR_ID);
-        check_attrs(cx, &self.valid_idents, attrs);
+        check_attrs(cx, &self.valid_idents, &self.spelling_words, CRATE_DEF_ID, attrs);
     }
 
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
         let attrs = cx.tcx.hir().attrs(item.hir_id());
-        let headers = check_attrs(cx, &self.valid_idents, attrs);
+        let headers = check_attrs(cx, &self.valid_idents, &self.spelling_words, item.def_id, attrs);
         match item.kind {
             hir::ItemKind::Fn(ref sig, _, body_id) => {
                 if !(is_entrypoint_fn(cx, item.def_id.to_def_id()) || in_external_macro(cx.tcx.sess, item.span)) {
@@ -235,9 +473,19 @@ impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
                         cx,
                         typeck_results: cx.tcx.typeck(item.def_id),
                         panic_span: None,
+                        implicit_panics: false,
                     };
                     fpu.visit_expr(&body.value);
-                    lint_for_missing_headers(cx, item.def_id, item.span, sig, headers, Some(body_id), fpu.panic_span);
+                    lint_for_missing_headers(
+                        cx,
+                        item.def_id,
+                        item.span,
+                        sig,
+                        &headers,
+                        Some(body_id),
+                        fpu.panic_span,
+                        &self.doc_sections,
+                    );
                 }
             },
             hir::ItemKind::Impl(impl_) => {
@@ -255,6 +503,28 @@ impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
             },
             _ => (),
         }
+
+        let kind = match item.kind {
+            hir::ItemKind::Struct(..) => DocSectionItems::Struct,
+            hir::ItemKind::Enum(..) => DocSectionItems::Enum,
+            hir::ItemKind::Union(..) => DocSectionItems::Union,
+            hir::ItemKind::Trait(_, hir::Unsafety::Unsafe, ..) => DocSectionItems::UnsafeTrait,
+            hir::ItemKind::Trait(..) => DocSectionItems::Trait,
+            hir::ItemKind::Const(..) => DocSectionItems::Const,
+            hir::ItemKind::Static(..) => DocSectionItems::Static,
+            hir::ItemKind::TyAlias(..) => DocSectionItems::Type,
+            hir::ItemKind::Mod(..) => DocSectionItems::Mod,
+            _ => return,
+        };
+        // Unsafe traits are traits as well
+        let kinds = if kind == DocSectionItems::UnsafeTrait {
+            vec![DocSectionItems::Any, DocSectionItems::Trait, kind]
+        } else {
+            vec![DocSectionItems::Any, kind]
+        };
+        if is_documented_api(cx, item.def_id) && !in_external_macro(cx.tcx.sess, item.span) {
+            check_doc_sections(cx, item.span, &kinds, &headers, &self.doc_sections);
+        }
     }
 
     fn check_item_post(&mut self, _cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
@@ -265,17 +535,26 @@ impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
 
     fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'_>) {
         let attrs = cx.tcx.hir().attrs(item.hir_id());
-        let headers = check_attrs(cx, &self.valid_idents, attrs);
+        let headers = check_attrs(cx, &self.valid_idents, &self.spelling_words, item.def_id, attrs);
         if let hir::TraitItemKind::Fn(ref sig, ..) = item.kind {
             if !in_external_macro(cx.tcx.sess, item.span) {
-                lint_for_missing_headers(cx, item.def_id, item.span, sig, headers, None, None);
+                lint_for_missing_headers(
+                    cx,
+                    item.def_id,
+                    item.span,
+                    sig,
+                    &headers,
+                    None,
+                    None,
+                    &self.doc_sections,
+                );
             }
         }
     }
 
     fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'_>) {
         let attrs = cx.tcx.hir().attrs(item.hir_id());
-        let headers = check_attrs(cx, &self.valid_idents, attrs);
+        let headers = check_attrs(cx, &self.valid_idents, &self.spelling_words, item.def_id, attrs);
         if self.in_trait_impl || in_external_macro(cx.tcx.sess, item.span) {
             return;
         }
@@ -285,37 +564,41 @@ impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
                 cx,
                 typeck_results: cx.tcx.typeck(item.def_id),
                 panic_span: None,
+                implicit_panics: false,
             };
             fpu.visit_expr(&body.value);
-            lint_for_missing_headers(cx, item.def_id, item.span, sig, headers, Some(body_id), fpu.panic_span);
+            lint_for_missing_headers(
+                cx,
+                item.def_id,
+                item.span,
+                sig,
+                &headers,
+                Some(body_id),
+                fpu.panic_span,
+                &self.doc_sections,
+            );
         }
     }
 }
 
+#[expect(clippy::too_many_arguments)]
 fn lint_for_missing_headers<'tcx>(
     cx: &LateContext<'tcx>,
     def_id: LocalDefId,
     span: impl Into<MultiSpan> + Copy,
     sig: &hir::FnSig<'_>,
-    headers: DocHeaders,
+    headers: &DocHeaders,
     body_id: Option<hir::BodyId>,
     panic_span: Option<Span>,
+    doc_sections: &[DocSectionRule],
 ) {
-    if !cx.access_levels.is_exported(def_id) {
-        return; // Private functions do not require doc comments
-    }
-
-    // do not lint if any parent has `#[doc(hidden)]` attribute (#7347)
-    if cx
-        .tcx
-        .hir()
-        .parent_iter(cx.tcx.hir().local_def_id_to_hir_id(def_id))
-        .any(|(id, _node)| is_doc_hidden(cx.tcx.hir().attrs(id)))
-    {
+    if !is_documented_api(cx, def_id) {
         return;
     }
 
-    if !headers.safety && sig.header.unsafety == hir::Unsafety::Unsafe {
+    let is_unsafe = sig.header.unsafety == hir::Unsafety::Unsafe;
+    let returns_result = returns_result(cx, def_id, body_id);
+    if !headers.safety && is_unsafe {
         span_lint(
             cx,
             MISSING_SAFETY_DOC,
@@ -333,36 +616,121 @@ fn lint_for_missing_headers<'tcx>(
             "first possible panic found here",
         );
     }
-    if !headers.errors {
-        let hir_id = cx.tcx.hir().local_def_id_to_hir_id(def_id);
-        if is_type_diagnostic_item(cx, return_ty(cx, hir_id), sym::Result) {
+    if !headers.errors && returns_result {
+        span_lint(
+            cx,
+            MISSING_ERRORS_DOC,
+            span,
+            "docs for function returning `Result` missing `# Errors` section",
+        );
+    }
+
+    let mut kinds = vec![DocSectionItems::Any, DocSectionItems::Fn];
+    if sig.header.is_async() {
+        kinds.push(DocSectionItems::AsyncFn);
+    }
+    if is_unsafe {
+        kinds.push(DocSectionItems::UnsafeFn);
+    }
+    if returns_result {
+        kinds.push(DocSectionItems::ResultFn);
+    }
+    check_doc_sections(cx, span, &kinds, headers, doc_sections);
+
+    for section in headers.sections.iter().flatten() {
+        let msg = if section.is("Panics")
+            && panic_span.is_none()
+            && let Some(body_id) = body_id
+            && !may_panic(cx, body_id)
+        {
+            "docs for function without a reachable panic have a `# Panics` section"
+        } else if section.is("Errors") && !returns_result {
+            "docs for function not returning `Result` have an `# Errors` section"
+        } else if section.is("Safety") && !is_unsafe {
+            "docs for safe function have a `# Safety` section"
+        } else {
+            continue;
+        };
+        span_lint(cx, UNNECESSARY_DOC_SECTIONS, section.span, msg);
+    }
+}
+
+/// Checks if the item is exported and not hidden from the documentation.
+fn is_documented_api(cx: &LateContext<'_>, def_id: LocalDefId) -> bool {
+    // do not lint if any parent has `#[doc(hidden)]` attribute (#7347)
+    cx.access_levels.is_exported(def_id)
+        && !cx
+            .tcx
+            .hir()
+            .parent_iter(cx.tcx.hir().local_def_id_to_hir_id(def_id))
+            .any(|(id, _node)| is_doc_hidden(cx.tcx.hir().attrs(id)))
+}
+
+fn returns_result(cx: &LateContext<'_>, def_id: LocalDefId, body_id: Option<hir::BodyId>) -> bool {
+    let hir_id = cx.tcx.hir().local_def_id_to_hir_id(def_id);
+    if is_type_diagnostic_item(cx, return_ty(cx, hir_id), sym::Result) {
+        return true;
+    }
+    if_chain! {
+        if let Some(body_id) = body_id;
+        if let Some(future) = cx.tcx.lang_items().future_trait();
+        let typeck = cx.tcx.typeck_body(body_id);
+        let body = cx.tcx.hir().body(body_id);
+        let ret_ty = typeck.expr_ty(&body.value);
+        if implements_trait(cx, ret_ty, future, &[]);
+        if let ty::Opaque(_, subs) = ret_ty.kind();
+        if let Some(gen) = subs.types().next();
+        if let ty::Generator(_, subs, _) = gen.kind();
+        then {
+            is_type_diagnostic_item(cx, subs.as_generator().return_ty(), sym::Result)
+        } else {
+            false
+        }
+    }
+}
+
+/// Checks the documentation for the sections configured for any of the kinds of the item, and
+/// their order.
+fn check_doc_sections(
+    cx: &LateContext<'_>,
+    span: impl Into<MultiSpan> + Copy,
+    kinds: &[DocSectionItems],
+    headers: &DocHeaders,
+    doc_sections: &[DocSectionRule],
+) {
+    let Some(sections) = &headers.sections else { return };
+    let mut reported = FxHashSet::default();
+    for rule in doc_sections.iter().filter(|rule| kinds.contains(&rule.items)) {
+        for name in &rule.sections {
+            if !sections.iter().any(|section| section.is(name)) && reported.insert(name.to_lowercase()) {
+                span_lint(
+                    cx,
+                    MISSING_DOC_SECTIONS,
+                    span,
+                    &format!("docs for {} missing `# {}` section", rule.items.descr(), name),
+                );
+            }
+        }
+
+        // The positions of the present sections, in the configured order
+        let positions: Vec<_> = rule
+            .sections
+            .iter()
+            .filter_map(|name| Some((sections.iter().position(|section| section.is(name))?, name)))
+            .collect();
+        if let Some([(_, previous), (position, name)]) = positions
+            .array_windows::<2>()
+            .find(|[(previous, _), (position, _)]| position < previous)
+        {
             span_lint(
                 cx,
-                MISSING_ERRORS_DOC,
-                span,
-                "docs for function returning `Result` missing `# Errors` section",
+                MISSING_DOC_SECTIONS,
+                sections[*position].span,
+                &format!(
+                    "the `# {}` section should come after the `# {}` section",
+                    name, previous
+                ),
             );
-        } else {
-            if_chain! {
-                if let Some(body_id) = body_id;
-                if let Some(future) = cx.tcx.lang_items().future_trait();
-                let typeck = cx.tcx.typeck_body(body_id);
-                let body = cx.tcx.hir().body(body_id);
-                let ret_ty = typeck.expr_ty(&body.value);
-                if implements_trait(cx, ret_ty, future, &[]);
-                if let ty::Opaque(_, subs) = ret_ty.kind();
-                if let Some(gen) = subs.types().next();
-                if let ty::Generator(_, subs, _) = gen.kind();
-                if is_type_diagnostic_item(cx, subs.as_generator().return_ty(), sym::Result);
-                then {
-                    span_lint(
-                        cx,
-                        MISSING_ERRORS_DOC,
-                        span,
-                        "docs for function returning `Result` missing `# Errors` section",
-                    );
-                }
-            }
         }
     }
 }
@@ -416,14 +784,33 @@ pub fn strip_doc_comment_decoration(doc: &str, comment_kind: CommentKind, span:
     (no_stars, sizes)
 }
 
-#[derive(Copy, Clone)]
 struct DocHeaders {
     safety: bool,
     errors: bool,
     panics: bool,
+    /// The headings of the documentation, `None` if they aren't known
+    sections: Option<Vec<DocSection>>,
+}
+
+struct DocSection {
+    name: String,
+    /// The span of the heading
+    span: Span,
+}
+
+impl DocSection {
+    fn is(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+    }
 }
 
-fn check_attrs<'a>(cx: &LateContext<'_>, valid_idents: &FxHashSet<String>, attrs: &'a [Attribute]) -> DocHeaders {
+fn check_attrs<'a>(
+    cx: &LateContext<'_>,
+    valid_idents: &FxHashSet<String>,
+    spelling_words: &FxHashSet<String>,
+    owner: LocalDefId,
+    attrs: &'a [Attribute],
+) -> DocHeaders {
     use pulldown_cmark::{BrokenLink, CowStr, Options};
     /// We don't want the parser to choke on intra doc links. Since we don't
     /// actually care about rendering them, just pretend that all broken links are
@@ -448,6 +835,7 @@ fn check_attrs<'a>(cx: &LateContext<'_>, valid_idents: &FxHashSet<String>, attrs
                 safety: true,
                 errors: true,
                 panics: true,
+                sections: None,
             };
         }
     }
@@ -464,6 +852,7 @@ fn check_attrs<'a>(cx: &LateContext<'_>, valid_idents: &FxHashSet<String>, attrs
             safety: false,
             errors: false,
             panics: false,
+            sections: Some(Vec::new()),
         };
     }
 
@@ -487,14 +876,18 @@ fn check_attrs<'a>(cx: &LateContext<'_>, valid_idents: &FxHashSet<String>, attrs
             (previous, current) => Err(((previous, previous_range), (current, current_range))),
         }
     });
-    check_doc(cx, valid_idents, events, &spans)
+    check_doc(cx, valid_idents, spelling_words, owner, &doc, events, &spans)
 }
 
 const RUST_CODE: &[&str] = &["rust", "no_run", "should_panic", "compile_fail"];
 
+#[allow(clippy::too_many_lines)] // Only a big match statement
 fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize>)>>(
     cx: &LateContext<'_>,
     valid_idents: &FxHashSet<String>,
+    spelling_words: &FxHashSet<String>,
+    owner: LocalDefId,
+    doc: &str,
     events: Events,
     spans: &[(usize, Span)],
 ) -> DocHeaders {
@@ -509,10 +902,24 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
         safety: false,
         errors: false,
         panics: false,
+        sections: Some(Vec::new()),
     };
+    let mut heading = String::new();
+    let check_spelling = !is_lint_allowed(cx, DOC_SPELLING, cx.tcx.hir().local_def_id_to_hir_id(owner));
     let mut in_code = false;
     let mut in_link = None;
     let mut in_heading = false;
+    let mut heading_level = HeadingLevel::H1;
+    let mut examples: Option<ExamplesSection> = None;
+    let mut missing_reason = None;
+    // The documentation of items generated by macros may describe something else, like the lint
+    // declarations of this crate
+    let from_expansion = owner != CRATE_DEF_ID && cx.tcx.def_span(owner).from_expansion();
+    let item_name = if owner == CRATE_DEF_ID || from_expansion {
+        None
+    } else {
+        cx.tcx.opt_item_name(owner.to_def_id())
+    };
     let mut is_rust = false;
     let mut edition = None;
     let mut ticks_unbalanced = false;
@@ -523,6 +930,12 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
             Start(CodeBlock(ref kind)) => {
                 in_code = true;
                 if let CodeBlockKind::Fenced(lang) = kind {
+                    if lang
+                        .split(',')
+                        .any(|item| item == "ignore" || item.starts_with("ignore-") || item == "no_run")
+                    {
+                        missing_reason = Some(first_line_of_span(cx, get_current_span(spans, range.start).1));
+                    }
                     for item in lang.split(',') {
                         if item == "ignore" {
                             is_rust = false;
@@ -540,20 +953,46 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
             End(CodeBlock(_)) => {
                 in_code = false;
                 is_rust = false;
+                if let Some(span) = missing_reason.take() {
+                    lint_missing_reason(cx, span);
+                }
+            },
+            Start(Link(kind, url, _)) => {
+                if !from_expansion
+                    && let Some(path) = links::link_path(kind, &url, &doc[range.clone()])
+                {
+                    let (begin, span) = get_current_span(spans, range.start);
+                    let lo = span.lo() + BytePos::from_usize(range.start - begin);
+                    let hi = (lo + BytePos::from_usize(range.len())).min(span.hi());
+                    links::check_link(cx, owner, path, span.with_lo(lo).with_hi(hi));
+                }
+                in_link = Some(url);
             },
-            Start(Link(_, url, _)) => in_link = Some(url),
             End(Link(..)) => in_link = None,
             Start(Heading(_, _, _) | Paragraph | Item) => {
-                if let Start(Heading(_, _, _)) = event {
-                    in_heading = true;
-                }
                 ticks_unbalanced = false;
                 let (_, span) = get_current_span(spans, range.start);
                 paragraph_span = first_line_of_span(cx, span);
+                if let Start(Heading(level, _, _)) = event {
+                    in_heading = true;
+                    heading_level = level;
+                    heading.clear();
+                    if let Some(section) = &examples
+                        && level <= section.level
+                    {
+                        check_examples(cx, item_name, examples.take());
+                    }
+                }
             },
             End(Heading(_, _, _) | Paragraph | Item) => {
                 if let End(Heading(_, _, _)) = event {
                     in_heading = false;
+                    if let Some(sections) = &mut headers.sections {
+                        sections.push(DocSection {
+                            name: heading.trim().to_owned(),
+                            span: paragraph_span,
+                        });
+                    }
                 }
                 if ticks_unbalanced {
                     span_lint_and_help(
@@ -567,6 +1006,9 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
                 } else {
                     for (text, span) in text_to_check {
                         check_text(cx, valid_idents, &text, span);
+                        if check_spelling {
+                            spelling::check_text(cx, valid_idents, spelling_words, &text, span);
+                        }
                     }
                 }
                 text_to_check = Vec::new();
@@ -578,6 +1020,9 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
                 let (begin, span) = get_current_span(spans, range.start);
                 paragraph_span = paragraph_span.with_hi(span.hi());
                 ticks_unbalanced |= text.contains('`') && !in_code;
+                if in_heading {
+                    heading.push_str(&text);
+                }
                 if Some(&text) == in_link.as_ref() || ticks_unbalanced {
                     // Probably a link of the form `<http://example.com>`
                     // Which are represented as a link to "http://example.com" with
@@ -590,8 +1035,27 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
                 headers.safety |= in_heading && trimmed_text == "Implementation Safety";
                 headers.errors |= in_heading && trimmed_text == "Errors";
                 headers.panics |= in_heading && trimmed_text == "Panics";
+                if in_heading && matches!(trimmed_text, "Examples" | "Example") {
+                    examples = Some(ExamplesSection {
+                        level: heading_level,
+                        span: paragraph_span,
+                        has_code: false,
+                        mentions_item: false,
+                    });
+                }
                 if in_code {
+                    if let Some(span) = missing_reason.take()
+                        && !has_reason(&text)
+                    {
+                        lint_missing_reason(cx, span);
+                    }
                     if is_rust {
+                        if let Some(section) = &mut examples
+                            && let Some(name) = item_name
+                        {
+                            section.has_code = true;
+                            section.mentions_item |= mentions_word(&text, name.as_str());
+                        }
                         let edition = edition.unwrap_or_else(|| cx.tcx.sess.edition());
                         check_code(cx, &text, edition, span);
                     }
@@ -603,9 +1067,68 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
             },
         }
     }
+    check_examples(cx, item_name, examples);
     headers
 }
 
+/// An `# Examples` section of the documentation.
+struct ExamplesSection {
+    level: HeadingLevel,
+    /// The span of the heading
+    span: Span,
+    /// Whether the section has Rust code blocks
+    has_code: bool,
+    /// Whether any of the code blocks mentions the documented item
+    mentions_item: bool,
+}
+
+fn check_examples(cx: &LateContext<'_>, item_name: Option<Symbol>, examples: Option<ExamplesSection>) {
+    if let Some(name) = item_name
+        && let Some(section) = examples
+        && section.has_code
+        && !section.mentions_item
+    {
+        span_lint_and_help(
+            cx,
+            UNRELATED_DOC_EXAMPLES,
+            section.span,
+            &format!("the examples don't use `{}`", name),
+            None,
+            "make sure the examples belong to this item",
+        );
+    }
+}
+
+fn mentions_word(code: &str, word: &str) -> bool {
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+    code.match_indices(word).any(|(start, _)| {
+        !code[..start].ends_with(is_ident_char) && !code[start + word.len()..].starts_with(is_ident_char)
+    })
+}
+
+/// Checks if the code block starts with a comment, which may be hidden from the rendered
+/// documentation.
+fn has_reason(code: &str) -> bool {
+    code.lines()
+        .map(str::trim_start)
+        .find(|line| !line.is_empty())
+        .map_or(false, |line| {
+            let line = line.strip_prefix('#').map_or(line, str::trim_start);
+            line.starts_with("//")
+        })
+}
+
+fn lint_missing_reason(cx: &LateContext<'_>, span: Span) {
+    span_lint_and_help(
+        cx,
+        DOCTEST_ATTRIBUTES_WITHOUT_REASON,
+        span,
+        "this code block is ignored or not run without an explanation",
+        None,
+        "start the code block with a comment explaining why",
+    );
+}
+
 fn get_current_span(spans: &[(usize, Span)], idx: usize) -> (usize, Span) {
     let index = match spans.binary_search_by(|c| c.0.cmp(&idx)) {
         Ok(o) => o,
@@ -801,10 +1324,44 @@ fn check_word(cx: &LateContext<'_>, word: &str, span: Span) {
     }
 }
 
+/// Checks if the body may panic, including the panics `MISSING_PANICS_DOC` ignores.
+fn may_panic(cx: &LateContext<'_>, body_id: hir::BodyId) -> bool {
+    let mut fpu = FindPanicUnwrap {
+        cx,
+        typeck_results: cx.tcx.typeck_body(body_id),
+        panic_span: None,
+        implicit_panics: true,
+    };
+    fpu.visit_expr(&cx.tcx.hir().body(body_id).value);
+    fpu.panic_span.is_some()
+}
+
 struct FindPanicUnwrap<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
     panic_span: Option<Span>,
     typeck_results: &'tcx ty::TypeckResults<'tcx>,
+    /// Whether to also look for `expect`, indexing and integer division, which are usually not
+    /// documented as panics.
+    implicit_panics: bool,
+}
+
+impl<'a, 'tcx> FindPanicUnwrap<'a, 'tcx> {
+    fn is_implicit_panic(&self, expr: &Expr<'_>) -> bool {
+        match expr.kind {
+            ExprKind::Index(..) => true,
+            ExprKind::Binary(op, _, divisor) | ExprKind::AssignOp(op, _, divisor)
+                if matches!(op.node, BinOpKind::Div | BinOpKind::Rem) =>
+            {
+                self.typeck_results.expr_ty(divisor).is_integral()
+            },
+            ExprKind::MethodCall(path, [receiver, _], _) if path.ident.name == sym::expect => {
+                let receiver_ty = self.typeck_results.expr_ty(receiver).peel_refs();
+                is_type_diagnostic_item(self.cx, receiver_ty, sym::Option)
+                    || is_type_diagnostic_item(self.cx, receiver_ty, sym::Result)
+            },
+            _ => false,
+        }
+    }
 }
 
 impl<'a, 'tcx> Visitor<'tcx> for FindPanicUnwrap<'a, 'tcx> {
@@ -836,6 +1393,10 @@ impl<'a, 'tcx> Visitor<'tcx> for FindPanicUnwrap<'a, 'tcx> {
             }
         }
 
+        if self.implicit_panics && self.is_implicit_panic(expr) {
+            self.panic_span = Some(expr.span);
+        }
+
         // and check sub-expressions
         intravisit::walk_expr(self, expr);
     }
@@ -0,0 +1,180 @@
+//! Spell checking of documentation, for `DOC_SPELLING`.
+
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::source_map::{BytePos, Span};
+use rustc_span::Pos;
+use std::fs;
+use std::sync::LazyLock;
+
+use super::DOC_SPELLING;
+
+/// The bundled English words.
+static WORDS: LazyLock<FxHashSet<&'static str>> = LazyLock::new(|| {
+    include_str!("words.txt")
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .collect()
+});
+
+/// The bundled English words, indexed by their length, to only compare a misspelled word with the
+/// words of a similar length.
+static WORDS_BY_LEN: LazyLock<Vec<Vec<&'static str>>> = LazyLock::new(|| {
+    let mut words_by_len = Vec::new();
+    for word in WORDS.iter() {
+        if words_by_len.len() <= word.len() {
+            words_by_len.resize_with(word.len() + 1, Vec::new);
+        }
+        words_by_len[word.len()].push(*word);
+    }
+    words_by_len
+});
+
+/// Reads the words of the project dictionaries, reporting the files which can't be read.
+pub(super) fn read_dictionaries(cx: &LateContext<'_>, paths: &[String]) -> FxHashSet<String> {
+    let mut words = FxHashSet::default();
+    for path in paths {
+        match fs::read_to_string(path) {
+            Ok(content) => words.extend(
+                content
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .map(str::to_lowercase),
+            ),
+            Err(error) => {
+                cx.sess()
+                    .err(&format!("error reading the spelling dictionary `{}`: {}", path, error));
+            },
+        }
+    }
+    words
+}
+
+pub(super) fn check_text(
+    cx: &LateContext<'_>,
+    valid_idents: &FxHashSet<String>,
+    project_words: &FxHashSet<String>,
+    text: &str,
+    span: Span,
+) {
+    for chunk in text.split_whitespace() {
+        // Trim punctuation and quotes, as in `(see "foo").`
+        let chunk = chunk.trim_matches(|c: char| !c.is_alphanumeric());
+        // Identifiers, paths, numbers and URLs aren't words
+        if !chunk.chars().all(|c| c.is_ascii_alphabetic() || c == '\'' || c == '-') {
+            continue;
+        }
+        for word in chunk.split('-') {
+            if is_known(word, valid_idents, project_words) {
+                continue;
+            }
+
+            let offset = word.as_ptr() as usize - text.as_ptr() as usize;
+            let span = Span::new(
+                span.lo() + BytePos::from_usize(offset),
+                span.lo() + BytePos::from_usize(offset + word.len()),
+                span.ctxt(),
+                span.parent(),
+            );
+            let msg = format!("`{}` may be misspelled", word);
+            if let Some(correction) = correction(word) {
+                span_lint_and_sugg(
+                    cx,
+                    DOC_SPELLING,
+                    span,
+                    &msg,
+                    "did you mean",
+                    correction,
+                    Applicability::MaybeIncorrect,
+                );
+            } else {
+                span_lint_and_help(
+                    cx,
+                    DOC_SPELLING,
+                    span,
+                    &msg,
+                    None,
+                    "if it's spelled correctly, add it to a dictionary in `doc-spelling-dictionaries`",
+                );
+            }
+        }
+    }
+}
+
+fn is_known(word: &str, valid_idents: &FxHashSet<String>, project_words: &FxHashSet<String>) -> bool {
+    // Single letters, acronyms like `HTTP` and mixed case identifiers like `GitHub` are skipped
+    if word.len() <= 1 || word.chars().skip(1).any(|c| c.is_ascii_uppercase()) || valid_idents.contains(word) {
+        return true;
+    }
+    let word = word.to_ascii_lowercase();
+    let word = word.trim_matches('\'');
+    // Possessives, as in `the crate's` and `the crates'`
+    let base = word.strip_suffix("'s").unwrap_or(word);
+    [word, base]
+        .iter()
+        .any(|word| WORDS.contains(word) || project_words.contains(*word))
+}
+
+/// Returns the closest word which is at most one edit away from short words, or two from longer
+/// ones. Of the words at the same distance, the ones made of the same letters are preferred, as in
+/// `teh` and `the`, and then the ones starting with the same letter.
+fn correction(word: &str) -> Option<String> {
+    let lower = word.to_ascii_lowercase();
+    let max_distance = if word.len() <= 4 { 1 } else { 2 };
+    let (_, _, _, correction) = WORDS_BY_LEN
+        .iter()
+        .take(lower.len() + max_distance + 1)
+        .skip(lower.len().saturating_sub(max_distance))
+        .flatten()
+        .filter_map(|&candidate| {
+            let distance = edit_distance(lower.as_bytes(), candidate.as_bytes());
+            (distance <= max_distance).then(|| {
+                (
+                    distance,
+                    !same_letters(&lower, candidate),
+                    lower.as_bytes()[0] != candidate.as_bytes()[0],
+                    candidate,
+                )
+            })
+        })
+        .min()?;
+
+    // Keep the capitalization of the first letter
+    Some(if word.starts_with(|c: char| c.is_ascii_uppercase()) {
+        correction[..1].to_ascii_uppercase() + &correction[1..]
+    } else {
+        correction.to_owned()
+    })
+}
+
+fn same_letters(a: &str, b: &str) -> bool {
+    let mut a = a.as_bytes().to_vec();
+    let mut b = b.as_bytes().to_vec();
+    a.sort_unstable();
+    b.sort_unstable();
+    a == b
+}
+
+/// The number of insertions, deletions, substitutions and transpositions of adjacent characters
+/// which turn `a` into `b`.
+fn edit_distance(a: &[u8], b: &[u8]) -> usize {
+    let mut before_previous = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (previous[j] + 1).min(current[j - 1] + 1).min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before_previous[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut before_previous, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
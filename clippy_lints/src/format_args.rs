@@ -1,16 +1,23 @@
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
-use clippy_utils::is_diag_trait_item;
-use clippy_utils::macros::{is_format_macro, FormatArgsArg, FormatArgsExpn};
+use clippy_utils::macros::{is_format_macro, is_log_macro, FormatArgsArg, FormatArgsExpn};
 use clippy_utils::source::snippet_opt;
 use clippy_utils::ty::implements_trait;
+use clippy_utils::{is_diag_trait_item, meets_msrv, msrvs};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind};
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_hir::def::Res;
+use rustc_hir::{Expr, ExprKind, QPath};
+use rustc_lexer::unescape::{unescape_literal, Mode};
+use rustc_lint::{LateContext, LateLintPass, Lint};
 use rustc_middle::ty::adjustment::{Adjust, Adjustment};
 use rustc_middle::ty::Ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
-use rustc_span::{sym, ExpnData, ExpnKind, Span, Symbol};
+use rustc_parse_format::{Count, ParseMode, Parser, Piece, Position};
+use rustc_semver::RustcVersion;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::def_id::DefId;
+use rustc_span::hygiene;
+use rustc_span::{sym, ExpnData, ExpnKind, InnerSpan, Span, Symbol};
+use std::iter;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -63,7 +70,107 @@ declare_clippy_lint! {
     "`to_string` applied to a type that implements `Display` in format args"
 }
 
-declare_lint_pass!(FormatArgs => [FORMAT_IN_FORMAT_ARGS, TO_STRING_IN_FORMAT_ARGS]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Detects `format!` within the arguments of a logging macro of the `log`
+    /// or `tracing` crates, such as `log::info!` or `tracing::warn!`. Suggests
+    /// inlining the `format!` call.
+    ///
+    /// ### Why is this bad?
+    /// The logging macros do the formatting themselves, and only when the
+    /// level is enabled. A `format!` argument allocates a temporary `String`
+    /// for every message which is logged.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// log::info!("request: {}", format!("{} {}", method, path));
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// log::info!("request: {} {}", method, path);
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub FORMAT_IN_LOG_ARGS,
+    pedantic,
+    "`format!` used in the arguments of a logging macro"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for [`ToString::to_string`](https://doc.rust-lang.org/std/string/trait.ToString.html#tymethod.to_string)
+    /// applied to a type that implements [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html)
+    /// in the arguments of a logging macro of the `log` or `tracing` crates.
+    ///
+    /// ### Why is this bad?
+    /// Since the type implements `Display`, the use of `to_string` is
+    /// unnecessary, and allocates a temporary `String` for every message which
+    /// is logged.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// log::warn!("connection to {} lost", addr.to_string());
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// log::warn!("connection to {} lost", addr);
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub TO_STRING_IN_LOG_ARGS,
+    pedantic,
+    "`to_string` applied to a type that implements `Display` in the arguments of a logging macro"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Detects local variables passed as arguments of macros that do
+    /// formatting, such as `format!` or `println!`, which could be inlined
+    /// in the format string (e.g. `println!("{}", x)` can be written
+    /// `println!("{x}")`).
+    ///
+    /// ### Why is this bad?
+    /// Inlined arguments are shorter, and read in the order the text is
+    /// formatted.
+    ///
+    /// ### Known problems
+    /// Only plain local variables are inlined, so calls mixing them with
+    /// other arguments are changed to use both styles.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let width = 5;
+    /// # let name = "world";
+    /// println!("hello {:>1$}", name, width);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let width = 5;
+    /// # let name = "world";
+    /// println!("hello {name:>width$}");
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub UNINLINED_FORMAT_ARGS,
+    restriction,
+    "local variables passed as format arguments instead of being inlined in the format string"
+}
+
+pub struct FormatArgs {
+    msrv: Option<RustcVersion>,
+}
+
+impl FormatArgs {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(FormatArgs => [
+    FORMAT_IN_FORMAT_ARGS,
+    TO_STRING_IN_FORMAT_ARGS,
+    FORMAT_IN_LOG_ARGS,
+    TO_STRING_IN_LOG_ARGS,
+    UNINLINED_FORMAT_ARGS,
+]);
 
 impl<'tcx> LateLintPass<'tcx> for FormatArgs {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
@@ -72,7 +179,7 @@ impl<'tcx> LateLintPass<'tcx> for FormatArgs {
             let expr_expn_data = expr.span.ctxt().outer_expn_data();
             let outermost_expn_data = outermost_expn_data(expr_expn_data);
             if let Some(macro_def_id) = outermost_expn_data.macro_def_id;
-            if is_format_macro(cx, macro_def_id);
+            if let Some((format_lint, to_string_lint)) = lints_for_macro(cx, macro_def_id);
             if let ExpnKind::Macro(_, name) = outermost_expn_data.kind;
             if let Some(args) = format_args.args();
             then {
@@ -86,12 +193,29 @@ impl<'tcx> LateLintPass<'tcx> for FormatArgs {
                     if is_aliased(&args, i) {
                         continue;
                     }
-                    check_format_in_format_args(cx, outermost_expn_data.call_site, name, arg.value);
-                    check_to_string_in_format_args(cx, name, arg.value);
+                    check_format_in_format_args(cx, format_lint, outermost_expn_data.call_site, name, arg.value);
+                    check_to_string_in_format_args(cx, to_string_lint, name, arg.value);
+                }
+                if is_format_macro(cx, macro_def_id) && meets_msrv(self.msrv, msrvs::FORMAT_ARGS_CAPTURE) {
+                    check_uninlined_args(cx, &format_args, outermost_expn_data.call_site, macro_def_id, name);
                 }
             }
         }
     }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Returns the `format!` and `to_string` lints to emit for the arguments of the given macro, if
+/// it does formatting.
+fn lints_for_macro(cx: &LateContext<'_>, macro_def_id: DefId) -> Option<(&'static Lint, &'static Lint)> {
+    if is_format_macro(cx, macro_def_id) {
+        Some((FORMAT_IN_FORMAT_ARGS, TO_STRING_IN_FORMAT_ARGS))
+    } else if is_log_macro(cx, macro_def_id) {
+        Some((FORMAT_IN_LOG_ARGS, TO_STRING_IN_LOG_ARGS))
+    } else {
+        None
+    }
 }
 
 fn outermost_expn_data(expn_data: ExpnData) -> ExpnData {
@@ -102,7 +226,13 @@ fn outermost_expn_data(expn_data: ExpnData) -> ExpnData {
     }
 }
 
-fn check_format_in_format_args(cx: &LateContext<'_>, call_site: Span, name: Symbol, arg: &Expr<'_>) {
+fn check_format_in_format_args(
+    cx: &LateContext<'_>,
+    lint: &'static Lint,
+    call_site: Span,
+    name: Symbol,
+    arg: &Expr<'_>,
+) {
     let expn_data = arg.span.ctxt().outer_expn_data();
     if expn_data.call_site.from_expansion() {
         return;
@@ -111,22 +241,16 @@ fn check_format_in_format_args(cx: &LateContext<'_>, call_site: Span, name: Symb
     if !cx.tcx.is_diagnostic_item(sym::format_macro, mac_id) {
         return;
     }
-    span_lint_and_then(
-        cx,
-        FORMAT_IN_FORMAT_ARGS,
-        call_site,
-        &format!("`format!` in `{}!` args", name),
-        |diag| {
-            diag.help(&format!(
-                "combine the `format!(..)` arguments with the outer `{}!(..)` call",
-                name
-            ));
-            diag.help("or consider changing `format!` to `format_args!`");
-        },
-    );
+    span_lint_and_then(cx, lint, call_site, &format!("`format!` in `{}!` args", name), |diag| {
+        diag.help(&format!(
+            "combine the `format!(..)` arguments with the outer `{}!(..)` call",
+            name
+        ));
+        diag.help("or consider changing `format!` to `format_args!`");
+    });
 }
 
-fn check_to_string_in_format_args(cx: &LateContext<'_>, name: Symbol, value: &Expr<'_>) {
+fn check_to_string_in_format_args(cx: &LateContext<'_>, lint: &'static Lint, name: Symbol, value: &Expr<'_>) {
     if_chain! {
         if !value.span.from_expansion();
         if let ExprKind::MethodCall(_, [receiver], _) = value.kind;
@@ -144,7 +268,7 @@ fn check_to_string_in_format_args(cx: &LateContext<'_>, name: Symbol, value: &Ex
                 if n_needed_derefs == 0 {
                     span_lint_and_sugg(
                         cx,
-                        TO_STRING_IN_FORMAT_ARGS,
+                        lint,
                         value.span.with_lo(receiver.span.hi()),
                         &format!("`to_string` applied to a type that implements `Display` in `{}!` args", name),
                         "remove this",
@@ -154,7 +278,7 @@ fn check_to_string_in_format_args(cx: &LateContext<'_>, name: Symbol, value: &Ex
                 } else {
                     span_lint_and_sugg(
                         cx,
-                        TO_STRING_IN_FORMAT_ARGS,
+                        lint,
                         value.span,
                         &format!("`to_string` applied to a type that implements `Display` in `{}!` args", name),
                         "use this",
@@ -167,6 +291,236 @@ fn check_to_string_in_format_args(cx: &LateContext<'_>, name: Symbol, value: &Ex
     }
 }
 
+fn check_uninlined_args(
+    cx: &LateContext<'_>,
+    format_args: &FormatArgsExpn<'_>,
+    call_site: Span,
+    macro_def_id: DefId,
+    name: Symbol,
+) {
+    let format_string_span = format_args.format_string_span;
+    if call_site.from_expansion() || format_string_span.from_expansion() {
+        return;
+    }
+    // Before Rust 2021, `panic!` and `assert!` don't format a lone message
+    if !call_site.rust_2021()
+        && matches!(
+            cx.tcx.get_diagnostic_name(macro_def_id),
+            Some(sym::std_panic_macro | sym::assert_macro | sym::debug_assert_macro)
+        )
+    {
+        return;
+    }
+    let Some(snippet) = snippet_opt(cx, format_string_span) else { return };
+    // Raw strings and format strings built by macros like `concat!` aren't supported
+    let Some(contents) = snippet.strip_prefix('"').and_then(|s| s.strip_suffix('"')) else { return };
+    let mut unescaped = String::with_capacity(contents.len());
+    // The offset in the snippet of each byte of `unescaped`, and of its end
+    let mut offsets = Vec::with_capacity(contents.len() + 1);
+    unescape_literal(contents, Mode::Str, &mut |range, c| {
+        if let Ok(c) = c {
+            unescaped.push(c);
+            offsets.extend(iter::repeat(range.start + 1).take(c.len_utf8()));
+        }
+    });
+    offsets.push(snippet.len() - 1);
+    let Some(placeholders) = parse_placeholders(&unescaped, &offsets) else { return };
+
+    // All the positional arguments are used, so their number is the highest index used plus one
+    let positional_count = placeholders
+        .iter()
+        .flat_map(|placeholder| {
+            let index = match placeholder.position {
+                Position::ArgumentIs(i) | Position::ArgumentImplicitlyIs(i) => Some(i),
+                Position::ArgumentNamed(..) => None,
+            };
+            index.into_iter().chain(placeholder.count_params.iter().copied())
+        })
+        .max()
+        .map_or(0, |i| i + 1);
+    let Some(positional) = format_args.value_args.get(..positional_count) else { return };
+    let arg_spans = positional
+        .iter()
+        .map(|arg| hygiene::walk_chain(arg.span, call_site.ctxt()))
+        .collect::<Vec<_>>();
+    // Values of implicitly captured arguments are in the format string
+    if arg_spans.iter().any(|span| span.lo() < format_string_span.hi()) {
+        return;
+    }
+    let inlined = positional
+        .iter()
+        .map(|arg| inlinable_ident(cx, arg))
+        .collect::<Vec<_>>();
+    if inlined.iter().all(Option::is_none) {
+        return;
+    }
+    let Some(new_snippet) = inline_args(&snippet, &placeholders, &inlined) else { return };
+
+    let mut suggestion = vec![(format_string_span, new_snippet)];
+    let mut previous_end = format_string_span.hi();
+    for (span, ident) in arg_spans.iter().zip(&inlined) {
+        if ident.is_some() {
+            suggestion.push((span.with_lo(previous_end), String::new()));
+        }
+        previous_end = span.hi();
+    }
+    span_lint_and_then(
+        cx,
+        UNINLINED_FORMAT_ARGS,
+        call_site,
+        &format!("variables can be used directly in the `{}!` string", name),
+        |diag| {
+            diag.multipart_suggestion("change this to", suggestion, Applicability::MachineApplicable);
+        },
+    );
+}
+
+/// A `{..}` placeholder of a format string.
+struct Placeholder<'a> {
+    /// The position of the placeholder in the snippet of the format string
+    place: InnerSpan,
+    position: Position<'a>,
+    /// The positional arguments used as width or precision, like `1$` in `{:>1$}`
+    count_params: Vec<usize>,
+}
+
+/// Parses the placeholders of the unescaped format string, `offsets` mapping its byte offsets to
+/// the ones of the snippet.
+fn parse_placeholders<'a>(unescaped: &'a str, offsets: &[usize]) -> Option<Vec<Placeholder<'a>>> {
+    // With an empty literal as the snippet, the parser doesn't try to map the offsets through the
+    // escapes itself, and only adds one for the opening quote
+    let mut parser = Parser::new(unescaped, None, Some("\"\"".to_owned()), false, ParseMode::Format);
+    let mut placeholders = Vec::new();
+    while let Some(piece) = parser.next() {
+        if let Piece::NextArgument(arg) = piece {
+            let count_params = [arg.format.width, arg.format.precision]
+                .into_iter()
+                .filter_map(|count| {
+                    if let Count::CountIsParam(i) = count {
+                        Some(i)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            let place = parser.arg_places.last()?;
+            // The closing `}` is the last byte of the placeholder
+            placeholders.push(Placeholder {
+                place: InnerSpan::new(offsets[place.start - 1], offsets[place.end - 2] + 1),
+                position: arg.position,
+                count_params,
+            });
+        }
+    }
+    parser.errors.is_empty().then_some(placeholders)
+}
+
+/// Rewrites the snippet of the format string with the `inlined` positional arguments, renumbering
+/// the remaining ones.
+fn inline_args(snippet: &str, placeholders: &[Placeholder<'_>], inlined: &[Option<Symbol>]) -> Option<String> {
+    // The indices of the remaining positional arguments once the inlined ones are removed
+    let new_indices = inlined
+        .iter()
+        .scan(0, |next, ident| {
+            let index = *next;
+            *next += usize::from(ident.is_none());
+            Some(index)
+        })
+        .collect::<Vec<_>>();
+    let replace_index = |i: usize| match inlined[i] {
+        Some(ident) => ident.to_string(),
+        None => new_indices[i].to_string(),
+    };
+
+    let mut new_snippet = String::with_capacity(snippet.len());
+    let mut last_end = 0;
+    let mut next_implicit = 0;
+    for placeholder in placeholders {
+        let place = placeholder.place;
+        let inner = snippet[place.start..place.end].strip_prefix('{')?.strip_suffix('}')?;
+        // `.*` takes the precision from an implicit argument
+        if inner.contains(".*") {
+            return None;
+        }
+        let (position_text, spec) = match inner.split_once(':') {
+            Some((position_text, spec)) => (position_text, Some(spec)),
+            None => (inner, None),
+        };
+        let new_position = match placeholder.position {
+            Position::ArgumentImplicitlyIs(i) if position_text.is_empty() => {
+                if inlined[i].is_none() && new_indices[i] == next_implicit {
+                    next_implicit += 1;
+                    String::new()
+                } else {
+                    replace_index(i)
+                }
+            },
+            Position::ArgumentIs(i) if position_text.parse() == Ok(i) => replace_index(i),
+            Position::ArgumentNamed(..) => position_text.to_owned(),
+            _ => return None,
+        };
+
+        new_snippet.push_str(&snippet[last_end..place.start]);
+        new_snippet.push('{');
+        new_snippet.push_str(&new_position);
+        if let Some(spec) = spec {
+            new_snippet.push(':');
+            new_snippet.push_str(&replace_count_params(spec, &placeholder.count_params, replace_index)?);
+        }
+        new_snippet.push('}');
+        last_end = place.end;
+    }
+    new_snippet.push_str(&snippet[last_end..]);
+    Some(new_snippet)
+}
+
+/// Returns the name of the local variable if the argument is only its identifier.
+fn inlinable_ident(cx: &LateContext<'_>, arg: &Expr<'_>) -> Option<Symbol> {
+    if let ExprKind::Path(QPath::Resolved(None, path)) = arg.kind
+        && let Res::Local(_) = path.res
+        && let [segment] = path.segments
+        && segment.args.is_none()
+        && !arg.span.from_expansion()
+        && snippet_opt(cx, arg.span).as_deref() == Some(segment.ident.as_str())
+    {
+        Some(segment.ident.name)
+    } else {
+        None
+    }
+}
+
+/// Replaces the positional width and precision parameters, like `1$` in `{:>1$}`, of the format
+/// spec, checking that they are the `counts` parsed by `rustc_parse_format`.
+fn replace_count_params(spec: &str, counts: &[usize], replace_index: impl Fn(usize) -> String) -> Option<String> {
+    let mut new_spec = String::with_capacity(spec.len());
+    let mut found = Vec::new();
+    let mut rest = spec;
+    while let Some(dollar) = rest.find('$') {
+        let before = &rest[..dollar];
+        let digits_start = before.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+        // Named parameters, like `width$`, are kept
+        let is_param =
+            digits_start < dollar && !before[..digits_start].ends_with(|c: char| c.is_alphanumeric() || c == '_');
+        // The `0` flag, as in `{:01$}`
+        let index_start = if dollar - digits_start > 1 && before[digits_start..].starts_with('0') {
+            digits_start + 1
+        } else {
+            digits_start
+        };
+        if is_param && let Ok(i) = before[index_start..].parse() {
+            found.push(i);
+            new_spec.push_str(&before[..index_start]);
+            new_spec.push_str(&replace_index(i));
+        } else {
+            new_spec.push_str(before);
+        }
+        new_spec.push('$');
+        rest = &rest[dollar + 1..];
+    }
+    new_spec.push_str(rest);
+    (found == counts).then_some(new_spec)
+}
+
 // Returns true if `args[i]` "refers to" or "is referred to by" another argument.
 fn is_aliased(args: &[FormatArgsArg<'_>], i: usize) -> bool {
     let value = args[i].value;
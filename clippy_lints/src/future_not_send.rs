@@ -1,7 +1,11 @@
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::return_ty;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{get_parent_expr, return_ty};
+use rustc_errors::{Diagnostic, MultiSpan};
+use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit::FnKind;
-use rustc_hir::{Body, FnDecl, HirId};
+use rustc_hir::{Body, Closure, Expr, ExprKind, FnDecl, HirId, LangItem, Node, QPath};
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::subst::Subst;
@@ -55,7 +59,7 @@ impl<'tcx> LateLintPass<'tcx> for FutureNotSend {
         cx: &LateContext<'tcx>,
         kind: FnKind<'tcx>,
         decl: &'tcx FnDecl<'tcx>,
-        _: &'tcx Body<'tcx>,
+        body: &'tcx Body<'tcx>,
         _: Span,
         hir_id: HirId,
     ) {
@@ -91,6 +95,7 @@ impl<'tcx> LateLintPass<'tcx> for FutureNotSend {
                         span,
                         "future cannot be sent between threads safely",
                         |db| {
+                            let rustc_notes = db.children.len();
                             cx.tcx.infer_ctxt().enter(|infcx| {
                                 for FulfillmentError { obligation, .. } in send_errors {
                                     infcx.maybe_note_obligation_cause_for_async_await(db, &obligation);
@@ -103,6 +108,12 @@ impl<'tcx> LateLintPass<'tcx> for FutureNotSend {
                                     }
                                 }
                             });
+                            let noted: Vec<_> = db.children[rustc_notes..]
+                                .iter()
+                                .flat_map(|note| note.span.span_labels())
+                                .map(|label| label.span)
+                                .collect();
+                            note_values_held_across_await(cx, body, send_trait, &noted, db);
                         },
                     );
                 }
@@ -110,3 +121,75 @@ impl<'tcx> LateLintPass<'tcx> for FutureNotSend {
         }
     }
 }
+
+/// Points at the values of the generator interior which aren't `Send`, and at the `.await` they
+/// are held across. The parameters, the futures being awaited, and the values rustc already
+/// pointed at in `noted` are skipped.
+fn note_values_held_across_await<'tcx>(
+    cx: &LateContext<'tcx>,
+    body: &'tcx Body<'tcx>,
+    send_trait: DefId,
+    noted: &[Span],
+    db: &mut Diagnostic,
+) {
+    let generator = match body.value.kind {
+        ExprKind::Call(
+            _,
+            [
+                Expr {
+                    kind: ExprKind::Closure(&Closure { body, .. }),
+                    ..
+                },
+            ],
+        ) => body,
+        _ => return,
+    };
+    let interior_types = &cx.tcx.typeck_body(generator).generator_interior_types;
+    for cause in interior_types.as_ref().skip_binder() {
+        if cause.span.from_expansion()
+            || noted.contains(&cause.span)
+            || body.params.iter().any(|param| param.span.contains(cause.span))
+            || cause.expr.map_or(false, |hir_id| is_awaited_future(cx, hir_id))
+        {
+            continue;
+        }
+        let ty = cx.tcx.erase_late_bound_regions(interior_types.rebind(cause.ty));
+        if !implements_trait(cx, ty, send_trait, &[]) {
+            let mut span = MultiSpan::from_span(cause.span);
+            span.push_span_label(cause.yield_span, "held across this `.await`");
+            db.span_help(
+                span,
+                &format!(
+                    "consider dropping `{}` before the `.await`, or moving it into a block which ends before it",
+                    snippet(cx, cause.span, "..")
+                ),
+            );
+        }
+    }
+}
+
+/// Checks if the expression is the future of an `.await`, which is desugared to
+/// `match IntoFuture::into_future(<expr>) { .. }`.
+fn is_awaited_future(cx: &LateContext<'_>, hir_id: HirId) -> bool {
+    let Some(Node::Expr(mut expr)) = cx.tcx.hir().find(hir_id) else { return false };
+    // The generator of an `async` block is wrapped in a call turning it into a future.
+    if let ExprKind::Closure(..) = expr.kind
+        && let Some(parent) = get_parent_expr(cx, expr)
+        && let ExprKind::Call(func, _) = parent.kind
+        && let ExprKind::Path(QPath::LangItem(LangItem::FromGenerator, ..)) = func.kind
+    {
+        expr = parent;
+    }
+    get_parent_expr(cx, expr).map_or(false, |parent| {
+        matches!(
+            parent.kind,
+            ExprKind::Call(
+                Expr {
+                    kind: ExprKind::Path(QPath::LangItem(LangItem::IntoFutureIntoFuture, ..)),
+                    ..
+                },
+                _
+            )
+        )
+    })
+}
@@ -1,13 +1,18 @@
 //! lint on indexing and slicing operations
 
+use clippy_utils::comparisons::{normalize_comparison, Rel};
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
-use clippy_utils::higher;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::value_range::{for_each_guard, is_immutable_binding, Guard};
+use clippy_utils::{higher, path_to_local, path_to_local_id, SpanlessEq};
 use rustc_ast::ast::RangeLimits;
-use rustc_hir::{Expr, ExprKind};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{BinOpKind, BindingAnnotation, Expr, ExprKind, HirId, Mutability, PatKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -77,13 +82,29 @@ declare_clippy_lint! {
     /// y.get(10);
     /// y.get(10..100);
     /// ```
+    ///
+    /// ### Configuration
+    /// With `allow-checked-indexing`, indexing and slicing of a local which is
+    /// dominated by a bounds check, like `if i < v.len()`, `assert!(v.len() > i)`,
+    /// `for i in 0..v.len()` or `for chunk in v.chunks_exact(4)`, isn't linted.
     #[clippy::version = "pre 1.29.0"]
     pub INDEXING_SLICING,
     restriction,
     "indexing/slicing usage"
 }
 
-declare_lint_pass!(IndexingSlicing => [INDEXING_SLICING, OUT_OF_BOUNDS_INDEXING]);
+pub struct IndexingSlicing {
+    allow_checked_indexing: bool,
+}
+
+impl IndexingSlicing {
+    #[must_use]
+    pub fn new(allow_checked_indexing: bool) -> Self {
+        Self { allow_checked_indexing }
+    }
+}
+
+impl_lint_pass!(IndexingSlicing => [INDEXING_SLICING, OUT_OF_BOUNDS_INDEXING]);
 
 impl<'tcx> LateLintPass<'tcx> for IndexingSlicing {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
@@ -142,6 +163,10 @@ impl<'tcx> LateLintPass<'tcx> for IndexingSlicing {
                     (None, None) => return, // [..] is ok.
                 };
 
+                if self.allow_checked_indexing && is_checked_slicing(cx, expr, array, &range) {
+                    return;
+                }
+
                 span_lint_and_help(cx, INDEXING_SLICING, expr.span, "slicing may panic", None, help_msg);
             } else {
                 // Catchall non-range index, i.e., [n] or [n << m]
@@ -157,6 +182,10 @@ impl<'tcx> LateLintPass<'tcx> for IndexingSlicing {
                     }
                 }
 
+                if self.allow_checked_indexing && is_checked_indexing(cx, expr, array, index) {
+                    return;
+                }
+
                 span_lint_and_help(
                     cx,
                     INDEXING_SLICING,
@@ -203,3 +232,229 @@ fn to_const_range<'tcx>(
 
     (start, end)
 }
+
+/// A known lower bound of the length of a local.
+struct LenBound<'tcx> {
+    value: BoundValue<'tcx>,
+    /// Whether the length is greater than the value, or at least the value.
+    strict: bool,
+}
+
+enum BoundValue<'tcx> {
+    /// Constant bounds are always stored as non-strict bounds.
+    Const(u128),
+    Local(HirId),
+    Expr(&'tcx Expr<'tcx>),
+}
+
+impl<'tcx> LenBound<'tcx> {
+    fn new(cx: &LateContext<'tcx>, value: &'tcx Expr<'tcx>, strict: bool) -> Option<Self> {
+        if let Some(c) = const_index(cx, value) {
+            return Some(Self {
+                value: BoundValue::Const(if strict { c + 1 } else { c }),
+                strict: false,
+            });
+        }
+        let value = if let Some(local_id) = path_to_local(value) {
+            is_immutable_binding(cx, local_id).then_some(BoundValue::Local(local_id))?
+        } else if is_stable(cx, value) {
+            BoundValue::Expr(value)
+        } else {
+            return None;
+        };
+        Some(Self { value, strict })
+    }
+
+    /// Checks if the length is greater than (`strict`), or at least the value of the expression.
+    fn bounds(&self, cx: &LateContext<'_>, e: &Expr<'_>, strict: bool) -> bool {
+        match self.value {
+            BoundValue::Const(len) => const_index(cx, e).map_or(false, |c| if strict { c < len } else { c <= len }),
+            BoundValue::Local(local_id) => (self.strict || !strict) && path_to_local_id(e, local_id),
+            BoundValue::Expr(value) => (self.strict || !strict) && SpanlessEq::new(cx).eq_expr(value, e),
+        }
+    }
+}
+
+fn is_checked_indexing<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, array: &Expr<'_>, index: &Expr<'_>) -> bool {
+    len_bounds(cx, expr, array).map_or(false, |bounds| bounds.iter().any(|b| b.bounds(cx, index, true)))
+}
+
+fn is_checked_slicing<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    array: &Expr<'_>,
+    range: &higher::Range<'_>,
+) -> bool {
+    let Some(bounds) = len_bounds(cx, expr, array) else { return false };
+    let in_bounds = |e, strict| bounds.iter().any(|b: &LenBound<'_>| b.bounds(cx, e, strict));
+    let end_in_bounds = range
+        .end
+        .map_or(true, |end| in_bounds(end, range.limits == RangeLimits::Closed));
+    let start_in_bounds = match (range.start, range.end) {
+        (None, _) => true,
+        (Some(start), None) => in_bounds(start, false),
+        // The start must not be after the end.
+        (Some(start), Some(end)) => match (const_index(cx, start), const_index(cx, end)) {
+            (Some(0), _) => true,
+            (Some(start), Some(end)) => start <= end,
+            _ => SpanlessEq::new(cx).eq_expr(start, end),
+        },
+    };
+    start_in_bounds && end_in_bounds
+}
+
+/// Collects the bounds of the length of the indexed local, which are known at the given expression.
+/// Returns `None` if the indexed expression isn't a local whose length can't change.
+fn len_bounds<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, array: &Expr<'_>) -> Option<Vec<LenBound<'tcx>>> {
+    let local_id = path_to_local(array)?;
+    if !is_immutable_binding(cx, local_id) || !has_fixed_len(cx, cx.typeck_results().node_type(local_id)) {
+        return None;
+    }
+
+    let mut bounds = Vec::new();
+    for_each_guard(cx, expr, |guard| match guard {
+        Guard::Cond { cond, holds } => cond_bounds(cx, cond, local_id, holds, &mut bounds),
+        Guard::ForLoop(for_loop) => loop_bounds(cx, &for_loop, local_id, &mut bounds),
+    });
+    Some(bounds)
+}
+
+/// Collects the bounds implied by iterating over `0..v.len()`, `v.iter().enumerate()`, or over the
+/// chunks of a constant size.
+fn loop_bounds<'tcx>(
+    cx: &LateContext<'tcx>,
+    for_loop: &higher::ForLoop<'tcx>,
+    local_id: HirId,
+    bounds: &mut Vec<LenBound<'tcx>>,
+) {
+    let index_pat = if let Some(range) = higher::Range::hir(for_loop.arg)
+        && range.limits == RangeLimits::HalfOpen
+        && range.end.map_or(false, |end| len_of(end) == Some(local_id))
+    {
+        for_loop.pat
+    } else if let ExprKind::MethodCall(path, [iter], _) = for_loop.arg.kind
+        && path.ident.name == sym!(enumerate)
+        && let ExprKind::MethodCall(path, [recv], _) = iter.kind
+        && path.ident.name == sym::iter
+        && path_to_local_id(recv, local_id)
+        && let PatKind::Tuple([index_pat, _], _) = for_loop.pat.kind
+    {
+        index_pat
+    } else {
+        if let PatKind::Binding(BindingAnnotation::Unannotated, chunk_id, _, None) = for_loop.pat.kind
+            && chunk_id == local_id
+            && let ExprKind::MethodCall(path, [_, size], _) = for_loop.arg.kind
+            && matches!(
+                path.ident.as_str(),
+                "chunks_exact" | "chunks_exact_mut" | "rchunks_exact" | "rchunks_exact_mut" | "windows"
+            )
+            && let Some(size) = const_index(cx, size)
+        {
+            bounds.push(LenBound {
+                value: BoundValue::Const(size),
+                strict: false,
+            });
+        }
+        return;
+    };
+    if let PatKind::Binding(BindingAnnotation::Unannotated, index_id, _, None) = index_pat.kind {
+        bounds.push(LenBound {
+            value: BoundValue::Local(index_id),
+            strict: true,
+        });
+    }
+}
+
+/// Collects the bounds implied by the condition evaluating to `holds`.
+fn cond_bounds<'tcx>(
+    cx: &LateContext<'tcx>,
+    cond: &'tcx Expr<'tcx>,
+    local_id: HirId,
+    holds: bool,
+    bounds: &mut Vec<LenBound<'tcx>>,
+) {
+    match cond.kind {
+        ExprKind::DropTemps(cond) => cond_bounds(cx, cond, local_id, holds, bounds),
+        ExprKind::Unary(UnOp::Not, cond) => cond_bounds(cx, cond, local_id, !holds, bounds),
+        ExprKind::Binary(op, left, right)
+            if (op.node == BinOpKind::And && holds) || (op.node == BinOpKind::Or && !holds) =>
+        {
+            cond_bounds(cx, left, local_id, holds, bounds);
+            cond_bounds(cx, right, local_id, holds, bounds);
+        },
+        ExprKind::MethodCall(path, [recv], _)
+            if !holds && path.ident.name == sym!(is_empty) && path_to_local_id(recv, local_id) =>
+        {
+            bounds.push(LenBound {
+                value: BoundValue::Const(1),
+                strict: false,
+            });
+        },
+        ExprKind::Binary(op, left, right) => {
+            let Some((rel, left, right)) = normalize_comparison(op.node, left, right) else { return };
+            // Turn the comparison into one which holds.
+            let (rel, left, right) = match (rel, holds) {
+                (rel, true) => (rel, left, right),
+                (Rel::Lt, false) => (Rel::Le, right, left),
+                (Rel::Le, false) => (Rel::Lt, right, left),
+                (Rel::Eq, false) => (Rel::Ne, left, right),
+                (Rel::Ne, false) => (Rel::Eq, left, right),
+            };
+            let (value, strict) = match rel {
+                Rel::Lt if len_of(right) == Some(local_id) => (left, true),
+                Rel::Le | Rel::Eq if len_of(right) == Some(local_id) => (left, false),
+                Rel::Eq if len_of(left) == Some(local_id) => (right, false),
+                _ => return,
+            };
+            bounds.extend(LenBound::new(cx, value, strict));
+        },
+        _ => {},
+    }
+}
+
+/// If the expression is `x.len()`, returns the `HirId` of `x`.
+fn len_of(e: &Expr<'_>) -> Option<HirId> {
+    if let ExprKind::MethodCall(path, [recv], _) = e.kind
+        && path.ident.name == sym::len
+    {
+        path_to_local(recv)
+    } else {
+        None
+    }
+}
+
+fn const_index(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<u128> {
+    if let Some((Constant::Int(c), _)) = constant(cx, cx.typeck_results(), e) {
+        Some(c)
+    } else {
+        None
+    }
+}
+
+/// Checks if the expression always evaluates to the same value, as it only consists of constants
+/// and immutable locals.
+fn is_stable(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    match e.kind {
+        ExprKind::Lit(_) => true,
+        ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, e.hir_id) {
+            Res::Local(local_id) => is_immutable_binding(cx, local_id),
+            Res::Def(DefKind::Const | DefKind::AssocConst, _) => true,
+            _ => false,
+        },
+        ExprKind::Binary(_, left, right) => is_stable(cx, left) && is_stable(cx, right),
+        ExprKind::Unary(_, e) | ExprKind::Cast(e, _) | ExprKind::Field(e, _) => is_stable(cx, e),
+        _ => false,
+    }
+}
+
+/// Checks if the length of a value of this type can't change while it is bound to an immutable
+/// local. Strings aren't included, as slicing them also panics when the index isn't on a `char`
+/// boundary.
+fn has_fixed_len(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    match *ty.kind() {
+        ty::Ref(_, ty, Mutability::Not) => has_fixed_len(cx, ty),
+        ty::Ref(_, ty, Mutability::Mut) => matches!(ty.kind(), ty::Array(..) | ty::Slice(_)),
+        ty::Array(..) | ty::Slice(_) => true,
+        _ => is_type_diagnostic_item(cx, ty, sym::Vec) || is_type_diagnostic_item(cx, ty, sym::VecDeque),
+    }
+}
@@ -0,0 +1,133 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{def_path_res, match_def_path, path_def_id};
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::DefIdSet;
+use rustc_hir::{Expr, ExprKind, LangItem, MatchSource, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for large futures which are awaited or passed to a spawn
+    /// function (e.g. `tokio::spawn`) by value.
+    ///
+    /// ### Why is this bad?
+    /// The state machine of an `async` block or function holds every value
+    /// which lives across an `.await`, so it can get very large. Moving it
+    /// around by value copies all of it, and can overflow the stack.
+    ///
+    /// ### Configuration
+    /// The size threshold can be configured with `future-size-threshold`, and
+    /// the spawn functions with `future-spawn-functions`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// async fn large_future(_x: [u8; 16 * 1024]) {}
+    ///
+    /// pub async fn trigger() {
+    ///     large_future([0u8; 16 * 1024]).await;
+    /// }
+    /// ```
+    ///
+    /// `Box::pin` the big future instead.
+    ///
+    /// ```rust
+    /// async fn large_future(_x: [u8; 16 * 1024]) {}
+    ///
+    /// pub async fn trigger() {
+    ///     Box::pin(large_future([0u8; 16 * 1024])).await;
+    /// }
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub LARGE_FUTURES,
+    pedantic,
+    "large future may lead to unexpected stack overflows"
+}
+
+pub struct LargeFuture {
+    future_size_threshold: u64,
+    spawn_functions: Vec<String>,
+    spawn_def_ids: DefIdSet,
+}
+
+impl LargeFuture {
+    #[must_use]
+    pub fn new(future_size_threshold: u64, spawn_functions: Vec<String>) -> Self {
+        Self {
+            future_size_threshold,
+            spawn_functions,
+            spawn_def_ids: DefIdSet::default(),
+        }
+    }
+
+    fn check_future<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        let ty = cx.typeck_results().expr_ty(expr);
+        if let Some(future_trait_def_id) = cx.tcx.lang_items().future_trait()
+            && implements_trait(cx, ty, future_trait_def_id, &[])
+            && let Ok(layout) = cx.layout_of(ty)
+            && layout.size.bytes() > self.future_size_threshold
+        {
+            let mut applicability = Applicability::MaybeIncorrect;
+            let snippet = snippet_with_applicability(cx, expr.span, "..", &mut applicability);
+            span_lint_and_sugg(
+                cx,
+                LARGE_FUTURES,
+                expr.span,
+                &format!("large future with a size of {} bytes", layout.size.bytes()),
+                "consider `Box::pin` on it",
+                format!("Box::pin({})", snippet),
+                applicability,
+            );
+        }
+    }
+
+    fn is_spawn_function(&self, cx: &LateContext<'_>, callee: &Expr<'_>) -> bool {
+        path_def_id(cx, callee).map_or(false, |def_id| {
+            self.spawn_def_ids.contains(&def_id)
+                || self.spawn_functions.iter().any(|path| {
+                    let segs: Vec<_> = path.split("::").collect();
+                    match_def_path(cx, def_id, &segs)
+                })
+        })
+    }
+}
+
+impl_lint_pass!(LargeFuture => [LARGE_FUTURES]);
+
+impl<'tcx> LateLintPass<'tcx> for LargeFuture {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        // Resolving the paths finds re-exports like `tokio::spawn`, `match_def_path` is still needed
+        // for functions of the current crate.
+        for path in &self.spawn_functions {
+            let segs: Vec<_> = path.split("::").collect();
+            if let Res::Def(_, id) = def_path_res(cx, &segs) {
+                self.spawn_def_ids.insert(id);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        match expr.kind {
+            ExprKind::Match(scrutinee, _, MatchSource::AwaitDesugar) => {
+                if let ExprKind::Call(func, [arg, ..]) = scrutinee.kind
+                    && let ExprKind::Path(QPath::LangItem(LangItem::IntoFutureIntoFuture, ..)) = func.kind
+                {
+                    self.check_future(cx, arg);
+                }
+            },
+            ExprKind::Call(callee, args) if self.is_spawn_function(cx, callee) => {
+                for arg in args {
+                    self.check_future(cx, arg);
+                }
+            },
+            _ => {},
+        }
+    }
+}
@@ -4,8 +4,13 @@
 
 store.register_group(true, "clippy::cargo", Some("clippy_cargo"), vec![
     LintId::of(cargo::CARGO_COMMON_METADATA),
+    LintId::of(cargo::DISALLOWED_DEPENDENCIES),
+    LintId::of(cargo::MISMATCHED_DEV_DEPENDENCY_VERSIONS),
+    LintId::of(cargo::MISSING_WORKSPACE_INHERITANCE),
     LintId::of(cargo::MULTIPLE_CRATE_VERSIONS),
     LintId::of(cargo::NEGATIVE_FEATURE_NAMES),
     LintId::of(cargo::REDUNDANT_FEATURE_NAMES),
+    LintId::of(cargo::UNGATED_OPTIONAL_DEPENDENCIES),
+    LintId::of(cargo::UNUSED_DEPENDENCIES),
     LintId::of(cargo::WILDCARD_DEPENDENCIES),
 ])
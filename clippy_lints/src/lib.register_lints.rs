@@ -47,6 +47,7 @@ store.register_lints(&[
     attrs::BLANKET_CLIPPY_RESTRICTION_LINTS,
     attrs::DEPRECATED_CFG_ATTR,
     attrs::DEPRECATED_SEMVER,
+    attrs::DISALLOWED_LINT_SUPPRESSIONS,
     attrs::EMPTY_LINE_AFTER_OUTER_ATTR,
     attrs::INLINE_ALWAYS,
     attrs::MISMATCHED_TARGET_OS,
@@ -55,6 +56,7 @@ store.register_lints(&[
     await_holding_invalid::AWAIT_HOLDING_LOCK,
     await_holding_invalid::AWAIT_HOLDING_REFCELL_REF,
     blacklisted_name::BLACKLISTED_NAME,
+    blocking_in_async::BLOCKING_IN_ASYNC,
     blocks_in_if_conditions::BLOCKS_IN_IF_CONDITIONS,
     bool_assert_comparison::BOOL_ASSERT_COMPARISON,
     booleans::LOGIC_BUG,
@@ -64,9 +66,14 @@ store.register_lints(&[
     bytecount::NAIVE_BYTECOUNT,
     bytes_count_to_len::BYTES_COUNT_TO_LEN,
     cargo::CARGO_COMMON_METADATA,
+    cargo::DISALLOWED_DEPENDENCIES,
+    cargo::MISMATCHED_DEV_DEPENDENCY_VERSIONS,
+    cargo::MISSING_WORKSPACE_INHERITANCE,
     cargo::MULTIPLE_CRATE_VERSIONS,
     cargo::NEGATIVE_FEATURE_NAMES,
     cargo::REDUNDANT_FEATURE_NAMES,
+    cargo::UNGATED_OPTIONAL_DEPENDENCIES,
+    cargo::UNUSED_DEPENDENCIES,
     cargo::WILDCARD_DEPENDENCIES,
     case_sensitive_file_extension_comparisons::CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS,
     casts::CAST_ABS_TO_UNSIGNED,
@@ -117,11 +124,18 @@ store.register_lints(&[
     disallowed_methods::DISALLOWED_METHODS,
     disallowed_script_idents::DISALLOWED_SCRIPT_IDENTS,
     disallowed_types::DISALLOWED_TYPES,
+    doc::DOCTEST_ATTRIBUTES_WITHOUT_REASON,
+    doc::DOC_LINKS_TO_PRIVATE_ITEMS,
     doc::DOC_MARKDOWN,
+    doc::DOC_SPELLING,
+    doc::DOC_UNRESOLVED_LINKS,
+    doc::MISSING_DOC_SECTIONS,
     doc::MISSING_ERRORS_DOC,
     doc::MISSING_PANICS_DOC,
     doc::MISSING_SAFETY_DOC,
     doc::NEEDLESS_DOCTEST_MAIN,
+    doc::UNNECESSARY_DOC_SECTIONS,
+    doc::UNRELATED_DOC_EXAMPLES,
     doc_link_with_quotes::DOC_LINK_WITH_QUOTES,
     double_parens::DOUBLE_PARENS,
     drop_forget_ref::DROP_COPY,
@@ -158,7 +172,10 @@ store.register_lints(&[
     floating_point_arithmetic::SUBOPTIMAL_FLOPS,
     format::USELESS_FORMAT,
     format_args::FORMAT_IN_FORMAT_ARGS,
+    format_args::FORMAT_IN_LOG_ARGS,
     format_args::TO_STRING_IN_FORMAT_ARGS,
+    format_args::TO_STRING_IN_LOG_ARGS,
+    format_args::UNINLINED_FORMAT_ARGS,
     format_impl::PRINT_IN_FORMAT_IMPL,
     format_impl::RECURSIVE_FORMAT_IMPL,
     format_push_string::FORMAT_PUSH_STRING,
@@ -201,6 +218,7 @@ store.register_lints(&[
     iter_not_returning_iterator::ITER_NOT_RETURNING_ITERATOR,
     large_const_arrays::LARGE_CONST_ARRAYS,
     large_enum_variant::LARGE_ENUM_VARIANT,
+    large_futures::LARGE_FUTURES,
     large_include_file::LARGE_INCLUDE_FILE,
     large_stack_arrays::LARGE_STACK_ARRAYS,
     len_zero::COMPARISON_TO_EMPTY,
@@ -218,6 +236,8 @@ store.register_lints(&[
     literal_representation::MISTYPED_LITERAL_SUFFIXES,
     literal_representation::UNREADABLE_LITERAL,
     literal_representation::UNUSUAL_BYTE_GROUPINGS,
+    log_macros::EAGER_LOG_ARGS,
+    log_macros::INCONSISTENT_TRACING_FIELD_NAMES,
     loops::EMPTY_LOOP,
     loops::EXPLICIT_COUNTER_LOOP,
     loops::EXPLICIT_INTO_ITER_LOOP,
@@ -439,12 +459,14 @@ store.register_lints(&[
     operators::MODULO_ONE,
     operators::NEEDLESS_BITWISE_BOOL,
     operators::OP_REF,
+    operators::PROVABLE_OVERFLOW,
     operators::PTR_EQ,
     operators::SELF_ASSIGNMENT,
     operators::VERBOSE_BIT_MASK,
     option_env_unwrap::OPTION_ENV_UNWRAP,
     option_if_let_else::OPTION_IF_LET_ELSE,
     overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL,
+    panic_in_no_panic_fn::PANIC_IN_NO_PANIC_FN,
     panic_in_result_fn::PANIC_IN_RESULT_FN,
     panic_unimplemented::PANIC,
     panic_unimplemented::TODO,
@@ -455,6 +477,7 @@ store.register_lints(&[
     pass_by_ref_or_value::TRIVIALLY_COPY_PASS_BY_REF,
     path_buf_push_overwrite::PATH_BUF_PUSH_OVERWRITE,
     pattern_type_mismatch::PATTERN_TYPE_MISMATCH,
+    potential_deadlock::POTENTIAL_DEADLOCK,
     precedence::PRECEDENCE,
     ptr::CMP_NULL,
     ptr::INVALID_NULL_PTR_USAGE,
@@ -481,6 +504,11 @@ store.register_lints(&[
     ref_option_ref::REF_OPTION_REF,
     reference::DEREF_ADDROF,
     regex::INVALID_REGEX,
+    regex::REGEX_CREATION_IN_LOOPS,
+    regex::REGEX_EMPTY_ALTERNATION,
+    regex::REGEX_REDUNDANT_CLASS_RANGES,
+    regex::REGEX_UNESCAPED_DOT,
+    regex::REGEX_UNUSED_CAPTURE_GROUPS,
     regex::TRIVIAL_REGEX,
     repeat_once::REPEAT_ONCE,
     return_self_not_must_use::RETURN_SELF_NOT_MUST_USE,
@@ -493,6 +521,7 @@ store.register_lints(&[
     shadow::SHADOW_REUSE,
     shadow::SHADOW_SAME,
     shadow::SHADOW_UNRELATED,
+    significant_drop_tightening::SIGNIFICANT_DROP_TIGHTENING,
     single_char_lifetime_names::SINGLE_CHAR_LIFETIME_NAMES,
     single_component_path_imports::SINGLE_COMPONENT_PATH_IMPORTS,
     size_of_in_element_count::SIZE_OF_IN_ELEMENT_COUNT,
@@ -547,6 +576,7 @@ store.register_lints(&[
     types::TYPE_COMPLEXITY,
     types::VEC_BOX,
     undocumented_unsafe_blocks::UNDOCUMENTED_UNSAFE_BLOCKS,
+    undocumented_unsafe_blocks::UNNECESSARY_SAFETY_COMMENT,
     unicode::INVISIBLE_CHARACTERS,
     unicode::NON_ASCII_LITERAL,
     unicode::UNICODE_NOT_NFC,
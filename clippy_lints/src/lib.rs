@@ -54,6 +54,7 @@ use rustc_data_structures::fx::FxHashSet;
 use rustc_lint::LintId;
 use rustc_semver::RustcVersion;
 use rustc_session::Session;
+use std::path::PathBuf;
 
 /// Macro used to declare a Clippy lint.
 ///
@@ -177,6 +178,7 @@ mod async_yields_async;
 mod attrs;
 mod await_holding_invalid;
 mod blacklisted_name;
+mod blocking_in_async;
 mod blocks_in_if_conditions;
 mod bool_assert_comparison;
 mod booleans;
@@ -259,6 +261,7 @@ mod items_after_statements;
 mod iter_not_returning_iterator;
 mod large_const_arrays;
 mod large_enum_variant;
+mod large_futures;
 mod large_include_file;
 mod large_stack_arrays;
 mod len_zero;
@@ -266,6 +269,7 @@ mod let_if_seq;
 mod let_underscore;
 mod lifetimes;
 mod literal_representation;
+mod log_macros;
 mod loops;
 mod macro_use;
 mod main_recursion;
@@ -327,12 +331,14 @@ mod operators;
 mod option_env_unwrap;
 mod option_if_let_else;
 mod overflow_check_conditional;
+mod panic_in_no_panic_fn;
 mod panic_in_result_fn;
 mod panic_unimplemented;
 mod partialeq_ne_impl;
 mod pass_by_ref_or_value;
 mod path_buf_push_overwrite;
 mod pattern_type_mismatch;
+mod potential_deadlock;
 mod precedence;
 mod ptr;
 mod ptr_offset_with_cast;
@@ -359,6 +365,7 @@ mod self_named_constructors;
 mod semicolon_if_nothing_returned;
 mod serde_api;
 mod shadow;
+mod significant_drop_tightening;
 mod single_char_lifetime_names;
 mod single_component_path_imports;
 mod size_of_in_element_count;
@@ -578,7 +585,18 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| Box::new(mut_mut::MutMut));
     store.register_late_pass(|| Box::new(mut_reference::UnnecessaryMutPassed));
     store.register_late_pass(|| Box::new(len_zero::LenZero));
-    store.register_late_pass(|| Box::new(attrs::Attributes));
+    let allow_reason_expiry_key = conf.allow_reason_expiry_key.clone();
+    let allow_reason_required_keys = conf.allow_reason_required_keys.clone();
+    let non_suppressible_lints = conf.non_suppressible_lints.iter().cloned().collect::<FxHashSet<_>>();
+    let today = attrs::today();
+    store.register_late_pass(move || {
+        Box::new(attrs::Attributes::new(
+            allow_reason_expiry_key.clone(),
+            allow_reason_required_keys.clone(),
+            non_suppressible_lints.clone(),
+            today,
+        ))
+    });
     store.register_late_pass(|| Box::new(blocks_in_if_conditions::BlocksInIfConditions));
     store.register_late_pass(|| Box::new(unicode::Unicode));
     store.register_late_pass(|| Box::new(uninit_vec::UninitVec));
@@ -682,7 +700,15 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         ))
     });
     let doc_valid_idents = conf.doc_valid_idents.iter().cloned().collect::<FxHashSet<_>>();
-    store.register_late_pass(move || Box::new(doc::DocMarkdown::new(doc_valid_idents.clone())));
+    let doc_sections = conf.doc_sections.clone();
+    let doc_spelling_dictionaries = conf.doc_spelling_dictionaries.clone();
+    store.register_late_pass(move || {
+        Box::new(doc::DocMarkdown::new(
+            doc_valid_idents.clone(),
+            doc_sections.clone(),
+            doc_spelling_dictionaries.clone(),
+        ))
+    });
     store.register_late_pass(|| Box::new(neg_multiply::NegMultiply));
     store.register_late_pass(|| Box::new(mem_forget::MemForget));
     store.register_late_pass(|| Box::new(let_if_seq::LetIfSeq));
@@ -718,7 +744,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| Box::new(inherent_impl::MultipleInherentImpl));
     store.register_late_pass(|| Box::new(neg_cmp_op_on_partial_ord::NoNegCompOpForPartialOrd));
     store.register_late_pass(|| Box::new(unwrap::Unwrap));
-    store.register_late_pass(|| Box::new(indexing_slicing::IndexingSlicing));
+    let allow_checked_indexing = conf.allow_checked_indexing;
+    store.register_late_pass(move || Box::new(indexing_slicing::IndexingSlicing::new(allow_checked_indexing)));
     store.register_late_pass(|| Box::new(non_copy_const::NonCopyConst));
     store.register_late_pass(|| Box::new(ptr_offset_with_cast::PtrOffsetWithCast));
     store.register_late_pass(|| Box::new(redundant_clone::RedundantClone));
@@ -870,8 +897,13 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
             enable_raw_pointer_heuristic_for_send,
         ))
     });
-    store.register_late_pass(move || Box::new(undocumented_unsafe_blocks::UndocumentedUnsafeBlocks));
-    store.register_late_pass(move || Box::new(format_args::FormatArgs));
+    let require_safety_comment_per_operation = conf.require_safety_comment_per_operation;
+    store.register_late_pass(move || {
+        Box::new(undocumented_unsafe_blocks::UndocumentedUnsafeBlocks::new(
+            require_safety_comment_per_operation,
+        ))
+    });
+    store.register_late_pass(move || Box::new(format_args::FormatArgs::new(msrv)));
     store.register_late_pass(|| Box::new(trailing_empty_array::TrailingEmptyArray));
     store.register_early_pass(|| Box::new(octal_escapes::OctalEscapes));
     store.register_late_pass(|| Box::new(needless_late_init::NeedlessLateInit));
@@ -886,9 +918,41 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     let allow_dbg_in_tests = conf.allow_dbg_in_tests;
     store.register_late_pass(move || Box::new(dbg_macro::DbgMacro::new(allow_dbg_in_tests)));
     let cargo_ignore_publish = conf.cargo_ignore_publish;
+    let required_metadata_fields = conf.required_metadata_fields.clone();
+    let forbidden_metadata_fields = conf.forbidden_metadata_fields.clone();
+    let metadata_field_values = conf.metadata_field_values.clone();
+    let allowed_duplicate_crates = conf.allowed_duplicate_crates.clone();
+    for allowed in &allowed_duplicate_crates {
+        for req in allowed.versions() {
+            if let Err(e) = semver::VersionReq::parse(req) {
+                sess.err(&format!(
+                    "error reading Clippy's configuration file. `{}` is not a valid version requirement for `{}`: {}",
+                    req,
+                    allowed.name(),
+                    e
+                ));
+            }
+        }
+    }
+    let allowed_licenses = conf.allowed_licenses.clone();
+    let denied_licenses = conf.denied_licenses.clone();
+    let allow_git_dependencies = conf.allow_git_dependencies;
+    let allowed_registries = conf.allowed_registries.clone();
     store.register_late_pass(move || {
         Box::new(cargo::Cargo {
             ignore_publish: cargo_ignore_publish,
+            metadata_policy: cargo::MetadataPolicy {
+                required_fields: required_metadata_fields.clone(),
+                forbidden_fields: forbidden_metadata_fields.clone(),
+                field_values: metadata_field_values.clone(),
+            },
+            allowed_duplicate_crates: allowed_duplicate_crates.clone(),
+            dependency_policy: cargo::DependencyPolicy {
+                allowed_licenses: allowed_licenses.clone(),
+                denied_licenses: denied_licenses.clone(),
+                allow_git_dependencies,
+                allowed_registries: allowed_registries.clone(),
+            },
         })
     });
     store.register_early_pass(|| Box::new(crate_in_macro_def::CrateInMacroDef));
@@ -916,9 +980,49 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move || Box::new(operators::Operators::new(verbose_bit_mask_threshold)));
     store.register_late_pass(|| Box::new(invalid_utf8_in_unchecked::InvalidUtf8InUnchecked));
     store.register_late_pass(|| Box::new(std_instead_of_core::StdReexports));
+    let no_panic_functions = conf.no_panic_functions.clone();
+    store.register_late_pass(move || Box::new(panic_in_no_panic_fn::PanicInNoPanicFn::new(no_panic_functions.clone())));
+    let future_size_threshold = conf.future_size_threshold;
+    let future_spawn_functions = conf.future_spawn_functions.clone();
+    store.register_late_pass(move || {
+        Box::new(large_futures::LargeFuture::new(
+            future_size_threshold,
+            future_spawn_functions.clone(),
+        ))
+    });
+    store.register_late_pass(|| Box::new(significant_drop_tightening::SignificantDropTightening));
+    store.register_late_pass(|| Box::new(potential_deadlock::PotentialDeadlock::default()));
+    let blocking_functions = conf.blocking_functions.clone();
+    let future_spawn_functions = conf.future_spawn_functions.clone();
+    store.register_late_pass(move || {
+        Box::new(blocking_in_async::BlockingInAsync::new(
+            blocking_functions.clone(),
+            future_spawn_functions.clone(),
+        ))
+    });
+    store.register_late_pass(|| Box::new(log_macros::LogMacros::default()));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
+/// Register the pass writing the inventory of lint suppressions to `path`.
+///
+/// Used in `./src/driver.rs` when `--report-suppressions=<path>` is passed.
+pub fn register_suppression_report(store: &mut rustc_lint::LintStore, conf: &Conf, path: PathBuf) {
+    let conf_suppressions = conf
+        .enabled_allow_options()
+        .into_iter()
+        .flat_map(|(option, lints)| lints.into_iter().map(move |lint| (option.clone(), lint)))
+        .collect::<Vec<_>>();
+    store.register_late_pass(move || Box::new(attrs::SuppressionReport::new(path.clone(), conf_suppressions.clone())));
+}
+
+/// Register the pass writing the inventory of unsafe code to `path`.
+///
+/// Used in `./src/driver.rs` when `--report-unsafe=<path>` is passed.
+pub fn register_unsafe_report(store: &mut rustc_lint::LintStore, path: PathBuf) {
+    store.register_late_pass(move || Box::new(undocumented_unsafe_blocks::UnsafeReport::new(path.clone())));
+}
+
 #[rustfmt::skip]
 fn register_removed_non_tool_lints(store: &mut rustc_lint::LintStore) {
     store.register_removed(
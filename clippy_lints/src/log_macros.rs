@@ -0,0 +1,407 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_note};
+use clippy_utils::macros::{
+    is_log_enabled_macro, is_log_macro, macro_backtrace, root_macro_call, root_macro_call_first_node,
+};
+use clippy_utils::ty::is_copy;
+use clippy_utils::visitors::{expr_visitor, for_each_expr};
+use clippy_utils::{
+    is_ctor_or_promotable_const_function, is_expr_path_def_path, match_def_path, path_to_local_id, paths,
+};
+use core::ops::ControlFlow;
+use rustc_ast::LitKind;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::intravisit::{walk_expr, Visitor};
+use rustc_hir::{
+    BindingAnnotation, Block, BodyId, Expr, ExprKind, HirId, Mutability, Node, PatKind, Path, QPath, Stmt, StmtKind,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::nested_filter;
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+use rustc_middle::ty::TypeckResults;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::source_map::Spanned;
+use rustc_span::{sym, Span, Symbol};
+use std::collections::hash_map::Entry;
+use std::mem;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for local variables which are computed only to be passed to
+    /// logging macros of the `log` or `tracing` crates, outside of a
+    /// `log_enabled!` (or `tracing::enabled!`) check.
+    ///
+    /// ### Why is this bad?
+    /// The arguments of a logging macro are only evaluated when its level is
+    /// enabled, but the variable is computed every time, even when nothing is
+    /// logged.
+    ///
+    /// ### Known problems
+    /// Every function call is considered expensive, except the ones returning
+    /// a `Copy` type. Variables computed by borrowing something mutably, like
+    /// `cache.remove(&key)`, are skipped, but other side effects of the called
+    /// functions aren't detected. Early returns like
+    /// `if !log_enabled!(..) { return; }` are only recognized in the same block.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let summary = request.summarize();
+    /// log::debug!("received {}", summary);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// log::debug!("received {}", request.summarize());
+    /// ```
+    /// or, if the value is logged several times:
+    /// ```rust,ignore
+    /// if log_enabled!(Level::Debug) {
+    ///     let summary = request.summarize();
+    ///     log::debug!("received {}", summary);
+    ///     log::debug!("queued {}", summary);
+    /// }
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub EAGER_LOG_ARGS,
+    pedantic,
+    "variables computed only to be logged, even when the log level is disabled"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for fields of `tracing` events which are named differently
+    /// than in other events of the same module, like `user_id` and `userId`.
+    ///
+    /// ### Why is this bad?
+    /// Structured logs are queried by field name. Events using different
+    /// spellings for the same field are easily missed by those queries.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// tracing::info!(user_id = id, "logged in");
+    /// tracing::info!(userId = id, "logged out");
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// tracing::info!(user_id = id, "logged in");
+    /// tracing::info!(user_id = id, "logged out");
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub INCONSISTENT_TRACING_FIELD_NAMES,
+    pedantic,
+    "fields of `tracing` events named differently in the same module"
+}
+
+#[derive(Default)]
+pub struct LogMacros {
+    /// The first spelling of each field in a module, by the normalized field name.
+    field_names: FxHashMap<(LocalDefId, String), (String, Span)>,
+}
+
+impl_lint_pass!(LogMacros => [EAGER_LOG_ARGS, INCONSISTENT_TRACING_FIELD_NAMES]);
+
+impl<'tcx> LateLintPass<'tcx> for LogMacros {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if let StmtKind::Local(local) = stmt.kind
+                && let PatKind::Binding(BindingAnnotation::Unannotated, id, ident, None) = local.pat.kind
+                && let Some(init) = local.init
+                && is_expensive(cx, init)
+                && !has_side_effects(cx, init)
+                && only_used_in_log_macros(cx, id, &block.stmts[i + 1..], block.expr)
+                && !block.stmts[..i].iter().any(|stmt| is_log_enabled_check(cx, stmt))
+                && !is_in_log_enabled_branch(cx, block.hir_id)
+            {
+                span_lint_and_help(
+                    cx,
+                    EAGER_LOG_ARGS,
+                    local.span,
+                    &format!("`{}` is computed even when the log level is disabled", ident),
+                    None,
+                    "compute it in the arguments of the logging macro, or guard it with `log_enabled!`",
+                );
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let Some(macro_call) = root_macro_call_first_node(cx, expr) else { return };
+        if cx.tcx.crate_name(macro_call.def_id.krate).as_str() != "tracing" || !is_log_macro(cx, macro_call.def_id) {
+            return;
+        }
+        let module = cx.tcx.parent_module(expr.hir_id);
+        for (name, value) in event_fields(cx, expr) {
+            let name = name.as_str();
+            // The names of `key = value` fields are only in a string literal, so the event is
+            // pointed at instead
+            let span = match field_path(value) {
+                Some((path, span)) if path == name && !span.from_expansion() => span,
+                _ => macro_call.span,
+            };
+            let normalized = name
+                .chars()
+                .filter(|&c| c != '_' && c != '.')
+                .flat_map(char::to_lowercase)
+                .collect::<String>();
+            match self.field_names.entry((module, normalized)) {
+                Entry::Occupied(entry) => {
+                    let (first_name, first_span) = entry.get();
+                    if first_name != name {
+                        span_lint_and_note(
+                            cx,
+                            INCONSISTENT_TRACING_FIELD_NAMES,
+                            span,
+                            &format!(
+                                "field `{}` is named `{}` in other events of this module",
+                                name, first_name
+                            ),
+                            Some(*first_span),
+                            "first named here",
+                        );
+                    }
+                },
+                Entry::Vacant(entry) => {
+                    entry.insert((name.to_owned(), span));
+                },
+            }
+        }
+    }
+}
+
+/// Checks if the expression calls a function, or allocates with `format!` or `vec!`, to compute a
+/// value which isn't `Copy`.
+fn is_expensive<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> bool {
+    let is_call = if e.span.from_expansion() {
+        root_macro_call_first_node(cx, e).map_or(false, |macro_call| {
+            matches!(
+                cx.tcx.get_diagnostic_name(macro_call.def_id),
+                Some(sym::format_macro | sym::vec_macro)
+            )
+        })
+    } else {
+        match e.kind {
+            ExprKind::Call(..) => !is_ctor_or_promotable_const_function(cx, e),
+            ExprKind::MethodCall(..) => true,
+            _ => false,
+        }
+    };
+    is_call && !is_copy(cx, cx.typeck_results().expr_ty(e))
+}
+
+/// Checks if the expression may mutate something, by borrowing it mutably or assigning to it. Such
+/// an expression has to be evaluated even when nothing is logged.
+fn has_side_effects<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> bool {
+    for_each_expr(e, |e| {
+        let mutates = matches!(
+            e.kind,
+            ExprKind::AddrOf(_, Mutability::Mut, _) | ExprKind::Assign(..) | ExprKind::AssignOp(..)
+        ) || cx.typeck_results().expr_adjustments(e).iter().any(|adjustment| {
+            matches!(
+                adjustment.kind,
+                Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Mut { .. }))
+            )
+        });
+        if mutates {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+/// Checks if the local is used at least once, and only in the arguments of logging macros.
+fn only_used_in_log_macros<'tcx>(
+    cx: &LateContext<'tcx>,
+    id: HirId,
+    stmts: &'tcx [Stmt<'tcx>],
+    expr: Option<&'tcx Expr<'tcx>>,
+) -> bool {
+    let mut used = false;
+    let mut other_use = false;
+    {
+        let mut visitor = expr_visitor(cx, |e| {
+            if path_to_local_id(e, id) {
+                used = true;
+                other_use |= !is_in_log_macro(cx, e);
+            }
+            !other_use
+        });
+        for stmt in stmts {
+            visitor.visit_stmt(stmt);
+        }
+        if let Some(expr) = expr {
+            visitor.visit_expr(expr);
+        }
+    }
+    used && !other_use
+}
+
+/// Checks if the expression is in the arguments of a logging macro called outside of any other
+/// macro.
+fn is_in_log_macro(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let hir = cx.tcx.hir();
+    hir.parent_iter(e.hir_id)
+        .find_map(|(id, _)| root_macro_call(hir.span(id)))
+        .map_or(false, |macro_call| is_log_macro(cx, macro_call.def_id))
+}
+
+/// Checks for `if log_enabled!(..) { .. }`, or `if !log_enabled!(..) { return; }`.
+fn is_log_enabled_check<'tcx>(cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'tcx>) -> bool {
+    if let StmtKind::Expr(e) | StmtKind::Semi(e) = stmt.kind
+        && let ExprKind::If(cond, ..) = e.kind
+    {
+        contains_log_enabled(cx, cond)
+    } else {
+        false
+    }
+}
+
+/// Checks if the block is in the `then` branch of an `if` checking `log_enabled!`.
+fn is_in_log_enabled_branch(cx: &LateContext<'_>, mut id: HirId) -> bool {
+    for (parent_id, node) in cx.tcx.hir().parent_iter(id) {
+        if let Node::Expr(Expr {
+            kind: ExprKind::If(cond, then, _),
+            ..
+        }) = node
+            && then.hir_id == id
+            && contains_log_enabled(cx, cond)
+        {
+            return true;
+        }
+        id = parent_id;
+    }
+    false
+}
+
+fn contains_log_enabled<'tcx>(cx: &LateContext<'tcx>, cond: &'tcx Expr<'tcx>) -> bool {
+    for_each_expr(cond, |e| {
+        if macro_backtrace(e.span).any(|macro_call| is_log_enabled_macro(cx, macro_call.def_id)) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+/// Returns the fields of the expansion of a `tracing` event macro, with the values they're
+/// recorded from. The names are the ones of the `FieldSet` of the callsite, and the values the
+/// ones of the `ValueSet` of the event, which are in the same order. The message isn't returned.
+fn event_fields<'tcx>(cx: &LateContext<'tcx>, event: &'tcx Expr<'tcx>) -> Vec<(Symbol, &'tcx Expr<'tcx>)> {
+    let mut visitor = EventVisitor {
+        cx,
+        typeck_results: cx.typeck_results(),
+        names: None,
+        values: None,
+    };
+    visitor.visit_expr(event);
+    let (Some(names), Some(values)) = (visitor.names, visitor.values) else {
+        return Vec::new();
+    };
+    names
+        .into_iter()
+        .zip(values)
+        .filter_map(|(name, value)| {
+            // `(&field, Some(&value as &dyn Value))`
+            if let Some(name) = name
+                && name != sym::message
+                && let ExprKind::Tup([_, value]) = value.kind
+                && let ExprKind::Call(_, [value]) = value.kind
+                && let ExprKind::Cast(value, _) = value.kind
+                && let ExprKind::AddrOf(_, _, value) = value.kind
+            {
+                Some((name, recorded_value(cx, value)))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Removes the `debug(&value)` and `display(&value)` calls of the `?` and `%` sigils.
+fn recorded_value<'tcx>(cx: &LateContext<'_>, value: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    if let ExprKind::Call(func, [arg]) = value.kind
+        && let ExprKind::AddrOf(_, _, inner) = arg.kind
+        && (is_expr_path_def_path(cx, func, &paths::TRACING_FIELD_DEBUG)
+            || is_expr_path_def_path(cx, func, &paths::TRACING_FIELD_DISPLAY))
+    {
+        inner
+    } else {
+        value
+    }
+}
+
+/// Returns the field name recorded by a `field` or `field.nested` shorthand, if the value is a
+/// local or one of its fields, with the span of its identifiers.
+fn field_path(value: &Expr<'_>) -> Option<(String, Span)> {
+    match value.kind {
+        ExprKind::Path(QPath::Resolved(
+            None,
+            Path {
+                segments: [segment], ..
+            },
+        )) => Some((segment.ident.to_string(), segment.ident.span)),
+        ExprKind::Field(base, ident) => {
+            field_path(base).map(|(base, span)| (format!("{}.{}", base, ident), span.to(ident.span)))
+        },
+        _ => None,
+    }
+}
+
+/// Finds the names of the `FieldSet::new` call in the callsite statics of an event, and the values
+/// of its `FieldSet::value_set` call.
+struct EventVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    /// The results of the body being visited, as the statics have their own
+    typeck_results: &'tcx TypeckResults<'tcx>,
+    /// The names of the fields, `None` for the ones named by a constant
+    names: Option<Vec<Option<Symbol>>>,
+    values: Option<&'tcx [Expr<'tcx>]>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for EventVisitor<'a, 'tcx> {
+    type NestedFilter = nested_filter::All;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+
+    fn visit_nested_body(&mut self, body_id: BodyId) {
+        let typeck_results = mem::replace(&mut self.typeck_results, self.cx.tcx.typeck_body(body_id));
+        self.visit_body(self.cx.tcx.hir().body(body_id));
+        self.typeck_results = typeck_results;
+    }
+
+    fn visit_expr(&mut self, e: &'tcx Expr<'tcx>) {
+        if self.names.is_none()
+            && let ExprKind::Call(func, [names, _]) = e.kind
+            && let ExprKind::Path(ref qpath) = func.kind
+            && let Some(def_id) = self.typeck_results.qpath_res(qpath, func.hir_id).opt_def_id()
+            && match_def_path(self.cx, def_id, &paths::TRACING_FIELD_SET_NEW)
+            && let ExprKind::AddrOf(_, _, names) = names.kind
+            && let ExprKind::Array(names) = names.kind
+        {
+            self.names = Some(
+                names
+                    .iter()
+                    .map(|name| match name.kind {
+                        ExprKind::Lit(Spanned {
+                            node: LitKind::Str(name, _),
+                            ..
+                        }) => Some(name),
+                        _ => None,
+                    })
+                    .collect(),
+            );
+        } else if self.values.is_none()
+            && let ExprKind::MethodCall(_, [_, values], _) = e.kind
+            && let Some(def_id) = self.typeck_results.type_dependent_def_id(e.hir_id)
+            && match_def_path(self.cx, def_id, &paths::TRACING_FIELD_SET_VALUE_SET)
+            && let ExprKind::AddrOf(_, _, values) = values.kind
+            && let ExprKind::Array(values) = values.kind
+        {
+            self.values = Some(values);
+        }
+        walk_expr(self, e);
+    }
+}
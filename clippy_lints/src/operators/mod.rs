@@ -21,6 +21,7 @@ mod modulo_one;
 mod needless_bitwise_bool;
 mod numeric_arithmetic;
 mod op_ref;
+mod provable_overflow;
 mod ptr_eq;
 mod self_assignment;
 mod verbose_bit_mask;
@@ -64,18 +65,26 @@ declare_clippy_lint! {
     /// Specifically, checks for any operators (`+`, `-`, `*`, `<<`, etc) which are capable
     /// of overflowing according to the [Rust
     /// Reference](https://doc.rust-lang.org/reference/expressions/operator-expr.html#overflow),
-    /// or which can panic (`/`, `%`). No bounds analysis or sophisticated reasoning is
-    /// attempted.
+    /// or which can panic (`/`, `%`). Operations whose result provably fits in its type are not
+    /// linted, e.g. `(x % 100) + 1` or `x + 1` after `if x < 10`. This is decided from the ranges
+    /// of the operands, which are computed from constants, casts from smaller types, operations
+    /// and methods like `%`, `&`, `>>` or `min`, and for immutable locals from their initializer
+    /// and the `if` conditions and `assert!`s guarding the operation.
     ///
     /// ### Why is this bad?
     /// Integer overflow will trigger a panic in debug builds or will wrap in
     /// release mode. Division by zero will cause a panic in either mode. In some applications one
     /// wants explicitly checked, wrapping or saturating arithmetic.
     ///
+    /// ### Known problems
+    /// The range analysis is local to the function and doesn't follow mutable locals, fields or
+    /// function results, so operations which can't overflow in practice may still be linted.
+    ///
     /// ### Example
     /// ```rust
-    /// # let a = 0;
-    /// a + 1;
+    /// fn next(a: u32) -> u32 {
+    ///     a + 1
+    /// }
     /// ```
     #[clippy::version = "pre 1.29.0"]
     pub INTEGER_ARITHMETIC,
@@ -673,6 +682,38 @@ declare_clippy_lint! {
     "Boolean expressions that use bitwise rather than lazy operators"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for integer additions, subtractions and multiplications whose
+    /// result is always out of the range of their type, based on the known
+    /// ranges of their operands.
+    ///
+    /// ### Why is this bad?
+    /// The operation panics in debug builds, and silently wraps around in
+    /// release builds.
+    ///
+    /// ### Known problems
+    /// Operations on known values are left to rustc's `arithmetic_overflow`
+    /// lint.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn padded_len(len: u8) -> u8 {
+    ///     len.max(200) + 100
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn padded_len(len: u8) -> u16 {
+    ///     u16::from(len.max(200)) + 100
+    /// }
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub PROVABLE_OVERFLOW,
+    correctness,
+    "integer arithmetic which always overflows"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Use `std::ptr::eq` when applicable
@@ -769,6 +810,7 @@ impl_lint_pass!(Operators => [
     MODULO_ONE,
     MODULO_ARITHMETIC,
     NEEDLESS_BITWISE_BOOL,
+    PROVABLE_OVERFLOW,
     PTR_EQ,
     SELF_ASSIGNMENT,
 ]);
@@ -797,6 +839,7 @@ impl<'tcx> LateLintPass<'tcx> for Operators {
                     ptr_eq::check(cx, e, op.node, lhs, rhs);
                 }
                 self.arithmetic_context.check_binary(cx, e, op.node, lhs, rhs);
+                provable_overflow::check(cx, e, op.node, lhs, rhs);
                 bit_mask::check(cx, e, op.node, lhs, rhs);
                 verbose_bit_mask::check(cx, e, op.node, lhs, rhs, self.verbose_bit_mask_threshold);
                 double_comparison::check(cx, op.node, lhs, rhs, e.span);
@@ -1,5 +1,6 @@
 use clippy_utils::consts::constant_simple;
 use clippy_utils::diagnostics::span_lint;
+use clippy_utils::value_range::{binary_op_range, IntRange};
 use rustc_hir as hir;
 use rustc_lint::LateContext;
 use rustc_span::source_map::Span;
@@ -46,6 +47,9 @@ impl Context {
 
         let (l_ty, r_ty) = (cx.typeck_results().expr_ty(l), cx.typeck_results().expr_ty(r));
         if l_ty.peel_refs().is_integral() && r_ty.peel_refs().is_integral() {
+            if result_fits(cx, op, l, r) {
+                return;
+            }
             match op {
                 hir::BinOpKind::Div | hir::BinOpKind::Rem => match &r.kind {
                     hir::ExprKind::Lit(_lit) => (),
@@ -126,3 +130,20 @@ impl Context {
         self.const_span = None;
     }
 }
+
+/// Checks if the result of the operation provably fits in its type. Operations on plain constants
+/// are left to the type-based check, like casts of constants.
+fn result_fits(cx: &LateContext<'_>, op: hir::BinOpKind, l: &hir::Expr<'_>, r: &hir::Expr<'_>) -> bool {
+    if constant_simple(cx, cx.typeck_results(), l).is_some() && constant_simple(cx, cx.typeck_results(), r).is_some() {
+        return false;
+    }
+    // The type of a compound assignment is `()`, so use the one of the left operand.
+    let ty = cx.typeck_results().expr_ty(l).peel_refs();
+    if let Some(range) = binary_op_range(cx, op, l, r)
+        && let Some(ty_range) = IntRange::of_ty(cx.tcx, ty)
+    {
+        ty_range.contains_range(range)
+    } else {
+        false
+    }
+}
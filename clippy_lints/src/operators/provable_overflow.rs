@@ -0,0 +1,35 @@
+use clippy_utils::diagnostics::span_lint_and_note;
+use clippy_utils::value_range::{binary_op_range, expr_range, IntRange};
+use rustc_hir::{BinOpKind, Expr};
+use rustc_lint::LateContext;
+
+use super::PROVABLE_OVERFLOW;
+
+pub(crate) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, op: BinOpKind, left: &Expr<'_>, right: &Expr<'_>) {
+    if !matches!(op, BinOpKind::Add | BinOpKind::Sub | BinOpKind::Mul) || expr.span.from_expansion() {
+        return;
+    }
+    let ty = cx.typeck_results().expr_ty(left).peel_refs();
+    let Some(ty_range) = IntRange::of_ty(cx.tcx, ty) else { return };
+    // Operations on known values are already reported by rustc's `arithmetic_overflow`.
+    if expr_range(cx, left).map_or(true, IntRange::is_singleton)
+        && expr_range(cx, right).map_or(true, IntRange::is_singleton)
+    {
+        return;
+    }
+    if let Some(range) = binary_op_range(cx, op, left, right)
+        && range.intersect(ty_range).is_none()
+    {
+        span_lint_and_note(
+            cx,
+            PROVABLE_OVERFLOW,
+            expr.span,
+            "this arithmetic operation will always overflow",
+            None,
+            &format!(
+                "the result is in `{}`, but `{}` can only hold values in `{}`",
+                range, ty, ty_range
+            ),
+        );
+    }
+}
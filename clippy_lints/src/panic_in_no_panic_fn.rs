@@ -0,0 +1,318 @@
+use clippy_utils::attrs::get_attr;
+use clippy_utils::consts::constant;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::higher;
+use clippy_utils::macros::{is_panic, root_macro_call_first_node};
+use clippy_utils::match_def_path;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::{walk_expr, FnKind, Visitor};
+use rustc_hir::{AnonConst, BinOpKind, Body, Expr, ExprKind, FnDecl, HirId, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::hir::nested_filter;
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::subst::SubstsRef;
+use rustc_middle::ty::{self, Instance, TypeckResults};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span};
+use std::collections::VecDeque;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks functions marked with `#[clippy::no_panic]` (or listed in the `no-panic-functions`
+    /// configuration) for panicking code, following calls to other functions of the current crate.
+    /// Reported are panicking macros, `unwrap`/`expect` on `Option` and `Result`, indexing and
+    /// slicing, and integer arithmetic which may overflow or divide by zero.
+    ///
+    /// ### Why is this bad?
+    /// Some functions must never panic, e.g. because they run inside of signal handlers, FFI
+    /// callbacks or `Drop` implementations. Lints like `unwrap_used` or `indexing_slicing` only
+    /// look at a single function body and miss panics hidden behind a call to a helper function.
+    ///
+    /// ### Known problems
+    /// Only calls to functions defined in the current crate are followed. Calls through function
+    /// pointers or trait objects are not followed, and functions of other crates are assumed not
+    /// to panic.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn first(v: &[u32]) -> u32 {
+    ///     v[0]
+    /// }
+    ///
+    /// #[clippy::no_panic]
+    /// fn checked(v: &[u32]) -> u32 {
+    ///     if v.is_empty() { 0 } else { first(v) }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn first(v: &[u32]) -> u32 {
+    ///     v.first().copied().unwrap_or_default()
+    /// }
+    ///
+    /// #[clippy::no_panic]
+    /// fn checked(v: &[u32]) -> u32 {
+    ///     first(v)
+    /// }
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub PANIC_IN_NO_PANIC_FN,
+    restriction,
+    "panicking code reachable from a function marked as `no_panic`"
+}
+
+#[derive(Clone, Copy)]
+enum PanicKind {
+    Macro,
+    Unwrap,
+    Indexing,
+    Slicing,
+    Arithmetic,
+}
+
+impl PanicKind {
+    fn descr(self) -> &'static str {
+        match self {
+            Self::Macro => "panicking macro",
+            Self::Unwrap => "`unwrap`/`expect` call",
+            Self::Indexing => "indexing",
+            Self::Slicing => "slicing",
+            Self::Arithmetic => "arithmetic which may overflow",
+        }
+    }
+}
+
+/// The panic sites and callees of a single function body.
+#[derive(Default)]
+struct FnInfo {
+    panics: Vec<(Span, PanicKind)>,
+    /// Functions of the current crate called from this body.
+    calls: Vec<LocalDefId>,
+}
+
+pub struct PanicInNoPanicFn {
+    no_panic_functions: Vec<String>,
+    /// The roots of the search, in the order they were found.
+    roots: Vec<LocalDefId>,
+    fns: FxHashMap<LocalDefId, FnInfo>,
+}
+
+impl PanicInNoPanicFn {
+    pub fn new(no_panic_functions: Vec<String>) -> Self {
+        Self {
+            no_panic_functions,
+            roots: Vec::new(),
+            fns: FxHashMap::default(),
+        }
+    }
+
+    fn is_root(&self, cx: &LateContext<'_>, def_id: LocalDefId, hir_id: HirId) -> bool {
+        get_attr(cx.sess(), cx.tcx.hir().attrs(hir_id), "no_panic").count() > 0
+            || self.no_panic_functions.iter().any(|path| {
+                let segs: Vec<_> = path.split("::").collect();
+                match_def_path(cx, def_id.to_def_id(), &segs)
+            })
+    }
+}
+
+impl_lint_pass!(PanicInNoPanicFn => [PANIC_IN_NO_PANIC_FN]);
+
+impl<'tcx> LateLintPass<'tcx> for PanicInNoPanicFn {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        _: Span,
+        hir_id: HirId,
+    ) {
+        // Closures are visited as part of the function defining them.
+        if matches!(kind, FnKind::Closure) {
+            return;
+        }
+        let def_id = cx.tcx.hir().local_def_id(hir_id);
+        if self.is_root(cx, def_id, hir_id) {
+            self.roots.push(def_id);
+        }
+
+        let mut visitor = PanicVisitor {
+            cx,
+            typeck: cx.typeck_results(),
+            info: FnInfo::default(),
+        };
+        visitor.visit_expr(&body.value);
+        self.fns.insert(def_id, visitor.info);
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for &root in &self.roots {
+            // Breadth first search, so the reported call chains are as short as possible.
+            let mut callers = FxHashMap::default();
+            let mut seen = FxHashSet::default();
+            // A panic is reported once for each root it's reachable from.
+            let mut reported = FxHashSet::default();
+            let mut queue = VecDeque::from([root]);
+            seen.insert(root);
+
+            while let Some(current) = queue.pop_front() {
+                let Some(info) = self.fns.get(&current) else { continue };
+
+                for &(span, kind) in &info.panics {
+                    if !reported.insert(span) {
+                        continue;
+                    }
+                    let chain = call_chain(cx, &callers, root, current);
+                    span_lint_hir_and_then(
+                        cx,
+                        PANIC_IN_NO_PANIC_FN,
+                        cx.tcx.hir().local_def_id_to_hir_id(root),
+                        span,
+                        &format!(
+                            "{} reachable from `no_panic` function `{}`",
+                            kind.descr(),
+                            cx.tcx.item_name(root.to_def_id())
+                        ),
+                        |diag| {
+                            if chain.len() > 1 {
+                                diag.note(&format!("call chain: {}", chain.join(" -> ")));
+                            }
+                        },
+                    );
+                }
+
+                for &callee in &info.calls {
+                    if seen.insert(callee) {
+                        callers.insert(callee, current);
+                        queue.push_back(callee);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Builds the list of function names leading from `root` to `target`.
+fn call_chain(
+    cx: &LateContext<'_>,
+    callers: &FxHashMap<LocalDefId, LocalDefId>,
+    root: LocalDefId,
+    target: LocalDefId,
+) -> Vec<String> {
+    let mut chain = vec![format!("`{}`", cx.tcx.def_path_str(target.to_def_id()))];
+    let mut current = target;
+    while current != root {
+        let Some(&caller) = callers.get(&current) else { break };
+        chain.push(format!("`{}`", cx.tcx.def_path_str(caller.to_def_id())));
+        current = caller;
+    }
+    chain.reverse();
+    chain
+}
+
+struct PanicVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    typeck: &'tcx TypeckResults<'tcx>,
+    info: FnInfo,
+}
+
+impl<'a, 'tcx> PanicVisitor<'a, 'tcx> {
+    /// Records a call to `def_id` if it's a function of the current crate. Trait methods are
+    /// resolved to their implementation when possible.
+    fn add_call(&mut self, def_id: DefId, substs: SubstsRef<'tcx>) {
+        let def_id = if self.cx.tcx.trait_of_item(def_id).is_some() {
+            match Instance::resolve(self.cx.tcx, self.cx.param_env, def_id, substs) {
+                Ok(Some(instance)) => instance.def_id(),
+                _ => def_id,
+            }
+        } else {
+            def_id
+        };
+        if let Some(local) = def_id.as_local() {
+            self.info.calls.push(local);
+        }
+    }
+
+    fn is_overflowing_op(&self, expr: &'tcx Expr<'_>, lhs: &'tcx Expr<'_>) -> bool {
+        self.typeck.expr_ty(lhs).peel_refs().is_integral() && constant(self.cx, self.typeck, expr).is_none()
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for PanicVisitor<'a, 'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        if let Some(macro_call) = root_macro_call_first_node(self.cx, expr) {
+            if is_panic(self.cx, macro_call.def_id)
+                || matches!(
+                    self.cx.tcx.item_name(macro_call.def_id).as_str(),
+                    "assert" | "assert_eq" | "assert_ne" | "todo" | "unimplemented" | "unreachable"
+                )
+            {
+                self.info.panics.push((macro_call.span, PanicKind::Macro));
+                return;
+            }
+        }
+        if in_external_macro(self.cx.sess(), expr.span) {
+            return;
+        }
+
+        match expr.kind {
+            ExprKind::MethodCall(path, [recv, ..], _) => {
+                let recv_ty = self.typeck.expr_ty(recv).peel_refs();
+                if matches!(path.ident.as_str(), "unwrap" | "expect" | "unwrap_err" | "expect_err")
+                    && (is_type_diagnostic_item(self.cx, recv_ty, sym::Option)
+                        || is_type_diagnostic_item(self.cx, recv_ty, sym::Result))
+                {
+                    self.info.panics.push((expr.span, PanicKind::Unwrap));
+                } else if let Some(def_id) = self.typeck.type_dependent_def_id(expr.hir_id) {
+                    self.add_call(def_id, self.typeck.node_substs(expr.hir_id));
+                }
+            },
+            ExprKind::Path(_) => {
+                // This also catches functions which are only referenced, e.g. passed to `map`.
+                if let ty::FnDef(def_id, substs) = *self.typeck.expr_ty(expr).kind() {
+                    self.add_call(def_id, substs);
+                }
+            },
+            ExprKind::Index(_, index) => {
+                if let Some(range) = higher::Range::hir(index) {
+                    if range.start.is_some() || range.end.is_some() {
+                        self.info.panics.push((expr.span, PanicKind::Slicing));
+                    }
+                } else {
+                    self.info.panics.push((expr.span, PanicKind::Indexing));
+                }
+            },
+            ExprKind::Binary(op, lhs, _) | ExprKind::AssignOp(op, lhs, _)
+                if matches!(
+                    op.node,
+                    BinOpKind::Add
+                        | BinOpKind::Sub
+                        | BinOpKind::Mul
+                        | BinOpKind::Div
+                        | BinOpKind::Rem
+                        | BinOpKind::Shl
+                        | BinOpKind::Shr
+                ) && self.is_overflowing_op(expr, lhs) =>
+            {
+                self.info.panics.push((expr.span, PanicKind::Arithmetic));
+            },
+            ExprKind::Unary(UnOp::Neg, arg) if self.is_overflowing_op(expr, arg) => {
+                self.info.panics.push((expr.span, PanicKind::Arithmetic));
+            },
+            _ => {},
+        }
+
+        walk_expr(self, expr);
+    }
+
+    // Panics in const blocks will cause compilation to fail.
+    fn visit_anon_const(&mut self, _: &'tcx AnonConst) {}
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+}
@@ -0,0 +1,406 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::ty::{is_type_diagnostic_item, match_type};
+use clippy_utils::{fn_def_id, path_to_local, paths};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::{walk_expr, Visitor};
+use rustc_hir::{Block, Body, Expr, ExprKind, HirId, PatKind, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Collects the order in which the `Mutex`es and `RwLock`s (from `std` or
+    /// `parking_lot`) stored in statics or struct fields are acquired in each
+    /// function, including the locks acquired by the functions of the crate it
+    /// calls while holding a guard, and checks for:
+    /// * two locks which are acquired in opposite orders by different code paths;
+    /// * a lock which is acquired again while its guard is still held.
+    ///
+    /// Locks are identified by the static or the struct field they are stored in,
+    /// so all instances of a struct are treated as the same lock when checking the
+    /// order. A field lock is only reported as acquired again in the same function
+    /// when both are reached through the same local, like `self`.
+    ///
+    /// ### Why is this bad?
+    /// When two threads take the same locks in opposite orders, each of them
+    /// can end up waiting for the lock the other holds forever. Locking a
+    /// `Mutex` which is already held by the current thread deadlocks (or panics).
+    ///
+    /// ### Known problems
+    /// Guards are assumed to be held until the end of the enclosing block when
+    /// bound with `let`, and until the end of the statement otherwise. Locks
+    /// behind local variables, closures and trait objects are not tracked.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// struct Bank {
+    ///     checking: Mutex<u64>,
+    ///     savings: Mutex<u64>,
+    /// }
+    ///
+    /// impl Bank {
+    ///     fn to_savings(&self, amount: u64) {
+    ///         let mut checking = self.checking.lock().unwrap();
+    ///         let mut savings = self.savings.lock().unwrap();
+    ///         *checking -= amount;
+    ///         *savings += amount;
+    ///     }
+    ///
+    ///     fn to_checking(&self, amount: u64) {
+    ///         let mut savings = self.savings.lock().unwrap();
+    ///         let mut checking = self.checking.lock().unwrap();
+    ///         *savings -= amount;
+    ///         *checking += amount;
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// # struct Bank {
+    /// #     checking: Mutex<u64>,
+    /// #     savings: Mutex<u64>,
+    /// # }
+    /// impl Bank {
+    ///     // ...
+    ///     fn to_checking(&self, amount: u64) {
+    ///         let mut checking = self.checking.lock().unwrap();
+    ///         let mut savings = self.savings.lock().unwrap();
+    ///         *savings -= amount;
+    ///         *checking += amount;
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub POTENTIAL_DEADLOCK,
+    nursery,
+    "locks acquired in inconsistent orders, or acquired again while already held"
+}
+
+#[derive(Default)]
+pub struct PotentialDeadlock {
+    /// The locks acquired directly by each function.
+    acquired: FxHashMap<LocalDefId, Vec<Lock>>,
+    /// Every call to a function of the current crate.
+    calls: Vec<CallSite>,
+    /// A lock acquired while another one is held, in the same function.
+    edges: Vec<Edge>,
+}
+
+impl_lint_pass!(PotentialDeadlock => [POTENTIAL_DEADLOCK]);
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum LockId {
+    Static(DefId),
+    /// The struct and the name of the field the lock is stored in.
+    Field(DefId, Symbol),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LockKind {
+    Exclusive,
+    Shared,
+}
+
+#[derive(Clone, Copy)]
+struct Lock {
+    id: LockId,
+    kind: LockKind,
+    /// For a lock stored in a field, the local holding the struct, if it's directly a local.
+    base: Option<HirId>,
+    /// The span of the call acquiring the lock.
+    span: Span,
+}
+
+impl Lock {
+    fn conflicts_with(&self, other: &Lock) -> bool {
+        self.id == other.id && (self.kind == LockKind::Exclusive || other.kind == LockKind::Exclusive)
+    }
+}
+
+impl Edge {
+    /// Checks if the edge acquires the lock it holds. The struct of a field lock can only be known
+    /// to be the same if both are locked in the same function, through the same local, like
+    /// `self`.
+    fn is_reentrant(&self) -> bool {
+        self.held.conflicts_with(&self.acquired)
+            && match self.held.id {
+                LockId::Static(_) => true,
+                LockId::Field(..) => {
+                    self.callee.is_some() || (self.held.base.is_some() && self.held.base == self.acquired.base)
+                },
+            }
+    }
+}
+
+struct CallSite {
+    caller: LocalDefId,
+    callee: LocalDefId,
+    held: Vec<Lock>,
+    span: Span,
+    hir_id: HirId,
+}
+
+struct Edge {
+    held: Lock,
+    acquired: Lock,
+    /// The call acquiring the lock, either directly or in the called function.
+    span: Span,
+    hir_id: HirId,
+    callee: Option<LocalDefId>,
+}
+
+impl<'tcx> LateLintPass<'tcx> for PotentialDeadlock {
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &'tcx Body<'tcx>) {
+        let caller = cx.tcx.hir().body_owner_def_id(body.id());
+        let mut visitor = LockVisitor {
+            cx,
+            caller,
+            held: Vec::new(),
+            acquired: Vec::new(),
+            calls: Vec::new(),
+            edges: Vec::new(),
+        };
+        visitor.visit_expr(&body.value);
+        if !visitor.acquired.is_empty() {
+            self.acquired.insert(caller, visitor.acquired);
+        }
+        self.calls.extend(visitor.calls);
+        self.edges.extend(visitor.edges);
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        // Propagate the acquired locks to the callers until nothing changes.
+        let mut acquired = std::mem::take(&mut self.acquired);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for call in &self.calls {
+                let Some(callee_locks) = acquired.get(&call.callee).cloned() else { continue };
+                let locks = acquired.entry(call.caller).or_default();
+                for lock in callee_locks {
+                    if !locks.iter().any(|l| l.id == lock.id && l.kind == lock.kind) {
+                        locks.push(lock);
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        let mut edges = std::mem::take(&mut self.edges);
+        for call in &self.calls {
+            for &lock in acquired.get(&call.callee).into_iter().flatten() {
+                edges.extend(call.held.iter().map(|&held| Edge {
+                    held,
+                    acquired: lock,
+                    span: call.span,
+                    hir_id: call.hir_id,
+                    callee: Some(call.callee),
+                }));
+            }
+        }
+
+        let mut reported = FxHashSet::default();
+        for (i, edge) in edges.iter().enumerate() {
+            if edge.held.id == edge.acquired.id {
+                if edge.is_reentrant() {
+                    lint_reentrant_lock(cx, edge);
+                }
+                continue;
+            }
+            // Report every pair of locks once, at the second path in visiting order. Shared locks
+            // count too, as a writer waiting for the lock blocks new readers.
+            if let Some(other) = edges[..i]
+                .iter()
+                .find(|other| other.held.id == edge.acquired.id && other.acquired.id == edge.held.id)
+                && reported.insert((edge.held.id, edge.acquired.id))
+                && reported.insert((edge.acquired.id, edge.held.id))
+            {
+                lint_lock_order_inversion(cx, edge, other);
+            }
+        }
+    }
+}
+
+fn lint_reentrant_lock(cx: &LateContext<'_>, edge: &Edge) {
+    let name = lock_name(cx, edge.held.id);
+    let msg = if let Some(callee) = edge.callee {
+        format!(
+            "`{}` is locked again by calling `{}` while it is already held",
+            name,
+            cx.tcx.item_name(callee.to_def_id())
+        )
+    } else {
+        format!("`{}` is locked while it is already held", name)
+    };
+    span_lint_hir_and_then(cx, POTENTIAL_DEADLOCK, edge.hir_id, edge.span, &msg, |diag| {
+        diag.span_note(edge.held.span, &format!("`{}` was first locked here", name));
+        if edge.callee.is_some() {
+            diag.span_note(edge.acquired.span, "and is locked again here");
+        }
+    });
+}
+
+fn lint_lock_order_inversion(cx: &LateContext<'_>, edge: &Edge, other: &Edge) {
+    let held = lock_name(cx, edge.held.id);
+    let acquired = lock_name(cx, edge.acquired.id);
+    span_lint_hir_and_then(
+        cx,
+        POTENTIAL_DEADLOCK,
+        edge.hir_id,
+        edge.span,
+        &format!(
+            "`{}` is locked while `{}` is held, in the opposite order of another code path",
+            acquired, held
+        ),
+        |diag| {
+            diag.help("always acquire these locks in the same order");
+            diag.span_note(edge.held.span, &format!("`{}` is locked here", held));
+            diag.span_note(
+                other.span,
+                &format!("but here `{}` is locked while `{}` is held", held, acquired),
+            );
+        },
+    );
+}
+
+fn lock_name(cx: &LateContext<'_>, id: LockId) -> String {
+    match id {
+        LockId::Static(def_id) => cx.tcx.def_path_str(def_id),
+        LockId::Field(def_id, field) => format!("{}::{}", cx.tcx.def_path_str(def_id), field),
+    }
+}
+
+struct LockVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    caller: LocalDefId,
+    /// The currently held guards, with the local they are bound to.
+    held: Vec<(Lock, HirId, Option<HirId>)>,
+    acquired: Vec<Lock>,
+    calls: Vec<CallSite>,
+    edges: Vec<Edge>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LockVisitor<'a, 'tcx> {
+    fn visit_block(&mut self, block: &'tcx Block<'tcx>) {
+        let block_len = self.held.len();
+        for stmt in block.stmts {
+            let stmt_len = self.held.len();
+            self.visit_stmt(stmt);
+            // Temporaries are dropped at the end of the statement, except for a guard bound to a
+            // local, which lives until the end of the block.
+            let bound_guard = if let StmtKind::Local(local) = stmt.kind
+                && let PatKind::Binding(_, local_id, _, None) = local.pat.kind
+                && let Some(init) = local.init
+            {
+                let acquisition = peel_unwrap(init);
+                self.held[stmt_len..]
+                    .iter()
+                    .find(|(_, expr_id, _)| *expr_id == acquisition.hir_id)
+                    .map(|&(lock, expr_id, _)| (lock, expr_id, Some(local_id)))
+            } else {
+                None
+            };
+            self.held.truncate(stmt_len);
+            self.held.extend(bound_guard);
+        }
+        if let Some(expr) = block.expr {
+            self.visit_expr(expr);
+        }
+        self.held.truncate(block_len);
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        walk_expr(self, expr);
+
+        if let Some(lock) = lock_acquisition(self.cx, expr) {
+            self.edges.extend(self.held.iter().map(|&(held, ..)| Edge {
+                held,
+                acquired: lock,
+                span: expr.span,
+                hir_id: expr.hir_id,
+                callee: None,
+            }));
+            self.acquired.push(lock);
+            self.held.push((lock, expr.hir_id, None));
+        } else if let Some(def_id) = fn_def_id(self.cx, expr) {
+            if self.cx.tcx.is_diagnostic_item(sym::mem_drop, def_id) {
+                if let ExprKind::Call(_, [arg]) = expr.kind
+                    && let Some(local_id) = path_to_local(arg)
+                {
+                    self.held.retain(|&(.., local)| local != Some(local_id));
+                }
+            } else if let Some(callee) = def_id.as_local() {
+                self.calls.push(CallSite {
+                    caller: self.caller,
+                    callee,
+                    held: self.held.iter().map(|&(lock, ..)| lock).collect(),
+                    span: expr.span,
+                    hir_id: expr.hir_id,
+                });
+            }
+        }
+    }
+}
+
+/// Peels `.unwrap()` and `.expect(..)` calls off a `LockResult`.
+fn peel_unwrap<'tcx>(mut expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    while let ExprKind::MethodCall(path, [recv, ..], _) = expr.kind
+        && matches!(path.ident.as_str(), "unwrap" | "expect")
+    {
+        expr = recv;
+    }
+    expr
+}
+
+/// Checks if the expression is a blocking `lock`, `read` or `write` call on a lock stored in a
+/// static or a struct field.
+fn lock_acquisition<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<Lock> {
+    let ExprKind::MethodCall(path, [recv, ..], _) = expr.kind else { return None };
+    let ty = cx.typeck_results().expr_ty_adjusted(recv).peel_refs();
+    let kind = match path.ident.as_str() {
+        "lock" if is_type_diagnostic_item(cx, ty, sym::Mutex) || match_type(cx, ty, &paths::PARKING_LOT_MUTEX) => {
+            LockKind::Exclusive
+        },
+        "read" if match_type(cx, ty, &paths::RWLOCK) || match_type(cx, ty, &paths::PARKING_LOT_RWLOCK) => {
+            LockKind::Shared
+        },
+        "write" if match_type(cx, ty, &paths::RWLOCK) || match_type(cx, ty, &paths::PARKING_LOT_RWLOCK) => {
+            LockKind::Exclusive
+        },
+        _ => return None,
+    };
+
+    let mut recv = recv;
+    while let ExprKind::AddrOf(_, _, inner) | ExprKind::Unary(UnOp::Deref, inner) = recv.kind {
+        recv = inner;
+    }
+    let (id, base) = match recv.kind {
+        ExprKind::Field(base, field) => {
+            let owner = cx.typeck_results().expr_ty_adjusted(base).peel_refs();
+            let mut base = base;
+            while let ExprKind::AddrOf(_, _, inner) | ExprKind::Unary(UnOp::Deref, inner) = base.kind {
+                base = inner;
+            }
+            (
+                LockId::Field(owner.ty_adt_def()?.did(), field.name),
+                path_to_local(base),
+            )
+        },
+        ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, recv.hir_id) {
+            Res::Def(DefKind::Static(_), def_id) => (LockId::Static(def_id), None),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(Lock {
+        id,
+        kind,
+        base,
+        span: expr.span,
+    })
+}
@@ -33,7 +33,9 @@ macro_rules! unwrap_or_continue {
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for a redundant `clone()` (and its relatives) which clones an owned
-    /// value that is going to be dropped without further use.
+    /// value that is going to be dropped without further use. This includes clones
+    /// of struct fields and clones inside loops, and clones passed to generic
+    /// parameters which only need to implement `AsRef`, where a reference would do.
     ///
     /// ### Why is this bad?
     /// It is not always possible for the compiler to eliminate useless
@@ -127,7 +129,8 @@ impl<'tcx> LateLintPass<'tcx> for RedundantClone {
 
             let from_deref = !from_borrow
                 && (match_def_path(cx, fn_def_id, &paths::PATH_TO_PATH_BUF)
-                    || match_def_path(cx, fn_def_id, &paths::OS_STR_TO_OS_STRING));
+                    || match_def_path(cx, fn_def_id, &paths::OS_STR_TO_OS_STRING)
+                    || match_def_path(cx, fn_def_id, &paths::SLICE_TO_VEC));
 
             if !from_borrow && !from_deref {
                 continue;
@@ -140,15 +143,17 @@ impl<'tcx> LateLintPass<'tcx> for RedundantClone {
             }
 
             // `{ arg = &cloned; clone(move arg); }` or `{ arg = &cloned; to_path_buf(arg); }`
-            let (cloned, cannot_move_out) = unwrap_or_continue!(find_stmt_assigns_to(cx, mir, arg, from_borrow, bb));
+            let (cloned_place, cannot_move_out) =
+                unwrap_or_continue!(find_stmt_assigns_to(cx, mir, arg, from_borrow, bb));
+            let cloned = cloned_place.local;
 
             let loc = mir::Location {
                 block: bb,
                 statement_index: bbdata.statements.len(),
             };
 
-            // `Local` to be cloned, and a local of `clone` call's destination
-            let (local, ret_local) = if from_borrow {
+            // `Place` to be cloned, and a local of `clone` call's destination
+            let (place, ret_local) = if from_borrow {
                 // `res = clone(arg)` can be turned into `res = move arg;`
                 // if `arg` is the only borrow of `cloned` at this point.
 
@@ -156,7 +161,7 @@ impl<'tcx> LateLintPass<'tcx> for RedundantClone {
                     continue;
                 }
 
-                (cloned, clone_ret)
+                (cloned_place, clone_ret)
             } else {
                 // `arg` is a reference as it is `.deref()`ed in the previous block.
                 // Look into the predecessor block and find out the source of deref.
@@ -174,7 +179,8 @@ impl<'tcx> LateLintPass<'tcx> for RedundantClone {
                     if res == cloned;
                     if cx.tcx.is_diagnostic_item(sym::deref_method, pred_fn_def_id);
                     if is_type_diagnostic_item(cx, pred_arg_ty, sym::PathBuf)
-                        || is_type_diagnostic_item(cx, pred_arg_ty, sym::OsString);
+                        || is_type_diagnostic_item(cx, pred_arg_ty, sym::OsString)
+                        || is_type_diagnostic_item(cx, pred_arg_ty, sym::Vec);
                     then {
                         (pred_arg, res)
                     } else {
@@ -182,7 +188,7 @@ impl<'tcx> LateLintPass<'tcx> for RedundantClone {
                     }
                 };
 
-                let (local, cannot_move_out) =
+                let (place, cannot_move_out) =
                     unwrap_or_continue!(find_stmt_assigns_to(cx, mir, pred_arg, true, ps[0]));
                 let loc = mir::Location {
                     block: bb,
@@ -199,23 +205,47 @@ impl<'tcx> LateLintPass<'tcx> for RedundantClone {
                 // StorageDead(pred_arg);
                 // res = to_path_buf(cloned);
                 // ```
-                if cannot_move_out || !possible_borrower.only_borrowers(&[arg, cloned], local, loc) {
+                if cannot_move_out || !possible_borrower.only_borrowers(&[arg, cloned], place.local, loc) {
                     continue;
                 }
 
-                (local, deref_clone_ret)
+                (place, deref_clone_ret)
             };
 
-            let clone_usage = if local == ret_local {
+            let span = terminator.source_info.span;
+            let scope = terminator.source_info.scope;
+            let node = mir.source_scopes[scope]
+                .local_data
+                .as_ref()
+                .assert_crate_local()
+                .lint_root;
+
+            let clone_usage = if place.local == ret_local {
                 CloneUsage {
                     cloned_used: false,
                     cloned_consume_or_mutate_loc: None,
                     clone_consumed_or_mutated: true,
                 }
             } else {
-                let clone_usage = visit_clone_usage(local, ret_local, mir, bb);
+                let clone_usage = visit_clone_usage(place, ret_local, mir, bb);
                 if clone_usage.cloned_used && clone_usage.clone_consumed_or_mutated {
-                    // cloned value is used, and the clone is modified or moved
+                    // cloned value is used, and the clone is modified or moved, but a reference
+                    // may still do if the clone is passed to a function only needing `AsRef`
+                    if from_borrow
+                        && mir.local_decls[ret_local].ty == arg_ty
+                        && is_moved_into_as_ref_param(cx, mir, &possible_borrower, terminator, ret_local, cloned)
+                        && let Some(snip) = snippet_opt(cx, span)
+                        && let Some(dot) = snip.rfind('.')
+                    {
+                        span_lint_hir_and_then(cx, REDUNDANT_CLONE, node, span, "redundant clone", |diag| {
+                            diag.span_suggestion(
+                                span,
+                                "the parameter only needs to implement `AsRef`, pass a reference instead",
+                                format!("&{}", &snip[..dot]),
+                                Applicability::MaybeIncorrect,
+                            );
+                        });
+                    }
                     continue;
                 } else if let Some(loc) = clone_usage.cloned_consume_or_mutate_loc {
                     // cloned value is mutated, and the clone is alive.
@@ -226,14 +256,6 @@ impl<'tcx> LateLintPass<'tcx> for RedundantClone {
                 clone_usage
             };
 
-            let span = terminator.source_info.span;
-            let scope = terminator.source_info.scope;
-            let node = mir.source_scopes[scope]
-                .local_data
-                .as_ref()
-                .assert_crate_local()
-                .lint_root;
-
             if_chain! {
                 if let Some(snip) = snippet_opt(cx, span);
                 if let Some(dot) = snip.rfind('.');
@@ -309,7 +331,7 @@ fn find_stmt_assigns_to<'tcx>(
     to_local: mir::Local,
     by_ref: bool,
     bb: mir::BasicBlock,
-) -> Option<(mir::Local, CannotMoveOut)> {
+) -> Option<(mir::Place<'tcx>, CannotMoveOut)> {
     let rvalue = mir.basic_blocks()[bb].statements.iter().rev().find_map(|stmt| {
         if let mir::StatementKind::Assign(box (mir::Place { local, .. }, v)) = &stmt.kind {
             return if *local == to_local { Some(v) } else { None };
@@ -320,11 +342,11 @@ fn find_stmt_assigns_to<'tcx>(
 
     match (by_ref, rvalue) {
         (true, mir::Rvalue::Ref(_, _, place)) | (false, mir::Rvalue::Use(mir::Operand::Copy(place))) => {
-            Some(base_local_and_movability(cx, mir, *place))
+            Some((*place, cannot_move_out(cx, mir, *place)))
         },
         (false, mir::Rvalue::Ref(_, _, place)) => {
             if let [mir::ProjectionElem::Deref] = place.as_ref().projection {
-                Some(base_local_and_movability(cx, mir, *place))
+                Some((*place, cannot_move_out(cx, mir, *place)))
             } else {
                 None
             }
@@ -333,15 +355,8 @@ fn find_stmt_assigns_to<'tcx>(
     }
 }
 
-/// Extracts and returns the undermost base `Local` of given `place`. Returns `place` itself
-/// if it is already a `Local`.
-///
-/// Also reports whether given `place` cannot be moved out.
-fn base_local_and_movability<'tcx>(
-    cx: &LateContext<'tcx>,
-    mir: &mir::Body<'tcx>,
-    place: mir::Place<'tcx>,
-) -> (mir::Local, CannotMoveOut) {
+/// Reports whether given `place` cannot be moved out.
+fn cannot_move_out<'tcx>(cx: &LateContext<'tcx>, mir: &mir::Body<'tcx>, place: mir::Place<'tcx>) -> CannotMoveOut {
     use rustc_middle::mir::PlaceRef;
 
     // Dereference. You cannot move things out from a borrowed value.
@@ -362,7 +377,7 @@ fn base_local_and_movability<'tcx>(
             && !is_copy(cx, mir::Place::ty_from(local, projection, &mir.local_decls, cx.tcx).ty);
     }
 
-    (local, deref || field || slice)
+    deref || field || slice
 }
 
 #[derive(Default)]
@@ -374,13 +389,18 @@ struct CloneUsage {
     /// Whether the clone value is mutated.
     clone_consumed_or_mutated: bool,
 }
-fn visit_clone_usage(cloned: mir::Local, clone: mir::Local, mir: &mir::Body<'_>, bb: mir::BasicBlock) -> CloneUsage {
-    struct V {
-        cloned: mir::Local,
+fn visit_clone_usage<'tcx>(
+    cloned: mir::Place<'tcx>,
+    clone: mir::Local,
+    mir: &mir::Body<'tcx>,
+    bb: mir::BasicBlock,
+) -> CloneUsage {
+    struct V<'tcx> {
+        cloned: mir::Place<'tcx>,
         clone: mir::Local,
         result: CloneUsage,
     }
-    impl<'tcx> mir::visit::Visitor<'tcx> for V {
+    impl<'tcx> mir::visit::Visitor<'tcx> for V<'tcx> {
         fn visit_basic_block_data(&mut self, block: mir::BasicBlock, data: &mir::BasicBlockData<'tcx>) {
             let statements = &data.statements;
             for (statement_index, statement) in statements.iter().enumerate() {
@@ -399,7 +419,9 @@ fn visit_clone_usage(cloned: mir::Local, clone: mir::Local, mir: &mir::Body<'_>,
         fn visit_place(&mut self, place: &mir::Place<'tcx>, ctx: PlaceContext, loc: mir::Location) {
             let local = place.local;
 
-            if local == self.cloned
+            if local == self.cloned.local
+                // Other fields can still be used after moving out of a field.
+                && !is_disjoint_field(place.as_ref(), self.cloned.as_ref())
                 && !matches!(
                     ctx,
                     PlaceContext::MutatingUse(MutatingUseContext::Drop) | PlaceContext::NonUse(_)
@@ -437,15 +459,8 @@ fn visit_clone_usage(cloned: mir::Local, clone: mir::Local, mir: &mir::Body<'_>,
         .skip(1)
         .fold(init, |usage, (tbb, tdata)| {
             // Short-circuit
-            if (usage.cloned_used && usage.clone_consumed_or_mutated) ||
-                // Give up on loops
-                tdata.terminator().successors().any(|s| s == bb)
-            {
-                return CloneUsage {
-                    cloned_used: true,
-                    clone_consumed_or_mutated: true,
-                    ..usage
-                };
+            if usage.cloned_used && usage.clone_consumed_or_mutated {
+                return usage;
             }
 
             let mut v = V {
@@ -454,10 +469,93 @@ fn visit_clone_usage(cloned: mir::Local, clone: mir::Local, mir: &mir::Body<'_>,
                 result: usage,
             };
             v.visit_basic_block_data(tbb, tdata);
+            // In a loop, the cloned value is used again by the clone of the next iteration. A
+            // temporary clone doesn't outlive the iteration, so uses after the loop are still fine.
+            if tdata.terminator().successors().any(|s| s == bb) {
+                v.result.cloned_used = true;
+            }
             v.result
         })
 }
 
+/// Checks if the places are different fields of the same local, e.g. `x.0` and `x.1.0`.
+fn is_disjoint_field<'tcx>(a: mir::PlaceRef<'tcx>, b: mir::PlaceRef<'tcx>) -> bool {
+    a.local == b.local
+        && a.projection
+            .iter()
+            .zip(b.projection)
+            .find(|(a, b)| a != b)
+            .map_or(false, |(a, b)| {
+                matches!((a, b), (mir::ProjectionElem::Field(..), mir::ProjectionElem::Field(..)))
+            })
+}
+
+/// Checks if the clone stored in `clone` is moved into the call right after `clone_terminator`,
+/// as an argument of a generic type parameter only bound by `AsRef`. A reference to `cloned` can
+/// be passed instead, if `cloned` isn't also passed to the same call.
+fn is_moved_into_as_ref_param<'tcx>(
+    cx: &LateContext<'tcx>,
+    mir: &'tcx mir::Body<'tcx>,
+    possible_borrower: &PossibleBorrowerMap<'_, 'tcx>,
+    clone_terminator: &mir::Terminator<'tcx>,
+    clone: mir::Local,
+    cloned: mir::Local,
+) -> bool {
+    let mir::TerminatorKind::Call { target: Some(next), .. } = clone_terminator.kind else {
+        return false;
+    };
+    let mir::TerminatorKind::Call { func, args, .. } = &mir.basic_blocks()[next].terminator().kind else {
+        return false;
+    };
+    let ty::FnDef(def_id, _) = *func.ty(mir, cx.tcx).kind() else {
+        return false;
+    };
+    let is_clone = |arg: &mir::Operand<'_>| matches!(arg, mir::Operand::Move(place) if place.as_local() == Some(clone));
+    let Some(index) = args.iter().position(is_clone) else {
+        return false;
+    };
+    if mir.local_kind(clone) != mir::LocalKind::Temp
+        || args.iter().enumerate().any(|(i, arg)| {
+            i != index
+                && arg.place().map_or(false, |p| {
+                    p.local == cloned || possible_borrower.is_possible_borrower(p.local, cloned)
+                })
+        })
+    {
+        return false;
+    }
+
+    let sig = cx.tcx.fn_sig(def_id).skip_binder();
+    let param_ty = sig.inputs()[index];
+    if !matches!(param_ty.kind(), ty::Param(_)) {
+        return false;
+    }
+    let Some(as_ref_trait) = cx.tcx.get_diagnostic_item(sym::AsRef) else {
+        return false;
+    };
+    let sized_trait = cx.tcx.lang_items().sized_trait();
+    let mentions_param = |ty: Ty<'tcx>| ty.walk().any(|arg| arg == param_ty.into());
+
+    // The parameter must only be bound by `AsRef` (and `Sized`), and must not be used elsewhere
+    // in the signature, since its type changes to a reference.
+    let predicates = cx.tcx.predicates_of(def_id).instantiate_identity(cx.tcx).predicates;
+    predicates.iter().all(|pred| match pred.kind().skip_binder() {
+        ty::PredicateKind::Trait(trait_pred) if trait_pred.self_ty() == param_ty => {
+            trait_pred.def_id() == as_ref_trait || Some(trait_pred.def_id()) == sized_trait
+        },
+        ty::PredicateKind::Trait(trait_pred) => !trait_pred.trait_ref.substs.types().any(mentions_param),
+        ty::PredicateKind::TypeOutlives(ty::OutlivesPredicate(outlived, _)) => !mentions_param(outlived),
+        ty::PredicateKind::Projection(proj) => {
+            !proj.projection_ty.substs.types().any(mentions_param) && !proj.term.ty().map_or(false, mentions_param)
+        },
+        _ => true,
+    }) && sig
+        .inputs_and_output
+        .iter()
+        .enumerate()
+        .all(|(i, ty)| i == index || !mentions_param(ty))
+}
+
 /// Determines liveness of each local purely based on `StorageLive`/`Dead`.
 #[derive(Copy, Clone)]
 struct MaybeStorageLive;
@@ -744,6 +842,13 @@ impl PossibleBorrowerMap<'_, '_> {
         self.bitset.0 == self.bitset.1
     }
 
+    /// Returns true if `local` may borrow from `borrowed`.
+    fn is_possible_borrower(&self, local: mir::Local, borrowed: mir::Local) -> bool {
+        self.map
+            .get(&borrowed)
+            .map_or(false, |borrowers| borrowers.contains(local))
+    }
+
     fn local_is_alive_at(&mut self, local: mir::Local, at: mir::Location) -> bool {
         self.maybe_live.seek_after_primary_effect(at);
         self.maybe_live.contains(local)
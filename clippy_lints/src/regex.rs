@@ -1,18 +1,27 @@
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
-use clippy_utils::{match_def_path, paths};
+use clippy_utils::source::snippet_opt;
+use clippy_utils::visitors::for_each_local_use_after_expr;
+use clippy_utils::{get_parent_expr, get_parent_node, is_trait_method, match_def_path, paths};
+use core::ops::ControlFlow;
 use if_chain::if_chain;
+use regex_syntax::ast::{self, Ast, ClassSetItem};
 use rustc_ast::ast::{LitKind, StrStyle};
-use rustc_hir::{BorrowKind, Expr, ExprKind};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{BorrowKind, Expr, ExprKind, ItemKind, Mutability, Node, PatKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::source_map::{BytePos, Span};
+use rustc_span::sym;
 
 declare_clippy_lint! {
     /// ### What it does
     /// Checks [regex](https://crates.io/crates/regex) creation
     /// (with `Regex::new`, `RegexBuilder::new`, or `RegexSet::new`) for correct
-    /// regex syntax.
+    /// regex syntax. The pattern can be a literal, `concat!`, a `const` or a
+    /// `static`. Patterns of `fancy_regex::Regex::new` are checked too, accepting
+    /// the additional syntax supported by [fancy-regex](https://crates.io/crates/fancy-regex).
     ///
     /// ### Why is this bad?
     /// This will lead to a runtime panic.
@@ -52,7 +61,152 @@ declare_clippy_lint! {
     "trivial regular expressions"
 }
 
-declare_lint_pass!(Regex => [INVALID_REGEX, TRIVIAL_REGEX]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for [regex](https://crates.io/crates/regex) creation with a
+    /// constant pattern inside of a loop, or inside of a closure passed to an
+    /// iterator method.
+    ///
+    /// ### Why is this bad?
+    /// Compiling a regex is expensive. Here the same regex is compiled again
+    /// for every iteration, while it could be compiled only once.
+    ///
+    /// ### Example
+    /// ```ignore
+    /// for line in lines {
+    ///     let re = Regex::new(r"^\d+$").unwrap();
+    ///     // ..
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\d+$").unwrap());
+    ///
+    /// for line in lines {
+    ///     // use `RE`
+    /// }
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub REGEX_CREATION_IN_LOOPS,
+    perf,
+    "compiling a regex with a constant pattern in a loop"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for capture groups in regexes which are only used with methods
+    /// that never read capture groups, like `is_match` or `find`.
+    ///
+    /// ### Why is this bad?
+    /// The capture group is useless, and a non-capturing group (`(?:...)`)
+    /// states the intent more clearly.
+    ///
+    /// ### Example
+    /// ```ignore
+    /// let re = Regex::new(r"^(foo|bar)\d+$").unwrap();
+    /// if re.is_match(input) {
+    ///     // ..
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// let re = Regex::new(r"^(?:foo|bar)\d+$").unwrap();
+    /// if re.is_match(input) {
+    ///     // ..
+    /// }
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub REGEX_UNUSED_CAPTURE_GROUPS,
+    pedantic,
+    "capture groups in regexes which are only used for matching"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for character classes in regexes with overlapping items, and for
+    /// character classes which spell out the ASCII word class.
+    ///
+    /// ### Why is this bad?
+    /// Overlapping items are redundant at best, and often a typo (e.g. `A-z`
+    /// instead of `A-Z`, which also matches ``[\]^_` ``). Spelled out word
+    /// classes are harder to read than the named class.
+    ///
+    /// ### Example
+    /// ```ignore
+    /// Regex::new("[a-zA-z]+");
+    /// Regex::new("[a-zA-Z0-9_]+");
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// Regex::new("[a-zA-Z]+");
+    /// Regex::new("[[:word:]]+");
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub REGEX_REDUNDANT_CLASS_RANGES,
+    pedantic,
+    "overlapping or spelled out ranges in regex character classes"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for unescaped `.` in regexes which look like they match a host
+    /// name or an IPv4 address.
+    ///
+    /// ### Why is this bad?
+    /// `.` matches any character, so the regex also matches strings like
+    /// `www-example-com`.
+    ///
+    /// ### Known problems
+    /// This is a heuristic, it only looks at dots after `www`, before common
+    /// top-level domains, and between the parts of a dotted decimal address.
+    ///
+    /// ### Example
+    /// ```ignore
+    /// Regex::new(r"^(www.)?example.com$");
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// Regex::new(r"^(www\.)?example\.com$");
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub REGEX_UNESCAPED_DOT,
+    suspicious,
+    "unescaped `.` in regexes which look like host names or IP addresses"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for alternations in regexes which have an empty branch, like
+    /// `foo|` or `(a||b)`.
+    ///
+    /// ### Why is this bad?
+    /// The empty branch matches everywhere, which is usually the result of a
+    /// stray `|`. If matching nothing is intended, making the alternation
+    /// optional with `?` is clearer.
+    ///
+    /// ### Example
+    /// ```ignore
+    /// Regex::new("^(foo|bar|)$");
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// Regex::new("^(foo|bar)?$");
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub REGEX_EMPTY_ALTERNATION,
+    suspicious,
+    "alternations with an empty branch in regexes"
+}
+
+declare_lint_pass!(Regex => [
+    INVALID_REGEX,
+    TRIVIAL_REGEX,
+    REGEX_CREATION_IN_LOOPS,
+    REGEX_UNUSED_CAPTURE_GROUPS,
+    REGEX_REDUNDANT_CLASS_RANGES,
+    REGEX_UNESCAPED_DOT,
+    REGEX_EMPTY_ALTERNATION,
+]);
 
 impl<'tcx> LateLintPass<'tcx> for Regex {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
@@ -61,23 +215,358 @@ impl<'tcx> LateLintPass<'tcx> for Regex {
             if let ExprKind::Path(ref qpath) = fun.kind;
             if args.len() == 1;
             if let Some(def_id) = cx.qpath_res(qpath, fun.hir_id).opt_def_id();
+            if let Some((kind, is_set)) = regex_constructor(cx, def_id);
             then {
-                if match_def_path(cx, def_id, &paths::REGEX_NEW) ||
-                   match_def_path(cx, def_id, &paths::REGEX_BUILDER_NEW) {
-                    check_regex(cx, &args[0], true);
-                } else if match_def_path(cx, def_id, &paths::REGEX_BYTES_NEW) ||
-                   match_def_path(cx, def_id, &paths::REGEX_BYTES_BUILDER_NEW) {
-                    check_regex(cx, &args[0], false);
-                } else if match_def_path(cx, def_id, &paths::REGEX_SET_NEW) {
-                    check_set(cx, &args[0], true);
-                } else if match_def_path(cx, def_id, &paths::REGEX_BYTES_SET_NEW) {
-                    check_set(cx, &args[0], false);
+                if is_set {
+                    check_set(cx, &args[0], kind);
+                } else {
+                    // Builders can't be followed through `build`, only check the plain constructors.
+                    let unused_captures = !match_def_path(cx, def_id, &paths::REGEX_BUILDER_NEW)
+                        && !match_def_path(cx, def_id, &paths::REGEX_BYTES_BUILDER_NEW)
+                        && is_only_used_for_matching(cx, expr);
+                    check_regex(cx, &args[0], kind, unused_captures);
+                }
+
+                if is_constant_pattern(cx, &args[0], is_set)
+                    && let Some(place) = repeated_evaluation(cx, expr)
+                {
+                    span_lint_and_help(
+                        cx,
+                        REGEX_CREATION_IN_LOOPS,
+                        expr.span,
+                        &format!("compiling a regex in {}", place),
+                        None,
+                        "consider compiling the regex only once, e.g. in a `static` using `once_cell::sync::Lazy`",
+                    );
                 }
             }
         }
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RegexKind {
+    Unicode,
+    Bytes,
+    Fancy,
+}
+
+/// Returns the kind of regex created by the function, and whether it creates a set of regexes.
+fn regex_constructor(cx: &LateContext<'_>, def_id: DefId) -> Option<(RegexKind, bool)> {
+    if match_def_path(cx, def_id, &paths::REGEX_NEW) || match_def_path(cx, def_id, &paths::REGEX_BUILDER_NEW) {
+        Some((RegexKind::Unicode, false))
+    } else if match_def_path(cx, def_id, &paths::REGEX_BYTES_NEW)
+        || match_def_path(cx, def_id, &paths::REGEX_BYTES_BUILDER_NEW)
+    {
+        Some((RegexKind::Bytes, false))
+    } else if match_def_path(cx, def_id, &paths::REGEX_SET_NEW) {
+        Some((RegexKind::Unicode, true))
+    } else if match_def_path(cx, def_id, &paths::REGEX_BYTES_SET_NEW) {
+        Some((RegexKind::Bytes, true))
+    } else if match_def_path(cx, def_id, &paths::FANCY_REGEX_NEW) {
+        Some((RegexKind::Fancy, false))
+    } else {
+        None
+    }
+}
+
+fn is_constant_pattern<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'_>, is_set: bool) -> bool {
+    if is_set {
+        if let ExprKind::AddrOf(BorrowKind::Ref, _, arg) = arg.kind
+            && let ExprKind::Array(exprs) = arg.kind
+        {
+            exprs.iter().all(|e| const_str(cx, e).is_some())
+        } else {
+            false
+        }
+    } else {
+        const_str(cx, arg).is_some()
+    }
+}
+
+/// Checks if the expression is evaluated repeatedly, i.e. in a loop or in a closure passed to an
+/// iterator method. Returns a description of where it's evaluated.
+fn repeated_evaluation(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<&'static str> {
+    let map = cx.tcx.hir();
+    for (_, node) in map.parent_iter(expr.hir_id) {
+        match node {
+            Node::Expr(e) => match e.kind {
+                ExprKind::Loop(..) => return Some("a loop"),
+                ExprKind::Closure(_) => {
+                    return match map.find(map.get_parent_node(e.hir_id)) {
+                        Some(Node::Expr(parent))
+                            if matches!(parent.kind, ExprKind::MethodCall(..))
+                                && is_trait_method(cx, parent, sym::Iterator) =>
+                        {
+                            Some("a closure called for each item of an iterator")
+                        },
+                        _ => None,
+                    };
+                },
+                _ => {},
+            },
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) | Node::AnonConst(_) => return None,
+            _ => {},
+        }
+    }
+    None
+}
+
+/// Methods of a compiled regex which don't read capture groups.
+const NON_CAPTURING_METHODS: &[&str] = &[
+    "as_str",
+    "find",
+    "find_at",
+    "find_iter",
+    "is_match",
+    "is_match_at",
+    "shortest_match",
+    "shortest_match_at",
+    "split",
+    "splitn",
+];
+
+/// Checks if the regex created by `new` is unwrapped and then only used with
+/// `NON_CAPTURING_METHODS`, either directly or through a local binding.
+fn is_only_used_for_matching<'tcx>(cx: &LateContext<'tcx>, new: &'tcx Expr<'_>) -> bool {
+    let is_non_capturing_call = |call: &Expr<'_>, recv_id| {
+        matches!(
+            call.kind,
+            ExprKind::MethodCall(path, [recv, ..], _)
+                if recv.hir_id == recv_id && NON_CAPTURING_METHODS.contains(&path.ident.as_str())
+        )
+    };
+
+    let Some(unwrap) = get_parent_expr(cx, new) else {
+        return false;
+    };
+    if !matches!(
+        unwrap.kind,
+        ExprKind::MethodCall(path, [recv, ..], _)
+            if recv.hir_id == new.hir_id && matches!(path.ident.as_str(), "unwrap" | "expect")
+    ) {
+        return false;
+    }
+
+    match get_parent_node(cx.tcx, unwrap.hir_id) {
+        Some(Node::Expr(parent)) => is_non_capturing_call(parent, unwrap.hir_id),
+        Some(Node::Local(local)) => {
+            if let PatKind::Binding(_, id, _, None) = local.pat.kind {
+                let mut used = false;
+                let res = for_each_local_use_after_expr(cx, id, unwrap.hir_id, |e| {
+                    used = true;
+                    match get_parent_expr(cx, e) {
+                        Some(parent) if is_non_capturing_call(parent, e.hir_id) => ControlFlow::Continue(()),
+                        _ => ControlFlow::Break(()),
+                    }
+                });
+                res.is_continue() && used
+            } else {
+                false
+            }
+        },
+        _ => false,
+    }
+}
+
+/// Runs the lints which need the syntax tree of a valid regex.
+fn check_ast(cx: &LateContext<'_>, expr: &Expr<'_>, pattern: &str, offset: Option<u8>, unused_captures: bool) {
+    if let Ok(ast) = ast::parse::Parser::new().parse(pattern) {
+        let _: Result<(), ()> = ast::visit(
+            &ast,
+            AstVisitor {
+                cx,
+                expr_span: expr.span,
+                pattern,
+                offset,
+                unused_captures,
+            },
+        );
+    }
+}
+
+struct AstVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    expr_span: Span,
+    pattern: &'a str,
+    /// The offset of the pattern in the source code, if the positions in the pattern map to the
+    /// source code.
+    offset: Option<u8>,
+    unused_captures: bool,
+}
+
+impl AstVisitor<'_, '_> {
+    fn span(&self, span: &ast::Span) -> Span {
+        self.offset
+            .map_or(self.expr_span, |offset| str_span(self.expr_span, *span, offset))
+    }
+
+    fn snippet(&self, span: &ast::Span) -> &str {
+        &self.pattern[span.start.offset..span.end.offset]
+    }
+
+    fn check_class(&self, class: &ast::ClassBracketed) {
+        let items = match &class.kind {
+            ast::ClassSet::Item(ClassSetItem::Union(union)) => &union.items[..],
+            ast::ClassSet::Item(item) => core::slice::from_ref(item),
+            ast::ClassSet::BinaryOp(_) => return,
+        };
+
+        let mut ranges = Vec::new();
+        for item in items {
+            let (start, end, span) = match item {
+                ClassSetItem::Literal(lit) => (lit.c, lit.c, &lit.span),
+                ClassSetItem::Range(range) => (range.start.c, range.end.c, &range.span),
+                _ => continue,
+            };
+            if let Some((_, _, prev)) = ranges
+                .iter()
+                .find(|&&(prev_start, prev_end, _)| start <= prev_end && prev_start <= end)
+            {
+                span_lint(
+                    self.cx,
+                    REGEX_REDUNDANT_CLASS_RANGES,
+                    self.span(span),
+                    &format!(
+                        "`{}` overlaps with `{}` in this character class",
+                        self.snippet(span),
+                        self.snippet(prev)
+                    ),
+                );
+            }
+            ranges.push((start, end, *span));
+        }
+
+        if !class.negated && ranges.len() == items.len() {
+            let mut ranges: Vec<_> = ranges.into_iter().map(|(start, end, _)| (start, end)).collect();
+            ranges.sort_unstable();
+            ranges.dedup();
+            if ranges == [('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')] {
+                span_lint_and_help(
+                    self.cx,
+                    REGEX_REDUNDANT_CLASS_RANGES,
+                    self.span(&class.span),
+                    "this character class matches the same characters as `[[:word:]]`",
+                    None,
+                    "consider using `[[:word:]]`, or `\\w` if Unicode word characters should match too",
+                );
+            }
+        }
+    }
+
+    fn check_dots(&self, asts: &[Ast]) {
+        for (i, ast) in asts.iter().enumerate() {
+            if let Ast::Dot(span) = ast
+                && (is_after_www(&asts[..i]) || is_before_tld(&asts[i + 1..]) || is_in_ip_address(asts, i))
+            {
+                span_lint_and_help(
+                    self.cx,
+                    REGEX_UNESCAPED_DOT,
+                    self.span(span),
+                    "`.` matches any character, not only a dot",
+                    None,
+                    "if a dot is intended, escape it: `\\.`",
+                );
+            }
+        }
+    }
+}
+
+impl ast::Visitor for AstVisitor<'_, '_> {
+    type Output = ();
+    type Err = ();
+
+    fn finish(self) -> Result<(), ()> {
+        Ok(())
+    }
+
+    fn visit_pre(&mut self, ast: &Ast) -> Result<(), ()> {
+        match ast {
+            Ast::Group(group) if self.unused_captures && group.capture_index().is_some() => {
+                span_lint_and_help(
+                    self.cx,
+                    REGEX_UNUSED_CAPTURE_GROUPS,
+                    self.span(&group.span),
+                    "this capture group is never read",
+                    None,
+                    "the regex is only used in ways which don't read capture groups, \
+                    consider using a non-capturing group: `(?:...)`",
+                );
+            },
+            Ast::Class(ast::Class::Bracketed(class)) => self.check_class(class),
+            Ast::Concat(concat) => self.check_dots(&concat.asts),
+            Ast::Alternation(alternation)
+                if alternation.asts.iter().any(|ast| matches!(ast, Ast::Empty(_)))
+                    && !alternation.asts.iter().all(|ast| matches!(ast, Ast::Empty(_))) =>
+            {
+                span_lint_and_help(
+                    self.cx,
+                    REGEX_EMPTY_ALTERNATION,
+                    self.span(&alternation.span),
+                    "this alternation has an empty branch",
+                    None,
+                    "if matching nothing is intended, consider making the alternation optional with `?`",
+                );
+            },
+            _ => {},
+        }
+        Ok(())
+    }
+
+    fn visit_class_set_item_pre(&mut self, item: &ClassSetItem) -> Result<(), ()> {
+        if let ClassSetItem::Bracketed(class) = item {
+            self.check_class(class);
+        }
+        Ok(())
+    }
+}
+
+fn literal_char(ast: &Ast) -> Option<char> {
+    match ast {
+        Ast::Literal(lit) => Some(lit.c),
+        _ => None,
+    }
+}
+
+fn is_after_www(before: &[Ast]) -> bool {
+    let chars: Option<String> = before.iter().rev().take(3).rev().map(literal_char).collect();
+    chars.map_or(false, |chars| chars.eq_ignore_ascii_case("www"))
+}
+
+fn is_before_tld(after: &[Ast]) -> bool {
+    const TLDS: &[&str] = &["com", "de", "edu", "gov", "io", "net", "org", "rs", "uk"];
+
+    let tld: String = after
+        .iter()
+        .map_while(literal_char)
+        .take_while(char::is_ascii_alphanumeric)
+        .collect();
+    TLDS.iter().any(|t| tld.eq_ignore_ascii_case(t))
+}
+
+/// Checks if the dot at `asts[i]` is part of a dotted decimal address, i.e. it's one of at least
+/// three dots separating digits.
+fn is_in_ip_address(asts: &[Ast], i: usize) -> bool {
+    fn is_digits(ast: &Ast) -> bool {
+        match ast {
+            Ast::Literal(lit) => lit.c.is_ascii_digit(),
+            Ast::Class(ast::Class::Perl(class)) => class.kind == ast::ClassPerlKind::Digit && !class.negated,
+            Ast::Repetition(rep) => is_digits(&rep.ast),
+            _ => false,
+        }
+    }
+
+    let is_separator = |j: usize| {
+        matches!(asts[j], Ast::Dot(_))
+            && j > 0
+            && j + 1 < asts.len()
+            && is_digits(&asts[j - 1])
+            && is_digits(&asts[j + 1])
+    };
+
+    is_separator(i) && (0..asts.len()).filter(|&j| is_separator(j)).count() >= 3
+}
+
 #[must_use]
 fn str_span(base: Span, c: regex_syntax::ast::Span, offset: u8) -> Span {
     let offset = u32::from(offset);
@@ -88,10 +577,29 @@ fn str_span(base: Span, c: regex_syntax::ast::Span, offset: u8) -> Span {
 }
 
 fn const_str<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> Option<String> {
-    constant(cx, cx.typeck_results(), e).and_then(|(c, _)| match c {
-        Constant::Str(s) => Some(s),
-        _ => None,
-    })
+    constant(cx, cx.typeck_results(), e)
+        .and_then(|(c, _)| match c {
+            Constant::Str(s) => Some(s),
+            _ => None,
+        })
+        .or_else(|| static_str(cx, e))
+}
+
+/// Gets the value of an immutable `static` string of the current crate.
+fn static_str(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<String> {
+    if let ExprKind::Path(ref qpath) = e.kind
+        && let Res::Def(DefKind::Static(_), def_id) = cx.qpath_res(qpath, e.hir_id)
+        && let Some(Node::Item(item)) = cx.tcx.hir().get_if_local(def_id)
+        && let ItemKind::Static(_, Mutability::Not, body_id) = item.kind
+    {
+        let body = cx.tcx.hir().body(body_id);
+        match constant(cx, cx.tcx.typeck_body(body_id), &body.value) {
+            Some((Constant::Str(s), _)) => Some(s),
+            _ => None,
+        }
+    } else {
+        None
+    }
 }
 
 fn is_trivial_regex(s: &regex_syntax::hir::Hir) -> Option<&'static str> {
@@ -128,34 +636,45 @@ fn is_trivial_regex(s: &regex_syntax::hir::Hir) -> Option<&'static str> {
     }
 }
 
-fn check_set<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, utf8: bool) {
+fn check_set<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, kind: RegexKind) {
     if_chain! {
         if let ExprKind::AddrOf(BorrowKind::Ref, _, expr) = expr.kind;
         if let ExprKind::Array(exprs) = expr.kind;
         then {
             for expr in exprs {
-                check_regex(cx, expr, utf8);
+                check_regex(cx, expr, kind, false);
             }
         }
     }
 }
 
-fn check_regex<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, utf8: bool) {
+fn check_regex<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, kind: RegexKind, unused_captures: bool) {
     let mut parser = regex_syntax::ParserBuilder::new()
         .unicode(true)
-        .allow_invalid_utf8(!utf8)
+        .allow_invalid_utf8(kind == RegexKind::Bytes)
         .build();
 
-    if let ExprKind::Lit(ref lit) = expr.kind {
+    // The span of a literal created by a macro like `concat!` doesn't match its contents.
+    if let ExprKind::Lit(ref lit) = expr.kind
+        && !expr.span.from_expansion()
+    {
         if let LitKind::Str(ref r, style) = lit.node {
             let r = r.as_str();
             let offset = if let StrStyle::Raw(n) = style { 2 + n } else { 1 };
             match parser.parse(r) {
-                Ok(r) => {
-                    if let Some(repl) = is_trivial_regex(&r) {
+                Ok(hir) => {
+                    if let Some(repl) = is_trivial_regex(&hir) {
                         span_lint_and_help(cx, TRIVIAL_REGEX, expr.span, "trivial regex", None, repl);
                     }
+                    // Escapes in non-raw strings shift the positions in the source code, so use the
+                    // span of the whole literal for them.
+                    let has_escapes = !matches!(style, StrStyle::Raw(_))
+                        && snippet_opt(cx, expr.span).map_or(true, |snippet| snippet.contains('\\'));
+                    let offset = (!has_escapes).then_some(offset);
+                    check_ast(cx, expr, r, offset, unused_captures);
                 },
+                // Syntax only supported by `fancy_regex`, like look-around or backreferences
+                Err(_) if kind == RegexKind::Fancy && fancy_regex::Regex::new(r).is_ok() => {},
                 Err(regex_syntax::Error::Parse(e)) => {
                     span_lint(
                         cx,
@@ -179,11 +698,13 @@ fn check_regex<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, utf8: bool) {
         }
     } else if let Some(r) = const_str(cx, expr) {
         match parser.parse(&r) {
-            Ok(r) => {
-                if let Some(repl) = is_trivial_regex(&r) {
+            Ok(hir) => {
+                if let Some(repl) = is_trivial_regex(&hir) {
                     span_lint_and_help(cx, TRIVIAL_REGEX, expr.span, "trivial regex", None, repl);
                 }
+                check_ast(cx, expr, &r, None, unused_captures);
             },
+            Err(_) if kind == RegexKind::Fancy && fancy_regex::Regex::new(&r).is_ok() => {},
             Err(regex_syntax::Error::Parse(e)) => {
                 span_lint(
                     cx,
@@ -0,0 +1,209 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::has_sig_drop_attr;
+use clippy_utils::visitors::{for_each_expr, is_local_used, Descend, Visitable};
+use clippy_utils::{get_parent_expr, higher, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_hir::{Block, Expr, ExprKind, HirId, MatchSource, PatKind, Stmt, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::subst::GenericArgKind;
+use rustc_middle::ty::Ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for values with a significant `Drop` (i.e. types with the
+    /// `clippy::has_significant_drop` attribute, such as `MutexGuard`) which live
+    /// longer than they are needed:
+    /// * temporaries created in a `while let` scrutinee, which live until the end
+    ///   of every iteration of the loop body;
+    /// * guards bound with `let` which are not used anymore, but are only dropped
+    ///   at the end of the enclosing block, possibly across an `.await`.
+    ///
+    /// ### Why is this bad?
+    /// The side-effect of the `Drop` impl, like unlocking a mutex, is delayed
+    /// for no reason. This can lead to contention or deadlocks.
+    ///
+    /// ### Known problems
+    /// Temporaries in `match` and `for` scrutinees are covered by
+    /// `significant_drop_in_scrutinee` instead.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// # fn do_heavy_work(_: u32) {}
+    /// fn process(mutex: &Mutex<u32>) {
+    ///     let lock = mutex.lock().unwrap();
+    ///     let value = *lock;
+    ///     do_heavy_work(value);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// # fn do_heavy_work(_: u32) {}
+    /// fn process(mutex: &Mutex<u32>) {
+    ///     let lock = mutex.lock().unwrap();
+    ///     let value = *lock;
+    ///     drop(lock);
+    ///     do_heavy_work(value);
+    /// }
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub SIGNIFICANT_DROP_TIGHTENING,
+    nursery,
+    "a value with a significant `Drop` which lives longer than needed"
+}
+
+declare_lint_pass!(SignificantDropTightening => [SIGNIFICANT_DROP_TIGHTENING]);
+
+impl<'tcx> LateLintPass<'tcx> for SignificantDropTightening {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some(higher::WhileLet { let_expr, .. }) = higher::WhileLet::hir(expr)
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some(temporary) = find_borrowed_sig_drop_temporary(cx, let_expr)
+        {
+            span_lint_and_then(
+                cx,
+                SIGNIFICANT_DROP_TIGHTENING,
+                temporary.span,
+                "temporary with significant `Drop` in `while let` scrutinee will live until the end of the loop body",
+                |diag| {
+                    diag.help("consider moving the scrutinee into a `let` statement at the start of a `loop`");
+                    diag.note("this might lead to deadlocks or other unexpected behavior");
+                },
+            );
+        }
+    }
+
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if block.span.from_expansion() {
+            return;
+        }
+        for (idx, stmt) in block.stmts.iter().enumerate() {
+            check_let_guard(cx, block, idx, stmt);
+        }
+    }
+}
+
+fn check_let_guard<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>, idx: usize, stmt: &'tcx Stmt<'tcx>) {
+    let StmtKind::Local(local) = stmt.kind else { return };
+    let Some(init) = local.init else { return };
+    let PatKind::Binding(_, local_id, ident, None) = local.pat.kind else { return };
+    if stmt.span.from_expansion()
+        || ident.as_str().starts_with('_')
+        || !matches!(init.kind, ExprKind::Call(..) | ExprKind::MethodCall(..))
+        || !has_sig_drop_attr(cx, cx.typeck_results().expr_ty(init))
+    {
+        return;
+    }
+
+    let rest = &block.stmts[idx + 1..];
+    // Locals borrowing from the guard, like `let first = guard.first();`, keep it alive too.
+    let mut locals = vec![local_id];
+    for stmt in rest {
+        if let StmtKind::Local(local) = stmt.kind
+            && let Some(init) = local.init
+            && is_any_local_used(cx, init, &locals)
+        {
+            local.pat.each_binding_or_first(&mut |_, id, _, _| {
+                if has_lifetime(cx.typeck_results().node_type(id)) {
+                    locals.push(id);
+                }
+            });
+        }
+    }
+    // A guard which is used until the end of the block, or not used at all (e.g. `let _guard`
+    // protecting a critical section) is fine.
+    if block.expr.map_or(false, |e| is_any_local_used(cx, e, &locals)) {
+        return;
+    }
+    let Some(last_use) = rest.iter().rposition(|s| is_any_local_used(cx, s, &locals)) else { return };
+    let after = &rest[last_use + 1..];
+    if (after.is_empty() && block.expr.is_none()) || is_moved_in(cx, &rest[last_use], local_id) {
+        return;
+    }
+
+    let await_stmt = after.iter().find(|s| contains_await(s));
+    span_lint_and_then(
+        cx,
+        SIGNIFICANT_DROP_TIGHTENING,
+        local.pat.span,
+        "temporary with significant `Drop` can be early dropped",
+        |diag| {
+            diag.help(&format!("drop it right after its last use with `drop({})`", ident));
+            diag.note("this might lead to deadlocks or other unexpected behavior");
+            let note = if is_local_used(cx, &rest[last_use], local_id) {
+                format!("`{}` is last used here", ident)
+            } else {
+                format!("`{}` is last used here, through a borrow", ident)
+            };
+            diag.span_note(rest[last_use].span, &note);
+            if let Some(await_stmt) = await_stmt {
+                diag.span_note(await_stmt.span, "but is still held across this `.await`");
+            }
+        },
+    );
+}
+
+/// Checks if the local is moved somewhere in the given statement, in which case it can't be dropped
+/// afterwards.
+fn is_moved_in<'tcx>(cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'tcx>, local_id: HirId) -> bool {
+    for_each_expr(stmt, |e| {
+        if path_to_local_id(e, local_id) && !is_borrowed(cx, e) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+fn is_any_local_used<'tcx>(cx: &LateContext<'tcx>, visitable: impl Visitable<'tcx> + Copy, ids: &[HirId]) -> bool {
+    ids.iter().any(|&id| is_local_used(cx, visitable, id))
+}
+
+/// Checks if the type may borrow something, i.e. has a lifetime.
+fn has_lifetime(ty: Ty<'_>) -> bool {
+    ty.walk().any(|arg| matches!(arg.unpack(), GenericArgKind::Lifetime(_)))
+}
+
+fn contains_await<'tcx>(stmt: &'tcx Stmt<'tcx>) -> bool {
+    for_each_expr(stmt, |e| match e.kind {
+        ExprKind::Match(_, _, MatchSource::AwaitDesugar) => ControlFlow::Break(()),
+        ExprKind::Closure { .. } => ControlFlow::Continue(Descend::No),
+        _ => ControlFlow::Continue(Descend::Yes),
+    })
+    .is_some()
+}
+
+/// Checks if the value of the expression is only used through a reference, i.e. it is still
+/// dropped at the end of its scope.
+fn is_borrowed<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> bool {
+    !cx.typeck_results().expr_adjustments(e).is_empty()
+        || get_parent_expr(cx, e).map_or(false, |parent| match parent.kind {
+            ExprKind::AddrOf(..) | ExprKind::Unary(UnOp::Deref, _) | ExprKind::Field(..) => true,
+            ExprKind::Index(base, _) => base.hir_id == e.hir_id,
+            _ => false,
+        })
+}
+
+/// Finds the outermost temporary with a significant `Drop` which is only borrowed in the
+/// scrutinee, and thus lives until the end of the `if let` created by the `while let` desugaring.
+fn find_borrowed_sig_drop_temporary<'tcx>(
+    cx: &LateContext<'tcx>,
+    scrutinee: &'tcx Expr<'tcx>,
+) -> Option<&'tcx Expr<'tcx>> {
+    for_each_expr(scrutinee, |e| match e.kind {
+        // Temporaries of blocks and closures are dropped before the loop body.
+        ExprKind::Block(..) | ExprKind::Closure { .. } => ControlFlow::Continue(Descend::No),
+        ExprKind::Call(..) | ExprKind::MethodCall(..)
+            if e.hir_id != scrutinee.hir_id
+                && is_borrowed(cx, e)
+                && has_sig_drop_attr(cx, cx.typeck_results().expr_ty(e)) =>
+        {
+            ControlFlow::Break(e)
+        },
+        _ => ControlFlow::Continue(Descend::Yes),
+    })
+}
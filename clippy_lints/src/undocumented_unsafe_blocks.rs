@@ -1,14 +1,27 @@
+use crate::utils::report;
 use clippy_utils::diagnostics::span_lint_and_help;
-use clippy_utils::source::walk_span_to_context;
+use clippy_utils::source::{snippet, walk_span_to_context};
+use clippy_utils::visitors::{contains_unsafe_block, for_each_unsafe_op, UnsafeOp};
 use clippy_utils::{get_parent_node, is_lint_allowed};
+use core::ops::ControlFlow;
+use rustc_ast::Attribute;
+use rustc_data_structures::fx::FxHashSet;
 use rustc_data_structures::sync::Lrc;
 use rustc_hir as hir;
-use rustc_hir::{Block, BlockCheckMode, ItemKind, Node, UnsafeSource};
+use rustc_hir::def_id::{DefId, LOCAL_CRATE};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{
+    Block, BlockCheckMode, Body, Expr, ExprKind, FnDecl, HirId, ItemKind, Node, QPath, Stmt, StmtKind, TraitFn,
+    TraitItem, TraitItemKind, UnOp, UnsafeSource, Unsafety,
+};
 use rustc_lexer::{tokenize, TokenKind};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
-use rustc_span::{BytePos, Pos, Span, SyntaxContext};
+use rustc_session::lint::builtin::UNSAFE_OP_IN_UNSAFE_FN;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, BytePos, Pos, Span, SyntaxContext};
+use serde::Serialize;
+use std::path::PathBuf;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -16,6 +29,12 @@ declare_clippy_lint! {
     /// explaining why the unsafe operations performed inside
     /// the block are safe.
     ///
+    /// Unsafe operations performed directly in the body of an `unsafe fn`
+    /// (i.e. while `unsafe_op_in_unsafe_fn` is allowed) need a safety comment
+    /// on the statement performing them, and items marked with one of the
+    /// unsafe attributes `#[no_mangle]`, `#[export_name]` or `#[link_section]`
+    /// need one preceding the attribute.
+    ///
     /// Note the comment must appear on the line(s) preceding the unsafe block
     /// with nothing appearing in between. The following is ok:
     /// ```ignore
@@ -34,6 +53,11 @@ declare_clippy_lint! {
     /// );
     /// ```
     ///
+    /// With the `require-safety-comment-per-operation` configuration, the
+    /// safety comment of a block also has to mention each unsafe operation
+    /// performed in it, i.e. the dereferenced pointer, the called function or
+    /// the accessed static.
+    ///
     /// ### Why is this bad?
     /// Undocumented unsafe blocks and impls can make it difficult to
     /// read and maintain code, as well as uncover unsoundness
@@ -60,61 +84,483 @@ declare_clippy_lint! {
     "creating an unsafe block without explaining why it is safe"
 }
 
-declare_lint_pass!(UndocumentedUnsafeBlocks => [UNDOCUMENTED_UNSAFE_BLOCKS]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `// SAFETY: ` comments on statements and `unsafe` blocks
+    /// which don't perform any unsafe operation.
+    ///
+    /// ### Why is this bad?
+    /// A safety comment justifying nothing is misleading. It's usually left
+    /// over after the unsafe code it documented was removed or moved.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let a = &mut 42;
+    ///
+    /// // SAFETY: references are guaranteed to be non-null.
+    /// let ptr = std::ptr::NonNull::from(a);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let a = &mut 42;
+    ///
+    /// let ptr = std::ptr::NonNull::from(a);
+    /// ```
+    #[clippy::version = "1.64.0"]
+    pub UNNECESSARY_SAFETY_COMMENT,
+    restriction,
+    "a safety comment on code without unsafe operations"
+}
+
+pub struct UndocumentedUnsafeBlocks {
+    require_safety_comment_per_operation: bool,
+}
+
+impl UndocumentedUnsafeBlocks {
+    pub fn new(require_safety_comment_per_operation: bool) -> Self {
+        Self {
+            require_safety_comment_per_operation,
+        }
+    }
+
+    /// Checks the safety comment of an unsafe block against the unsafe operations performed in it.
+    fn check_block_operations<'tcx>(&self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        let check_unnecessary = !is_lint_allowed(cx, UNNECESSARY_SAFETY_COMMENT, block.hir_id);
+        let check_operations =
+            self.require_safety_comment_per_operation && !is_lint_allowed(cx, UNDOCUMENTED_UNSAFE_BLOCKS, block.hir_id);
+        if !check_unnecessary && !check_operations {
+            return;
+        }
 
-impl LateLintPass<'_> for UndocumentedUnsafeBlocks {
-    fn check_block(&mut self, cx: &LateContext<'_>, block: &'_ Block<'_>) {
+        let mut operations = Vec::new();
+        for_each_block_unsafe_op(cx, block, |_, expr| {
+            operations.push(expr);
+            ControlFlow::<()>::Continue(())
+        });
+        if operations.is_empty() {
+            if check_unnecessary
+                && !block.stmts.iter().any(|stmt| contains_unsafe_block(cx, stmt))
+                && !block.expr.map_or(false, |e| contains_unsafe_block(cx, e))
+            {
+                span_lint_and_help(
+                    cx,
+                    UNNECESSARY_SAFETY_COMMENT,
+                    first_line_span(cx, block.span),
+                    "unsafe block without unsafe operations has a safety comment",
+                    None,
+                    "consider removing the safety comment and the `unsafe` block",
+                );
+            }
+        } else if check_operations && let Some(comment) = span_in_body_safety_comment(cx, block.span) {
+            for expr in operations {
+                let name = unsafe_operation_name(cx, expr);
+                if !comment_mentions(&comment, &name) {
+                    span_lint_and_help(
+                        cx,
+                        UNDOCUMENTED_UNSAFE_BLOCKS,
+                        expr.span,
+                        &format!("unsafe operation `{}` is not justified in the safety comment", name),
+                        None,
+                        &format!("consider explaining why using `{}` is safe in the safety comment", name),
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl_lint_pass!(UndocumentedUnsafeBlocks => [UNDOCUMENTED_UNSAFE_BLOCKS, UNNECESSARY_SAFETY_COMMENT]);
+
+impl<'tcx> LateLintPass<'tcx> for UndocumentedUnsafeBlocks {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
         if block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
             && !in_external_macro(cx.tcx.sess, block.span)
-            && !is_lint_allowed(cx, UNDOCUMENTED_UNSAFE_BLOCKS, block.hir_id)
             && !is_unsafe_from_proc_macro(cx, block.span)
-            && !block_has_safety_comment(cx, block)
         {
-            let source_map = cx.tcx.sess.source_map();
-            let span = if source_map.is_multiline(block.span) {
-                source_map.span_until_char(block.span, '\n')
-            } else {
-                block.span
-            };
+            if block_has_safety_comment(cx, block) {
+                self.check_block_operations(cx, block);
+            } else if !is_lint_allowed(cx, UNDOCUMENTED_UNSAFE_BLOCKS, block.hir_id) {
+                span_lint_and_help(
+                    cx,
+                    UNDOCUMENTED_UNSAFE_BLOCKS,
+                    first_line_span(cx, block.span),
+                    "unsafe block missing a safety comment",
+                    None,
+                    "consider adding a safety comment on the preceding line",
+                );
+            }
+        }
+    }
 
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        _: Span,
+        _: HirId,
+    ) {
+        if !kind
+            .header()
+            .map_or(false, |header| header.unsafety == Unsafety::Unsafe)
+        {
+            return;
+        }
+        // Unsafe operations performed directly in the body of an unsafe function.
+        let mut linted_stmts = FxHashSet::default();
+        for_each_unsafe_op(cx, &body.value, |_, expr| {
+            if !in_external_macro(cx.tcx.sess, expr.span)
+                && !is_lint_allowed(cx, UNDOCUMENTED_UNSAFE_BLOCKS, expr.hir_id)
+                && is_lint_allowed(cx, UNSAFE_OP_IN_UNSAFE_FN, expr.hir_id)
+            {
+                let stmt_span = enclosing_statement_span(cx, expr.hir_id);
+                if !span_from_macro_expansion_has_safety_comment(cx, stmt_span)
+                    && !span_in_body_has_safety_comment(cx, stmt_span)
+                    && linted_stmts.insert(stmt_span)
+                {
+                    span_lint_and_help(
+                        cx,
+                        UNDOCUMENTED_UNSAFE_BLOCKS,
+                        expr.span,
+                        "unsafe operation in an unsafe function missing a safety comment",
+                        None,
+                        "consider adding a safety comment on the line preceding the statement",
+                    );
+                }
+            }
+            ControlFlow::<()>::Continue(())
+        });
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'tcx>) {
+        if stmt.span.ctxt() == SyntaxContext::root()
+            && !matches!(stmt.kind, StmtKind::Item(_))
+            && !is_lint_allowed(cx, UNNECESSARY_SAFETY_COMMENT, stmt.hir_id)
+            && span_in_body_safety_comment(cx, stmt.span).is_some()
+            && !is_stmt_unsafe(cx, stmt)
+        {
             span_lint_and_help(
                 cx,
-                UNDOCUMENTED_UNSAFE_BLOCKS,
-                span,
-                "unsafe block missing a safety comment",
+                UNNECESSARY_SAFETY_COMMENT,
+                first_line_span(cx, stmt.span),
+                "statement without unsafe operations has a safety comment",
                 None,
-                "consider adding a safety comment on the preceding line",
+                "consider removing the safety comment",
             );
         }
     }
 
-    fn check_item(&mut self, cx: &LateContext<'_>, item: &hir::Item<'_>) {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
         if let hir::ItemKind::Impl(imple) = item.kind
             && imple.unsafety == hir::Unsafety::Unsafe
             && !in_external_macro(cx.tcx.sess, item.span)
             && !is_lint_allowed(cx, UNDOCUMENTED_UNSAFE_BLOCKS, item.hir_id())
             && !is_unsafe_from_proc_macro(cx, item.span)
-            && !item_has_safety_comment(cx, item)
+            && !item_has_safety_comment(cx, item, item.span)
         {
-            let source_map = cx.tcx.sess.source_map();
-            let span = if source_map.is_multiline(item.span) {
-                source_map.span_until_char(item.span, '\n')
-            } else {
-                item.span
-            };
-
             span_lint_and_help(
                 cx,
                 UNDOCUMENTED_UNSAFE_BLOCKS,
-                span,
+                first_line_span(cx, item.span),
                 "unsafe impl missing a safety comment",
                 None,
                 "consider adding a safety comment on the preceding line",
             );
         }
+
+        if matches!(item.kind, ItemKind::Fn(..) | ItemKind::Static(..))
+            && let Some(attr) = cx.tcx.hir().attrs(item.hir_id()).iter().find(|attr| {
+                attr.has_name(sym::no_mangle) || attr.has_name(sym::export_name) || attr.has_name(sym::link_section)
+            })
+            && !in_external_macro(cx.tcx.sess, attr.span)
+            && !is_lint_allowed(cx, UNDOCUMENTED_UNSAFE_BLOCKS, item.hir_id())
+            && !item_has_safety_comment(cx, item, attr.span)
+        {
+            span_lint_and_help(
+                cx,
+                UNDOCUMENTED_UNSAFE_BLOCKS,
+                attr.span,
+                "unsafe attribute missing a safety comment",
+                None,
+                "consider adding a safety comment on the preceding line",
+            );
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct UnsafeOperation {
+    kind: &'static str,
+    line: usize,
+    column: usize,
+    code: String,
+}
+
+#[derive(Serialize)]
+struct UnsafeEntry {
+    /// One of `block`, `fn`, `impl` or `trait`.
+    kind: &'static str,
+    file: String,
+    line: usize,
+    /// The path of the item, or of the enclosing item for blocks.
+    item: String,
+    /// The `// SAFETY:` comment of blocks and impls, or the `# Safety` section of the
+    /// documentation of functions and traits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    safety_comment: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    operations: Vec<UnsafeOperation>,
+}
+
+#[derive(Serialize)]
+struct UnsafeInventory<'a> {
+    #[serde(rename = "crate")]
+    krate: &'a str,
+    #[serde(rename = "unsafe")]
+    entries: &'a [UnsafeEntry],
+}
+
+/// Collects every `unsafe` block, function, impl and trait of the crate, along with the unsafe
+/// operations performed in them, and writes them as JSON to `path` once the crate has been
+/// checked. This pass is only registered by `--report-unsafe`.
+pub struct UnsafeReport {
+    path: PathBuf,
+    entries: Vec<UnsafeEntry>,
+}
+
+impl UnsafeReport {
+    #[must_use]
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            entries: Vec::new(),
+        }
+    }
+
+    fn push(
+        &mut self,
+        cx: &LateContext<'_>,
+        kind: &'static str,
+        span: Span,
+        item: DefId,
+        safety_comment: Option<String>,
+        operations: Vec<UnsafeOperation>,
+    ) {
+        let loc = cx.sess().source_map().lookup_char_pos(span.lo());
+        self.entries.push(UnsafeEntry {
+            kind,
+            file: loc.file.name.prefer_remapped().to_string(),
+            line: loc.line,
+            item: cx.tcx.def_path_str(item),
+            // Comments are taken with their indentation from the source
+            safety_comment: safety_comment.map(|comment| comment.lines().map(str::trim).collect::<Vec<_>>().join("\n")),
+            operations,
+        });
+    }
+}
+
+impl_lint_pass!(UnsafeReport => []);
+
+impl<'tcx> LateLintPass<'tcx> for UnsafeReport {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+            && !in_external_macro(cx.tcx.sess, block.span)
+        {
+            let mut operations = Vec::new();
+            for_each_block_unsafe_op(cx, block, |op, expr| {
+                operations.push(unsafe_operation(cx, op, expr));
+                ControlFlow::Continue(())
+            });
+            let item = cx.tcx.hir().get_parent_item(block.hir_id);
+            let comment = span_in_body_safety_comment(cx, block.span);
+            self.push(cx, "block", block.span, item.to_def_id(), comment, operations);
+        }
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        hir_id: HirId,
+    ) {
+        if kind
+            .header()
+            .map_or(false, |header| header.unsafety == Unsafety::Unsafe)
+            && !in_external_macro(cx.tcx.sess, span)
+        {
+            let mut operations = Vec::new();
+            for_each_unsafe_op(cx, &body.value, |op, expr| {
+                operations.push(unsafe_operation(cx, op, expr));
+                ControlFlow::<()>::Continue(())
+            });
+            let def_id = cx.tcx.hir().local_def_id(hir_id);
+            let comment = safety_doc_section(cx, hir_id);
+            self.push(cx, "fn", span, def_id.to_def_id(), comment, operations);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'_>) {
+        // Functions with a body are handled by `check_fn`.
+        if let TraitItemKind::Fn(sig, TraitFn::Required(_)) = &item.kind
+            && sig.header.unsafety == Unsafety::Unsafe
+            && !in_external_macro(cx.tcx.sess, item.span)
+        {
+            let comment = safety_doc_section(cx, item.hir_id());
+            self.push(cx, "fn", item.span, item.def_id.to_def_id(), comment, Vec::new());
+        }
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
+        if in_external_macro(cx.tcx.sess, item.span) {
+            return;
+        }
+        match item.kind {
+            ItemKind::Impl(imple) if imple.unsafety == Unsafety::Unsafe => {
+                let comment = item_safety_comment(cx, item);
+                self.push(cx, "impl", item.span, item.def_id.to_def_id(), comment, Vec::new());
+            },
+            ItemKind::Trait(_, Unsafety::Unsafe, ..) => {
+                let comment = safety_doc_section(cx, item.hir_id());
+                self.push(cx, "trait", item.span, item.def_id.to_def_id(), comment, Vec::new());
+            },
+            _ => {},
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        let crate_name = cx.tcx.crate_name(LOCAL_CRATE);
+        let inventory = UnsafeInventory {
+            krate: crate_name.as_str(),
+            entries: &self.entries,
+        };
+        report::append(cx, &self.path, &inventory);
+    }
+}
+
+fn unsafe_operation(cx: &LateContext<'_>, op: UnsafeOp, expr: &Expr<'_>) -> UnsafeOperation {
+    let kind = match op {
+        UnsafeOp::RawPtrDeref => "raw_deref",
+        UnsafeOp::UnsafeCall => "unsafe_call",
+        UnsafeOp::ForeignCall => "ffi_call",
+        UnsafeOp::UnionFieldAccess => "union_field",
+        UnsafeOp::StaticMutAccess => "static_mut",
+        UnsafeOp::ForeignStaticAccess => "extern_static",
+        UnsafeOp::InlineAsm => "inline_asm",
+    };
+    let loc = cx.sess().source_map().lookup_char_pos(expr.span.lo());
+    UnsafeOperation {
+        kind,
+        line: loc.line,
+        column: loc.col.to_usize() + 1,
+        code: snippet(cx, expr.span, "..").into_owned(),
     }
 }
 
+/// Gets the text of the `# Safety` section of the item's documentation.
+fn safety_doc_section(cx: &LateContext<'_>, hir_id: HirId) -> Option<String> {
+    let docs: Vec<_> = cx
+        .tcx
+        .hir()
+        .attrs(hir_id)
+        .iter()
+        .filter_map(Attribute::doc_str)
+        .collect();
+    let mut lines = docs.iter().flat_map(|doc| doc.as_str().lines()).map(str::trim);
+    lines.find(|line| line.starts_with('#') && line.trim_start_matches('#').trim().eq_ignore_ascii_case("safety"))?;
+    let section = lines
+        .take_while(|line| !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let section = section.trim();
+    (!section.is_empty()).then(|| section.to_owned())
+}
+
+/// Returns the span of the first line of `span`.
+fn first_line_span(cx: &LateContext<'_>, span: Span) -> Span {
+    let source_map = cx.sess().source_map();
+    if source_map.is_multiline(span) {
+        source_map.span_until_char(span, '\n')
+    } else {
+        span
+    }
+}
+
+/// Calls the given function for each unsafe operation performed in the block, which may itself be
+/// an `unsafe` block.
+fn for_each_block_unsafe_op<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'tcx>,
+    mut f: impl FnMut(UnsafeOp, &'tcx Expr<'tcx>) -> ControlFlow<()>,
+) {
+    for stmt in block.stmts {
+        for_each_unsafe_op(cx, stmt, &mut f);
+    }
+    if let Some(expr) = block.expr {
+        for_each_unsafe_op(cx, expr, &mut f);
+    }
+}
+
+/// Checks if the statement contains an unsafe operation or an `unsafe` block.
+fn is_stmt_unsafe<'tcx>(cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'tcx>) -> bool {
+    for_each_unsafe_op(cx, stmt, |_, _| ControlFlow::Break(())).is_some() || contains_unsafe_block(cx, stmt)
+}
+
+/// Gets the name a safety comment uses to refer to an unsafe operation.
+fn unsafe_operation_name(cx: &LateContext<'_>, expr: &Expr<'_>) -> String {
+    match expr.kind {
+        ExprKind::Unary(UnOp::Deref, operand) | ExprKind::Call(operand, _) => operand_name(cx, operand),
+        ExprKind::MethodCall(path, ..) => path.ident.to_string(),
+        ExprKind::InlineAsm(_) => String::from("asm"),
+        _ => operand_name(cx, expr),
+    }
+}
+
+/// Gets the name of the pointer, function or static used in an unsafe operation.
+fn operand_name(cx: &LateContext<'_>, expr: &Expr<'_>) -> String {
+    match expr.kind {
+        ExprKind::Path(QPath::Resolved(
+            _,
+            hir::Path {
+                segments: [.., last], ..
+            },
+        )) => last.ident.to_string(),
+        ExprKind::Path(QPath::TypeRelative(_, segment)) => segment.ident.to_string(),
+        ExprKind::Field(_, ident) => ident.to_string(),
+        ExprKind::MethodCall(_, [recv, ..], _) | ExprKind::Cast(recv, _) | ExprKind::Unary(UnOp::Deref, recv) => {
+            operand_name(cx, recv)
+        },
+        _ => snippet(cx, expr.span, "..").into_owned(),
+    }
+}
+
+/// Checks if `name` appears as a separate word in the comment.
+fn comment_mentions(comment: &str, name: &str) -> bool {
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+    comment.match_indices(name).any(|(start, _)| {
+        !comment[..start].ends_with(is_ident_char) && !comment[start + name.len()..].starts_with(is_ident_char)
+    })
+}
+
+/// Gets the span of the statement, or the trailing expression of a block, containing the
+/// expression.
+fn enclosing_statement_span(cx: &LateContext<'_>, hir_id: HirId) -> Span {
+    let map = cx.tcx.hir();
+    let mut span = map.span(hir_id);
+    for (_, node) in map.parent_iter(hir_id) {
+        match node {
+            Node::Stmt(stmt) => return stmt.span,
+            Node::Expr(expr) => span = expr.span,
+            Node::Block(_) | Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) | Node::AnonConst(_) => break,
+            _ => (),
+        }
+    }
+    span
+}
+
 fn is_unsafe_from_proc_macro(cx: &LateContext<'_>, span: Span) -> bool {
     let source_map = cx.sess().source_map();
     let file_pos = source_map.lookup_byte_offset(span.lo());
@@ -139,67 +585,33 @@ fn block_has_safety_comment(cx: &LateContext<'_>, block: &hir::Block<'_>) -> boo
     span_from_macro_expansion_has_safety_comment(cx, block.span) || span_in_body_has_safety_comment(cx, block.span)
 }
 
-/// Checks if the lines immediately preceding the item contain a safety comment.
-#[allow(clippy::collapsible_match)]
-fn item_has_safety_comment(cx: &LateContext<'_>, item: &hir::Item<'_>) -> bool {
-    if span_from_macro_expansion_has_safety_comment(cx, item.span) {
+/// Checks if the lines immediately preceding `unsafe_span`, the start of the item or one of its
+/// attributes, contain a safety comment.
+fn item_has_safety_comment(cx: &LateContext<'_>, item: &hir::Item<'_>, unsafe_span: Span) -> bool {
+    if span_from_macro_expansion_has_safety_comment(cx, unsafe_span) {
         return true;
     }
 
     if item.span.ctxt() == SyntaxContext::root() {
-        if let Some(parent_node) = get_parent_node(cx.tcx, item.hir_id()) {
-            let comment_start = match parent_node {
-                Node::Crate(parent_mod) => {
-                    comment_start_before_impl_in_mod(cx, parent_mod, parent_mod.spans.inner_span, item)
-                },
-                Node::Item(parent_item) => {
-                    if let ItemKind::Mod(parent_mod) = &parent_item.kind {
-                        comment_start_before_impl_in_mod(cx, parent_mod, parent_item.span, item)
-                    } else {
-                        // Doesn't support impls in this position. Pretend a comment was found.
-                        return true;
-                    }
-                },
-                Node::Stmt(stmt) => {
-                    if let Some(stmt_parent) = get_parent_node(cx.tcx, stmt.hir_id) {
-                        match stmt_parent {
-                            Node::Block(block) => walk_span_to_context(block.span, SyntaxContext::root()).map(Span::lo),
-                            _ => {
-                                // Doesn't support impls in this position. Pretend a comment was found.
-                                return true;
-                            },
-                        }
-                    } else {
-                        // Problem getting the parent node. Pretend a comment was found.
-                        return true;
-                    }
-                },
-                _ => {
-                    // Doesn't support impls in this position. Pretend a comment was found.
-                    return true;
-                },
-            };
-
-            let source_map = cx.sess().source_map();
-            if let Some(comment_start) = comment_start
-                && let Ok(unsafe_line) = source_map.lookup_line(item.span.lo())
-                && let Ok(comment_start_line) = source_map.lookup_line(comment_start)
-                && Lrc::ptr_eq(&unsafe_line.sf, &comment_start_line.sf)
-                && let Some(src) = unsafe_line.sf.src.as_deref()
-            {
-                unsafe_line.sf.lines(|lines| {
-                    comment_start_line.line < unsafe_line.line && text_has_safety_comment(
+        let source_map = cx.sess().source_map();
+        if let Some(comment_start) = item_comment_start(cx, item)
+            && let Ok(unsafe_line) = source_map.lookup_line(unsafe_span.lo())
+            && let Ok(comment_start_line) = source_map.lookup_line(comment_start)
+            && Lrc::ptr_eq(&unsafe_line.sf, &comment_start_line.sf)
+            && let Some(src) = unsafe_line.sf.src.as_deref()
+        {
+            unsafe_line.sf.lines(|lines| {
+                comment_start_line.line < unsafe_line.line
+                    && text_safety_comment(
                         src,
                         &lines[comment_start_line.line + 1..=unsafe_line.line],
                         unsafe_line.sf.start_pos.to_usize(),
                     )
-                })
-            } else {
-                // Problem getting source text. Pretend a comment was found.
-                true
-            }
+                    .is_some()
+            })
         } else {
-            // No parent node. Pretend a comment was found.
+            // Problem getting source text, or the item is in an unsupported position. Pretend a comment
+            // was found.
             true
         }
     } else {
@@ -207,6 +619,33 @@ fn item_has_safety_comment(cx: &LateContext<'_>, item: &hir::Item<'_>) -> bool {
     }
 }
 
+/// Gets the text of the safety comment on the lines immediately preceding the item.
+fn item_safety_comment(cx: &LateContext<'_>, item: &hir::Item<'_>) -> Option<String> {
+    if item.span.ctxt() == SyntaxContext::root() {
+        safety_comment_after(cx, item_comment_start(cx, item)?, item.span)
+    } else {
+        None
+    }
+}
+
+/// Gets the position after which a comment on the item can start. Returns `None` if the item is
+/// in an unsupported position.
+fn item_comment_start(cx: &LateContext<'_>, item: &hir::Item<'_>) -> Option<BytePos> {
+    match get_parent_node(cx.tcx, item.hir_id())? {
+        Node::Crate(parent_mod) => comment_start_before_impl_in_mod(cx, parent_mod, parent_mod.spans.inner_span, item),
+        Node::Item(hir::Item {
+            kind: ItemKind::Mod(parent_mod),
+            span,
+            ..
+        }) => comment_start_before_impl_in_mod(cx, parent_mod, *span, item),
+        Node::Stmt(stmt) => match get_parent_node(cx.tcx, stmt.hir_id)? {
+            Node::Block(block) => walk_span_to_context(block.span, SyntaxContext::root()).map(Span::lo),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn comment_start_before_impl_in_mod(
     cx: &LateContext<'_>,
     parent_mod: &hir::Mod<'_>,
@@ -252,11 +691,13 @@ fn span_from_macro_expansion_has_safety_comment(cx: &LateContext<'_>, span: Span
             && let Some(src) = unsafe_line.sf.src.as_deref()
         {
             unsafe_line.sf.lines(|lines| {
-                macro_line.line < unsafe_line.line && text_has_safety_comment(
-                    src,
-                    &lines[macro_line.line + 1..=unsafe_line.line],
-                    unsafe_line.sf.start_pos.to_usize(),
-                )
+                macro_line.line < unsafe_line.line
+                    && text_safety_comment(
+                        src,
+                        &lines[macro_line.line + 1..=unsafe_line.line],
+                        unsafe_line.sf.start_pos.to_usize(),
+                    )
+                    .is_some()
             })
         } else {
             // Problem getting source text. Pretend a comment was found.
@@ -295,11 +736,13 @@ fn span_in_body_has_safety_comment(cx: &LateContext<'_>, span: Span) -> bool {
             //     fn foo() { some_stuff; unsafe { stuff }; other_stuff; }
             //              ^-------------^
             unsafe_line.sf.lines(|lines| {
-                body_line.line < unsafe_line.line && text_has_safety_comment(
-                    src,
-                    &lines[body_line.line + 1..=unsafe_line.line],
-                    unsafe_line.sf.start_pos.to_usize(),
-                )
+                body_line.line < unsafe_line.line
+                    && text_safety_comment(
+                        src,
+                        &lines[body_line.line + 1..=unsafe_line.line],
+                        unsafe_line.sf.start_pos.to_usize(),
+                    )
+                    .is_some()
             })
         } else {
             // Problem getting source text. Pretend a comment was found.
@@ -310,8 +753,39 @@ fn span_in_body_has_safety_comment(cx: &LateContext<'_>, span: Span) -> bool {
     }
 }
 
-/// Checks if the given text has a safety comment for the immediately proceeding line.
-fn text_has_safety_comment(src: &str, line_starts: &[BytePos], offset: usize) -> bool {
+/// Gets the text of the safety comment on the lines immediately preceding `span`, which must be
+/// in the current body.
+fn span_in_body_safety_comment(cx: &LateContext<'_>, span: Span) -> Option<String> {
+    if span.ctxt() == SyntaxContext::root() {
+        let body_span = walk_span_to_context(get_body_search_span(cx)?, SyntaxContext::root())?;
+        safety_comment_after(cx, body_span.lo(), span)
+    } else {
+        None
+    }
+}
+
+/// Gets the text of the safety comment on the lines immediately preceding `span`, starting after
+/// the line containing `start`.
+fn safety_comment_after(cx: &LateContext<'_>, start: BytePos, span: Span) -> Option<String> {
+    let source_map = cx.sess().source_map();
+    let unsafe_line = source_map.lookup_line(span.lo()).ok()?;
+    let start_line = source_map.lookup_line(start).ok()?;
+    let src = unsafe_line.sf.src.as_deref()?;
+    if !Lrc::ptr_eq(&unsafe_line.sf, &start_line.sf) || start_line.line >= unsafe_line.line {
+        return None;
+    }
+    unsafe_line.sf.lines(|lines| {
+        text_safety_comment(
+            src,
+            &lines[start_line.line + 1..=unsafe_line.line],
+            unsafe_line.sf.start_pos.to_usize(),
+        )
+        .map(str::to_owned)
+    })
+}
+
+/// Gets the safety comment for the immediately proceeding line of the given text.
+fn text_safety_comment<'src>(src: &'src str, line_starts: &[BytePos], offset: usize) -> Option<&'src str> {
     let mut lines = line_starts
         .array_windows::<2>()
         .rev()
@@ -322,37 +796,31 @@ fn text_has_safety_comment(src: &str, line_starts: &[BytePos], offset: usize) ->
         })
         .filter(|(_, text)| !text.is_empty());
 
-    let Some((line_start, line)) = lines.next() else {
-        return false;
-    };
+    let (mut line_start, mut line) = lines.next()?;
+    let comment_end = line_starts.last().unwrap().to_usize() - offset;
     // Check for a sequence of line comments.
     if line.starts_with("//") {
-        let mut line = line;
         loop {
             if line.to_ascii_uppercase().contains("SAFETY:") {
-                return true;
+                return Some(&src[line_start..comment_end]);
             }
             match lines.next() {
-                Some((_, x)) if x.starts_with("//") => line = x,
-                _ => return false,
+                Some((start, x)) if x.starts_with("//") => (line_start, line) = (start, x),
+                _ => return None,
             }
         }
     }
     // No line comments; look for the start of a block comment.
     // This will only find them if they are at the start of a line.
-    let (mut line_start, mut line) = (line_start, line);
     loop {
         if line.starts_with("/*") {
-            let src = src[line_start..line_starts.last().unwrap().to_usize() - offset].trim_start();
+            let src = src[line_start..comment_end].trim_start();
             let mut tokens = tokenize(src);
-            return src[..tokens.next().unwrap().len]
-                .to_ascii_uppercase()
-                .contains("SAFETY:")
-                && tokens.all(|t| t.kind == TokenKind::Whitespace);
-        }
-        match lines.next() {
-            Some(x) => (line_start, line) = x,
-            None => return false,
+            let comment = &src[..tokens.next().unwrap().len];
+            return (comment.to_ascii_uppercase().contains("SAFETY:")
+                && tokens.all(|t| t.kind == TokenKind::Whitespace))
+            .then_some(comment);
         }
+        (line_start, line) = lines.next()?;
     }
 }
@@ -4,6 +4,7 @@
 
 use serde::de::{Deserializer, IgnoredAny, IntoDeserializer, MapAccess, Visitor};
 use serde::Deserialize;
+use std::any::Any;
 use std::error::Error;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -31,6 +32,61 @@ const DEFAULT_DOC_VALID_IDENTS: &[&str] = &[
     "CamelCase",
 ];
 const DEFAULT_BLACKLISTED_NAMES: &[&str] = &["foo", "baz", "quux"];
+const DEFAULT_SPAWN_FUNCTIONS: &[&str] = &[
+    "async_std::task::spawn",
+    "async_std::task::spawn_local",
+    "smol::spawn",
+    "tokio::spawn",
+    "tokio::task::spawn",
+    "tokio::task::spawn_local",
+];
+const DEFAULT_BLOCKING_FUNCTIONS: &[(&str, Option<&str>)] = &[
+    ("std::fs::copy", Some("tokio::fs::copy")),
+    ("std::fs::create_dir_all", Some("tokio::fs::create_dir_all")),
+    ("std::fs::metadata", Some("tokio::fs::metadata")),
+    ("std::fs::read", Some("tokio::fs::read")),
+    ("std::fs::read_dir", Some("tokio::fs::read_dir")),
+    ("std::fs::read_to_string", Some("tokio::fs::read_to_string")),
+    ("std::fs::remove_dir_all", Some("tokio::fs::remove_dir_all")),
+    ("std::fs::remove_file", Some("tokio::fs::remove_file")),
+    ("std::fs::rename", Some("tokio::fs::rename")),
+    ("std::fs::write", Some("tokio::fs::write")),
+    ("std::fs::File::create", Some("tokio::fs::File::create")),
+    ("std::fs::File::open", Some("tokio::fs::File::open")),
+    ("std::net::TcpStream::connect", Some("tokio::net::TcpStream::connect")),
+    ("std::process::Command::output", Some("tokio::process::Command::output")),
+    ("std::process::Command::status", Some("tokio::process::Command::status")),
+    ("std::sync::Mutex::lock", Some("tokio::sync::Mutex::lock")),
+    ("std::sync::RwLock::read", Some("tokio::sync::RwLock::read")),
+    ("std::sync::RwLock::write", Some("tokio::sync::RwLock::write")),
+    ("std::thread::sleep", Some("tokio::time::sleep")),
+    ("std::thread::JoinHandle::join", None),
+    ("reqwest::blocking::get", Some("reqwest::get")),
+];
+
+fn default_blocking_functions() -> Vec<BlockingFunction> {
+    DEFAULT_BLOCKING_FUNCTIONS
+        .iter()
+        .map(|&(path, alternative)| BlockingFunction::WithAlternative {
+            path: path.to_owned(),
+            alternative: alternative.map(ToOwned::to_owned),
+        })
+        .collect()
+}
+
+fn default_required_metadata_fields() -> Vec<String> {
+    [
+        "description",
+        "license",
+        "repository",
+        "readme",
+        "keywords",
+        "categories",
+    ]
+    .iter()
+    .map(ToString::to_string)
+    .collect()
+}
 
 /// Holds information used by `MISSING_ENFORCED_IMPORT_RENAMES` lint.
 #[derive(Clone, Debug, Deserialize)]
@@ -63,6 +119,108 @@ pub enum DisallowedType {
     WithReason { path: String, reason: Option<String> },
 }
 
+/// A single blocking function, used by the `BLOCKING_IN_ASYNC` lint.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum BlockingFunction {
+    Simple(String),
+    WithAlternative { path: String, alternative: Option<String> },
+}
+
+impl BlockingFunction {
+    pub fn path(&self) -> &str {
+        let (Self::Simple(path) | Self::WithAlternative { path, .. }) = self;
+
+        path
+    }
+
+    pub fn alternative(&self) -> Option<&str> {
+        match self {
+            Self::Simple(_) => None,
+            Self::WithAlternative { alternative, .. } => alternative.as_deref(),
+        }
+    }
+}
+
+/// A crate which may have multiple versions, used by the `MULTIPLE_CRATE_VERSIONS` lint.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum AllowedDuplicateCrate {
+    Simple(String),
+    WithVersions { name: String, versions: Vec<String> },
+}
+
+impl AllowedDuplicateCrate {
+    pub fn name(&self) -> &str {
+        let (Self::Simple(name) | Self::WithVersions { name, .. }) = self;
+
+        name
+    }
+
+    pub fn versions(&self) -> &[String] {
+        match self {
+            Self::Simple(_) => &[],
+            Self::WithVersions { versions, .. } => versions,
+        }
+    }
+}
+
+/// A required value of a `package` field, used by the `CARGO_COMMON_METADATA` lint.
+#[derive(Clone, Debug, Deserialize)]
+pub struct MetadataFieldValue {
+    pub field: String,
+    pub value: toml::Value,
+}
+
+/// The sections the documentation of some kind of items must have, used by the
+/// `MISSING_DOC_SECTIONS` lint.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DocSectionRule {
+    pub items: DocSectionItems,
+    /// The headings of the sections, in the order they must appear in
+    pub sections: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum DocSectionItems {
+    Any,
+    Fn,
+    AsyncFn,
+    UnsafeFn,
+    ResultFn,
+    Struct,
+    Enum,
+    Union,
+    Trait,
+    UnsafeTrait,
+    Const,
+    Static,
+    Type,
+    Mod,
+}
+
+impl DocSectionItems {
+    pub fn descr(self) -> &'static str {
+        match self {
+            Self::Any => "item",
+            Self::Fn => "function",
+            Self::AsyncFn => "async function",
+            Self::UnsafeFn => "unsafe function",
+            Self::ResultFn => "function returning `Result`",
+            Self::Struct => "struct",
+            Self::Enum => "enum",
+            Self::Union => "union",
+            Self::Trait => "trait",
+            Self::UnsafeTrait => "unsafe trait",
+            Self::Const => "constant",
+            Self::Static => "static",
+            Self::Type => "type alias",
+            Self::Mod => "module",
+        }
+    }
+}
+
 /// Conf with parse errors
 #[derive(Default)]
 pub struct TryConf {
@@ -94,6 +252,22 @@ fn conf_error(s: String) -> Box<dyn Error> {
     Box::new(ConfError(s))
 }
 
+/// Returns the value of an option if it's a boolean.
+fn bool_value(value: &dyn Any) -> Option<bool> {
+    value.downcast_ref().copied()
+}
+
+/// Returns the lints listed in the `Lint: LINT_NAME_1, LINT_NAME_2.` line of the documentation of
+/// an option, in lower case.
+fn doc_lints(doc: &str) -> Vec<String> {
+    doc.trim_start()
+        .strip_prefix("Lint: ")
+        .and_then(|doc| doc.split_once('.'))
+        .map_or_else(Vec::new, |(lints, _)| {
+            lints.split(", ").map(str::to_ascii_lowercase).collect()
+        })
+}
+
 macro_rules! define_Conf {
     ($(
         $(#[doc = $doc:literal])+
@@ -115,6 +289,23 @@ macro_rules! define_Conf {
             }
         }
 
+        impl Conf {
+            /// Returns the boolean `allow-*` options which are enabled while they are disabled by
+            /// default, along with the lints they suppress.
+            pub fn enabled_allow_options(&self) -> Vec<(String, Vec<String>)> {
+                let mut options = Vec::new();
+                $(
+                    if stringify!($name).starts_with("allow_")
+                        && bool_value(&self.$name) == Some(true)
+                        && bool_value(&defaults::$name()) == Some(false)
+                    {
+                        options.push((stringify!($name).replace('_', "-"), doc_lints(concat!($($doc, '\n',)*))));
+                    }
+                )*
+                options
+            }
+        }
+
         impl<'de> Deserialize<'de> for TryConf {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
                 deserializer.deserialize_map(ConfVisitor)
@@ -195,7 +386,7 @@ define_Conf! {
     ///
     /// Suppress lints whenever the suggested change would cause breakage for other crates.
     (avoid_breaking_exported_api: bool = true),
-    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED.
+    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS.
     ///
     /// The minimum rust version that the project supports
     (msrv: Option<String> = None),
@@ -224,6 +415,17 @@ define_Conf! {
     ///
     /// Default list:
     (doc_valid_idents: Vec<String> = super::DEFAULT_DOC_VALID_IDENTS.iter().map(ToString::to_string).collect()),
+    /// Lint: MISSING_DOC_SECTIONS.
+    ///
+    /// The sections the documentation of exported items must have, in the given order. The `items` are one of
+    /// `any`, `fn`, `async-fn`, `unsafe-fn`, `result-fn`, `struct`, `enum`, `union`, `trait`, `unsafe-trait`,
+    /// `const`, `static`, `type` or `mod`, e.g. `[{ items = "result-fn", sections = ["Errors"] }]`
+    (doc_sections: Vec<crate::utils::conf::DocSectionRule> = Vec::new()),
+    /// Lint: DOC_SPELLING.
+    ///
+    /// Files with additional words for the spell checker, relative to the configuration file. The files have one
+    /// word per line, lines starting with `#` are ignored.
+    (doc_spelling_dictionaries: Vec<String> = Vec::new()),
     /// Lint: TOO_MANY_ARGUMENTS.
     ///
     /// The maximum number of argument a function or method can have
@@ -312,6 +514,18 @@ define_Conf! {
     ///
     /// For internal testing only, ignores the current `publish` settings in the Cargo manifest.
     (cargo_ignore_publish: bool = false),
+    /// Lint: CARGO_COMMON_METADATA.
+    ///
+    /// The `package` fields of the manifest which must be set. `license` is also satisfied by `license-file`
+    (required_metadata_fields: Vec<String> = super::default_required_metadata_fields()),
+    /// Lint: CARGO_COMMON_METADATA.
+    ///
+    /// The `package` fields of the manifest which must not be set, e.g. `["authors"]`
+    (forbidden_metadata_fields: Vec<String> = Vec::new()),
+    /// Lint: CARGO_COMMON_METADATA.
+    ///
+    /// The values `package` fields of the manifest must have, e.g. `[{ field = "edition", value = "2021" }]`
+    (metadata_field_values: Vec<crate::utils::conf::MetadataFieldValue> = Vec::new()),
     /// Lint: NONSTANDARD_MACRO_BRACES.
     ///
     /// Enforce the named macros always use the braces specified.
@@ -356,6 +570,71 @@ define_Conf! {
     ///
     /// Whether `dbg!` should be allowed in test functions
     (allow_dbg_in_tests: bool = false),
+    /// Lint: DISALLOWED_LINT_SUPPRESSIONS.
+    ///
+    /// The key of the `reason` field holding the date after which a lint suppression expires, e.g. `until` for
+    /// `reason = "until=2030-12-31"`.
+    (allow_reason_expiry_key: String = String::from("until")),
+    /// Lint: DISALLOWED_LINT_SUPPRESSIONS.
+    ///
+    /// The keys which must be present in the `reason` of every lint suppression, e.g. `["ticket"]` to require
+    /// `reason = "ticket=ABC-123"`.
+    (allow_reason_required_keys: Vec<String> = Vec::new()),
+    /// Lint: DISALLOWED_LINT_SUPPRESSIONS.
+    ///
+    /// The list of lints which must not be suppressed, written as in the lint attribute, e.g. `clippy::unwrap_used`.
+    (non_suppressible_lints: Vec<String> = Vec::new()),
+    /// Lint: PANIC_IN_NO_PANIC_FN.
+    ///
+    /// The list of functions, written as fully qualified paths, which are checked as if they were marked
+    /// with `#[clippy::no_panic]`.
+    (no_panic_functions: Vec<String> = Vec::new()),
+    /// Lint: UNDOCUMENTED_UNSAFE_BLOCKS.
+    ///
+    /// Whether the safety comment of an `unsafe` block has to mention each unsafe operation performed in it, i.e. the
+    /// dereferenced pointer, the called function or the accessed static.
+    (require_safety_comment_per_operation: bool = false),
+    /// Lint: LARGE_FUTURES.
+    ///
+    /// The maximum byte size a `Future` can have, before it triggers the `clippy::large_futures` lint
+    (future_size_threshold: u64 = 16 * 1024),
+    /// Lint: LARGE_FUTURES, BLOCKING_IN_ASYNC.
+    ///
+    /// The list of functions, written as fully qualified paths, which spawn the future they are passed
+    (future_spawn_functions: Vec<String> = super::DEFAULT_SPAWN_FUNCTIONS.iter().map(ToString::to_string).collect()),
+    /// Lint: BLOCKING_IN_ASYNC.
+    ///
+    /// The list of blocking functions, written as fully qualified paths, optionally with the path of an async
+    /// `alternative`
+    (blocking_functions: Vec<crate::utils::conf::BlockingFunction> = super::default_blocking_functions()),
+    /// Lint: INDEXING_SLICING.
+    ///
+    /// Whether to only lint indexing and slicing which isn't dominated by a bounds check, like `if i < v.len()`,
+    /// `assert!(v.len() > i)` or `for i in 0..v.len()`
+    (allow_checked_indexing: bool = false),
+    /// Lint: MULTIPLE_CRATE_VERSIONS.
+    ///
+    /// The crates which are allowed to have multiple versions, optionally only when all of them match one of the
+    /// `versions` requirements, e.g. `["winapi", { name = "syn", versions = ["1", "2"] }]`
+    (allowed_duplicate_crates: Vec<crate::utils::conf::AllowedDuplicateCrate> = Vec::new()),
+    /// Lint: DISALLOWED_DEPENDENCIES.
+    ///
+    /// The SPDX identifiers of the licenses dependencies may use. If empty, all licenses which aren't denied are
+    /// allowed
+    (allowed_licenses: Vec<String> = Vec::new()),
+    /// Lint: DISALLOWED_DEPENDENCIES.
+    ///
+    /// The SPDX identifiers of the licenses dependencies may not use
+    (denied_licenses: Vec<String> = Vec::new()),
+    /// Lint: DISALLOWED_DEPENDENCIES.
+    ///
+    /// Whether dependencies may come from git repositories
+    (allow_git_dependencies: bool = true),
+    /// Lint: DISALLOWED_DEPENDENCIES.
+    ///
+    /// The index URLs of the registries dependencies may come from, with `crates-io` for crates.io. If empty, all
+    /// registries are allowed
+    (allowed_registries: Vec<String> = Vec::new()),
 }
 
 /// Search for the configuration file.
@@ -417,6 +696,11 @@ pub fn read(path: &Path) -> TryConf {
         Ok(mut conf) => {
             extend_vec_if_indicator_present(&mut conf.conf.doc_valid_idents, DEFAULT_DOC_VALID_IDENTS);
             extend_vec_if_indicator_present(&mut conf.conf.blacklisted_names, DEFAULT_BLACKLISTED_NAMES);
+            if let Some(dir) = path.parent() {
+                for dictionary in &mut conf.conf.doc_spelling_dictionaries {
+                    *dictionary = dir.join(&*dictionary).to_string_lossy().into_owned();
+                }
+            }
 
             conf
         },
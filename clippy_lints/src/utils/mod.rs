@@ -3,3 +3,4 @@ pub mod conf;
 pub mod dump_hir;
 #[cfg(feature = "internal")]
 pub mod internal_lints;
+pub mod report;
@@ -0,0 +1,28 @@
+//! Write the reports requested with `--report-suppressions` and `--report-unsafe`.
+
+use rustc_lint::{LateContext, LintContext};
+use serde::Serialize;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Appends the report of the current crate to the file at `path`, as a single line of JSON.
+///
+/// The file is shared by all the crates checked by `cargo clippy`, each of them adding its own
+/// line in a single write.
+pub fn append(cx: &LateContext<'_>, path: &Path, report: &impl Serialize) {
+    let result = serde_json::to_string(report)
+        .map_err(io::Error::from)
+        .and_then(|mut json| {
+            json.push('\n');
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)?
+                .write_all(json.as_bytes())
+        });
+    if let Err(e) = result {
+        cx.sess()
+            .err(&format!("failed to write the report to `{}`: {}", path.display(), e));
+    }
+}
@@ -54,6 +54,7 @@ pub mod str_utils;
 pub mod sugg;
 pub mod ty;
 pub mod usage;
+pub mod value_range;
 pub mod visitors;
 
 pub use self::attrs::*;
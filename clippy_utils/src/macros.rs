@@ -40,6 +40,28 @@ pub fn is_format_macro(cx: &LateContext<'_>, macro_def_id: DefId) -> bool {
     }
 }
 
+/// Returns true if a given Macro `DefId` is a logging macro of the `log` or `tracing` crates
+/// (e.g. `log::info!` or `tracing::event!`)
+pub fn is_log_macro(cx: &LateContext<'_>, macro_def_id: DefId) -> bool {
+    let name = cx.tcx.item_name(macro_def_id);
+    match cx.tcx.crate_name(macro_def_id.krate).as_str() {
+        "log" => matches!(name.as_str(), "trace" | "debug" | "info" | "warn" | "error" | "log"),
+        "tracing" => matches!(name.as_str(), "trace" | "debug" | "info" | "warn" | "error" | "event"),
+        _ => false,
+    }
+}
+
+/// Returns true if a given Macro `DefId` checks whether a log level is enabled (e.g.
+/// `log::log_enabled!` or `tracing::enabled!`)
+pub fn is_log_enabled_macro(cx: &LateContext<'_>, macro_def_id: DefId) -> bool {
+    let name = cx.tcx.item_name(macro_def_id);
+    match cx.tcx.crate_name(macro_def_id.krate).as_str() {
+        "log" => name.as_str() == "log_enabled",
+        "tracing" => matches!(name.as_str(), "enabled" | "event_enabled" | "level_enabled"),
+        _ => false,
+    }
+}
+
 /// A macro call, like `vec![1, 2, 3]`.
 ///
 /// Use `tcx.item_name(macro_call.def_id)` to get the macro name.
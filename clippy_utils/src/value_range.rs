@@ -0,0 +1,451 @@
+//! A value range analysis for integer expressions.
+//!
+//! The ranges are computed from constants, the operations applied to them, and the `if` guards
+//! dominating a local. Only immutable bindings are followed, so an assignment can never invalidate
+//! a range.
+
+use crate::consts::{constant_context, ConstEvalLateContext, Constant};
+use crate::{find_binding_init, higher, int_bits, path_to_local, path_to_local_id, sext};
+use rustc_hir::{BinOpKind, BindingAnnotation, Block, Expr, ExprKind, HirId, Node, Pat, PatKind, StmtKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty::layout::IntegerExt;
+use rustc_middle::ty::{self, Ty, TyCtxt};
+use rustc_target::abi::Integer;
+use std::fmt;
+
+/// How deep the analysis looks through nested expressions and bindings.
+const MAX_DEPTH: u32 = 16;
+
+/// An inclusive range of integer values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IntRange {
+    pub lo: i128,
+    pub hi: i128,
+}
+
+impl IntRange {
+    #[must_use]
+    pub fn new(lo: i128, hi: i128) -> Self {
+        debug_assert!(lo <= hi);
+        Self { lo, hi }
+    }
+
+    #[must_use]
+    pub fn singleton(value: i128) -> Self {
+        Self::new(value, value)
+    }
+
+    /// Returns the range of values of the given integer type, or `None` for `u128`, which can't be
+    /// represented, and for non-integer types.
+    #[must_use]
+    pub fn of_ty(tcx: TyCtxt<'_>, ty: Ty<'_>) -> Option<Self> {
+        match *ty.kind() {
+            ty::Int(ity) => {
+                let shift = 128 - int_bits(tcx, ity);
+                Some(Self::new(i128::MIN >> shift, i128::MAX >> shift))
+            },
+            ty::Uint(uty) => {
+                let bits = Integer::from_uint_ty(&tcx, uty).size().bits();
+                (bits < 128).then(|| Self::new(0, i128::MAX >> (127 - bits)))
+            },
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn is_singleton(self) -> bool {
+        self.lo == self.hi
+    }
+
+    #[must_use]
+    pub fn contains(self, value: i128) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+
+    /// Checks if every value of `other` is also in this range.
+    #[must_use]
+    pub fn contains_range(self, other: Self) -> bool {
+        self.lo <= other.lo && other.hi <= self.hi
+    }
+
+    /// Returns the values contained in both ranges, or `None` if there are none.
+    #[must_use]
+    pub fn intersect(self, other: Self) -> Option<Self> {
+        let (lo, hi) = (self.lo.max(other.lo), self.hi.min(other.hi));
+        (lo <= hi).then(|| Self::new(lo, hi))
+    }
+
+    /// Returns the smallest range containing both ranges.
+    #[must_use]
+    pub fn hull(self, other: Self) -> Self {
+        Self::new(self.lo.min(other.lo), self.hi.max(other.hi))
+    }
+
+    /// Applies a function which is monotonic in both of its arguments to every corner of the two
+    /// ranges, and returns the range between the smallest and largest result.
+    fn corners(self, other: Self, f: impl Fn(i128, i128) -> Option<i128>) -> Option<Self> {
+        let values = [
+            f(self.lo, other.lo)?,
+            f(self.lo, other.hi)?,
+            f(self.hi, other.lo)?,
+            f(self.hi, other.hi)?,
+        ];
+        Some(Self::new(*values.iter().min()?, *values.iter().max()?))
+    }
+}
+
+impl fmt::Display for IntRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_singleton() {
+            write!(f, "{}", self.lo)
+        } else {
+            write!(f, "{}..={}", self.lo, self.hi)
+        }
+    }
+}
+
+/// Computes the range of values the integer expression can evaluate to. If nothing is known about
+/// it, this is the range of its type.
+///
+/// Returns `None` for non-integer expressions and for `u128`.
+pub fn expr_range(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<IntRange> {
+    RangeAnalysis::new(cx).expr(expr)
+}
+
+/// Computes the range of the mathematical result of the binary operation, i.e. before it is
+/// wrapped or checked against its type.
+///
+/// Returns `None` if it isn't known, or if the operation may panic for a reason other than an
+/// overflow, e.g. a division by zero.
+pub fn binary_op_range(cx: &LateContext<'_>, op: BinOpKind, left: &Expr<'_>, right: &Expr<'_>) -> Option<IntRange> {
+    RangeAnalysis::new(cx).binary_op(op, left, right)
+}
+
+struct RangeAnalysis<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    consts: ConstEvalLateContext<'a, 'tcx>,
+    depth: u32,
+}
+
+impl<'a, 'tcx> RangeAnalysis<'a, 'tcx> {
+    fn new(cx: &'a LateContext<'tcx>) -> Self {
+        Self {
+            cx,
+            consts: constant_context(cx, cx.typeck_results()),
+            depth: 0,
+        }
+    }
+
+    fn expr_ty(&self, e: &Expr<'_>) -> Ty<'tcx> {
+        self.cx.typeck_results().expr_ty(e).peel_refs()
+    }
+
+    fn expr(&mut self, e: &Expr<'_>) -> Option<IntRange> {
+        let ty_range = IntRange::of_ty(self.cx.tcx, self.expr_ty(e))?;
+        if self.depth >= MAX_DEPTH {
+            return Some(ty_range);
+        }
+        self.depth += 1;
+        let range = self.expr_inner(e, ty_range);
+        self.depth -= 1;
+        Some(range.and_then(|range| range.intersect(ty_range)).unwrap_or(ty_range))
+    }
+
+    fn expr_inner(&mut self, e: &Expr<'_>, ty_range: IntRange) -> Option<IntRange> {
+        if let Some(Constant::Int(value)) = self.consts.expr(e) {
+            return match *self.expr_ty(e).kind() {
+                ty::Int(ity) => Some(IntRange::singleton(sext(self.cx.tcx, value, ity))),
+                _ => i128::try_from(value).ok().map(IntRange::singleton),
+            };
+        }
+
+        match e.kind {
+            ExprKind::Path(_) => {
+                let local_id = path_to_local(e)?;
+                if !is_immutable_binding(self.cx, local_id) {
+                    return None;
+                }
+                let range = find_binding_init(self.cx, local_id)
+                    .filter(|init| self.cx.typeck_results().expr_adjustments(init).is_empty())
+                    .and_then(|init| self.expr(init))
+                    .unwrap_or(ty_range);
+                Some(self.apply_guards(e, local_id, range))
+            },
+            ExprKind::AddrOf(_, _, inner) | ExprKind::DropTemps(inner) => self.expr(inner),
+            ExprKind::Block(Block { expr: Some(inner), .. }, _) => self.expr(inner),
+            ExprKind::Cast(inner, _) => {
+                let inner_ty = self.cx.typeck_results().expr_ty(inner);
+                match inner_ty.kind() {
+                    ty::Bool => Some(IntRange::new(0, 1)),
+                    ty::Char => Some(IntRange::new(0, i128::from(u32::from(char::MAX)))),
+                    // Casts between integers keep the value if it fits, and wrap it otherwise.
+                    ty::Int(_) | ty::Uint(_) => self.expr(inner).filter(|&range| ty_range.contains_range(range)),
+                    _ => None,
+                }
+            },
+            ExprKind::Binary(op, left, right) => self.binary_op(op.node, left, right),
+            ExprKind::Unary(UnOp::Neg, inner) => {
+                let range = self.expr(inner)?;
+                Some(IntRange::new(range.hi.checked_neg()?, range.lo.checked_neg()?))
+            },
+            ExprKind::If(_, then, Some(els)) => self.branches([then, els].into_iter()),
+            ExprKind::Match(_, arms, _) => self.branches(arms.iter().map(|arm| arm.body)),
+            ExprKind::MethodCall(path, args, _) => self.method_call(path.ident.as_str(), args),
+            _ => None,
+        }
+    }
+
+    /// Computes the range of an expression with several branches, ignoring the diverging ones.
+    fn branches<'b>(&mut self, bodies: impl Iterator<Item = &'b Expr<'b>>) -> Option<IntRange> {
+        let mut result: Option<IntRange> = None;
+        for body in bodies {
+            if self.cx.typeck_results().expr_ty(body).is_never() {
+                continue;
+            }
+            let range = self.expr(body)?;
+            result = Some(result.map_or(range, |result| result.hull(range)));
+        }
+        result
+    }
+
+    fn binary_op(&mut self, op: BinOpKind, left: &Expr<'_>, right: &Expr<'_>) -> Option<IntRange> {
+        let bits = int_ty_bits(self.cx.tcx, self.expr_ty(left))?;
+        let (l, r) = (self.expr(left)?, self.expr(right)?);
+        match op {
+            BinOpKind::Add => Some(IntRange::new(l.lo.checked_add(r.lo)?, l.hi.checked_add(r.hi)?)),
+            BinOpKind::Sub => Some(IntRange::new(l.lo.checked_sub(r.hi)?, l.hi.checked_sub(r.lo)?)),
+            BinOpKind::Mul => l.corners(r, i128::checked_mul),
+            BinOpKind::Div if !r.contains(0) => l.corners(r, i128::checked_div),
+            // `MIN % -1` panics, even though the result would fit.
+            BinOpKind::Rem if !(r.contains(0) || r.contains(-1) && l.lo < 0) => {
+                // The result has the sign of the dividend, and is smaller than the divisor.
+                let max = i128::try_from(r.lo.unsigned_abs().max(r.hi.unsigned_abs()) - 1).ok()?;
+                Some(IntRange::new(l.lo.max(-max).min(0), l.hi.min(max).max(0)))
+            },
+            BinOpKind::BitAnd => match (l.lo >= 0, r.lo >= 0) {
+                (true, true) => Some(IntRange::new(0, l.hi.min(r.hi))),
+                (true, false) => Some(IntRange::new(0, l.hi)),
+                (false, true) => Some(IntRange::new(0, r.hi)),
+                (false, false) => None,
+            },
+            BinOpKind::BitOr if l.lo >= 0 && r.lo >= 0 => Some(IntRange::new(l.lo.max(r.lo), mask(l.hi.max(r.hi)))),
+            BinOpKind::BitXor if l.lo >= 0 && r.lo >= 0 => Some(IntRange::new(0, mask(l.hi.max(r.hi)))),
+            // Shifting by at least the number of bits panics.
+            BinOpKind::Shr if r.lo >= 0 && r.hi < i128::from(bits) => {
+                l.corners(r, |l, r| Some(l >> u32::try_from(r).ok()?))
+            },
+            BinOpKind::Shl if r.lo >= 0 && r.hi < i128::from(bits) => {
+                l.corners(r, |l, r| l.checked_mul(2_i128.checked_pow(u32::try_from(r).ok()?)?))
+            },
+            _ => None,
+        }
+    }
+
+    fn method_call(&mut self, name: &str, args: &[Expr<'_>]) -> Option<IntRange> {
+        match (name, args) {
+            ("min", [left, right]) => {
+                let (l, r) = (self.expr(left)?, self.expr(right)?);
+                Some(IntRange::new(l.lo.min(r.lo), l.hi.min(r.hi)))
+            },
+            ("max", [left, right]) => {
+                let (l, r) = (self.expr(left)?, self.expr(right)?);
+                Some(IntRange::new(l.lo.max(r.lo), l.hi.max(r.hi)))
+            },
+            ("clamp", [_, min, max]) => {
+                let (min, max) = (self.expr(min)?, self.expr(max)?);
+                // `clamp` panics if `min > max`.
+                (min.lo <= max.hi).then(|| IntRange::new(min.lo, max.hi))
+            },
+            ("rem_euclid", [_, right]) => {
+                let r = self.expr(right)?;
+                (r.lo > 0).then(|| IntRange::new(0, r.hi - 1))
+            },
+            ("abs", [inner]) => {
+                let range = self.expr(inner)?;
+                let (lo, hi) = (range.lo.checked_abs()?, range.hi.checked_abs()?);
+                Some(if range.contains(0) {
+                    IntRange::new(0, lo.max(hi))
+                } else {
+                    IntRange::new(lo.min(hi), lo.max(hi))
+                })
+            },
+            ("signum", [_]) => Some(IntRange::new(-1, 1)),
+            (
+                "count_ones" | "count_zeros" | "leading_ones" | "leading_zeros" | "trailing_ones" | "trailing_zeros",
+                [recv],
+            ) => {
+                let bits = int_ty_bits(self.cx.tcx, self.expr_ty(recv))?;
+                Some(IntRange::new(0, bits.into()))
+            },
+            ("len", [recv]) => {
+                if let ty::Array(_, len) = self.expr_ty(recv).kind() {
+                    let len = len.try_eval_usize(self.cx.tcx, self.cx.param_env)?;
+                    Some(IntRange::singleton(len.into()))
+                } else {
+                    None
+                }
+            },
+            _ => None,
+        }
+    }
+
+    /// Narrows the range of the local with the conditions guarding the expression.
+    fn apply_guards(&mut self, e: &Expr<'_>, local_id: HirId, mut range: IntRange) -> IntRange {
+        let cx = self.cx;
+        for_each_guard(cx, e, |guard| {
+            if let Guard::Cond { cond, holds } = guard {
+                range = self.apply_condition(cond, local_id, range, holds);
+            }
+        });
+        range
+    }
+
+    /// Narrows the range of the local assuming the condition evaluates to `holds`.
+    fn apply_condition(&mut self, cond: &Expr<'_>, local_id: HirId, range: IntRange, holds: bool) -> IntRange {
+        match cond.kind {
+            ExprKind::DropTemps(cond) => self.apply_condition(cond, local_id, range, holds),
+            ExprKind::Unary(UnOp::Not, cond) => self.apply_condition(cond, local_id, range, !holds),
+            ExprKind::Binary(op, left, right)
+                if (op.node == BinOpKind::And && holds) || (op.node == BinOpKind::Or && !holds) =>
+            {
+                let range = self.apply_condition(left, local_id, range, holds);
+                self.apply_condition(right, local_id, range, holds)
+            },
+            ExprKind::Binary(op, left, right) => {
+                let (op, other) = if path_to_local_id(left, local_id) {
+                    (op.node, right)
+                } else if path_to_local_id(right, local_id) {
+                    (flip(op.node), left)
+                } else {
+                    return range;
+                };
+                let op = if holds { Some(op) } else { negate(op) };
+                let Some(other) = self.expr(other) else { return range };
+                let bound = match op {
+                    Some(BinOpKind::Lt) => other.hi.checked_sub(1).map(|hi| IntRange::new(i128::MIN, hi)),
+                    Some(BinOpKind::Le) => Some(IntRange::new(i128::MIN, other.hi)),
+                    Some(BinOpKind::Gt) => other.lo.checked_add(1).map(|lo| IntRange::new(lo, i128::MAX)),
+                    Some(BinOpKind::Ge) => Some(IntRange::new(other.lo, i128::MAX)),
+                    Some(BinOpKind::Eq) => Some(other),
+                    _ => None,
+                };
+                // An empty intersection means the branch is unreachable, keep the range in that case.
+                bound.and_then(|bound| range.intersect(bound)).unwrap_or(range)
+            },
+            _ => range,
+        }
+    }
+}
+
+/// A guard of an expression, see [`for_each_guard`].
+pub enum Guard<'tcx> {
+    /// The condition is known to evaluate to `holds` at the expression.
+    Cond { cond: &'tcx Expr<'tcx>, holds: bool },
+    /// The expression is in the body of the `for` loop.
+    ForLoop(higher::ForLoop<'tcx>),
+}
+
+/// Calls `f` with the guards of the expression: the conditions of the `if` expressions it is in,
+/// of the preceding `if` expressions which leave their block early (like `assert!`), and the `for`
+/// loops it is in. The walk stops at the enclosing closure or item.
+pub fn for_each_guard<'tcx>(cx: &LateContext<'tcx>, e: &Expr<'_>, mut f: impl FnMut(Guard<'tcx>)) {
+    let mut child_id = e.hir_id;
+    for (parent_id, node) in cx.tcx.hir().parent_iter(e.hir_id) {
+        match node {
+            Node::Expr(parent) => {
+                if let Some(for_loop) = higher::ForLoop::hir(parent) {
+                    if for_loop.body.span.contains(e.span) {
+                        f(Guard::ForLoop(for_loop));
+                    }
+                } else if let ExprKind::If(cond, then, els) = parent.kind {
+                    if then.hir_id == child_id {
+                        f(Guard::Cond { cond, holds: true });
+                    } else if els.map_or(false, |els| els.hir_id == child_id) {
+                        f(Guard::Cond { cond, holds: false });
+                    }
+                } else if let ExprKind::Closure { .. } = parent.kind {
+                    break;
+                }
+            },
+            Node::Block(block) => {
+                for stmt in block.stmts.iter().take_while(|stmt| stmt.hir_id != child_id) {
+                    if let StmtKind::Expr(expr) | StmtKind::Semi(expr) = stmt.kind
+                        && let ExprKind::If(cond, then, None) = expr.kind
+                        && diverges(cx, then)
+                    {
+                        f(Guard::Cond { cond, holds: false });
+                    }
+                }
+            },
+            Node::Stmt(_) | Node::Local(_) | Node::Arm(_) => {},
+            _ => break,
+        }
+        child_id = parent_id;
+    }
+}
+
+/// Checks if the block always leaves the enclosing block early, by returning, breaking, continuing
+/// or diverging.
+fn diverges(cx: &LateContext<'_>, block: &Expr<'_>) -> bool {
+    let ExprKind::Block(block, _) = block.kind else { return false };
+    let last = match (block.expr, block.stmts.last()) {
+        (Some(expr), _) => expr,
+        (None, Some(stmt)) => match stmt.kind {
+            StmtKind::Expr(expr) | StmtKind::Semi(expr) => expr,
+            _ => return false,
+        },
+        (None, None) => return false,
+    };
+    matches!(
+        last.kind,
+        ExprKind::Ret(_) | ExprKind::Break(..) | ExprKind::Continue(_)
+    ) || cx.typeck_results().expr_ty(last).is_never()
+}
+
+/// Checks if the local is bound by value without `mut`, so it's never assigned to again.
+pub fn is_immutable_binding(cx: &LateContext<'_>, local_id: HirId) -> bool {
+    matches!(
+        cx.tcx.hir().find(local_id),
+        Some(Node::Pat(Pat {
+            kind: PatKind::Binding(BindingAnnotation::Unannotated, ..),
+            ..
+        }))
+    )
+}
+
+fn int_ty_bits(tcx: TyCtxt<'_>, ty: Ty<'_>) -> Option<u64> {
+    match *ty.kind() {
+        ty::Int(ity) => Some(int_bits(tcx, ity)),
+        ty::Uint(uty) => Some(Integer::from_uint_ty(&tcx, uty).size().bits()),
+        _ => None,
+    }
+}
+
+/// Returns the value with all bits set up to the highest set bit of the given non-negative value.
+fn mask(value: i128) -> i128 {
+    i128::MAX >> value.leading_zeros().saturating_sub(1)
+}
+
+/// Returns the operator with its operands swapped, i.e. `a < b` becomes `b > a`.
+fn flip(op: BinOpKind) -> BinOpKind {
+    match op {
+        BinOpKind::Lt => BinOpKind::Gt,
+        BinOpKind::Le => BinOpKind::Ge,
+        BinOpKind::Gt => BinOpKind::Lt,
+        BinOpKind::Ge => BinOpKind::Le,
+        op => op,
+    }
+}
+
+/// Returns the comparison which holds when the given one doesn't.
+fn negate(op: BinOpKind) -> Option<BinOpKind> {
+    match op {
+        BinOpKind::Lt => Some(BinOpKind::Ge),
+        BinOpKind::Le => Some(BinOpKind::Gt),
+        BinOpKind::Gt => Some(BinOpKind::Le),
+        BinOpKind::Ge => Some(BinOpKind::Lt),
+        BinOpKind::Eq => Some(BinOpKind::Ne),
+        BinOpKind::Ne => Some(BinOpKind::Eq),
+        _ => None,
+    }
+}
@@ -5,8 +5,8 @@ use rustc_hir as hir;
 use rustc_hir::def::{CtorKind, DefKind, Res};
 use rustc_hir::intravisit::{self, walk_block, walk_expr, Visitor};
 use rustc_hir::{
-    Arm, Block, BlockCheckMode, Body, BodyId, Expr, ExprKind, HirId, ItemId, ItemKind, Let, Pat, QPath, Stmt, UnOp,
-    UnsafeSource, Unsafety,
+    Arm, Block, BlockCheckMode, Body, BodyId, Expr, ExprKind, HirId, ItemId, Let, Pat, QPath, Stmt, UnOp, UnsafeSource,
+    Unsafety,
 };
 use rustc_lint::LateContext;
 use rustc_middle::hir::map::Map;
@@ -382,69 +382,130 @@ pub fn is_const_evaluatable<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) ->
     v.is_const
 }
 
-/// Checks if the given expression performs an unsafe operation outside of an unsafe block.
-pub fn is_expr_unsafe<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> bool {
-    struct V<'a, 'tcx> {
+/// The kind of an unsafe operation, see [`for_each_unsafe_op`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnsafeOp {
+    /// Dereferencing a raw pointer.
+    RawPtrDeref,
+    /// Calling an unsafe function, method or function pointer.
+    UnsafeCall,
+    /// Calling a function declared in an `extern` block.
+    ForeignCall,
+    /// Reading a field of a union.
+    UnionFieldAccess,
+    /// Accessing a `static mut`.
+    StaticMutAccess,
+    /// Accessing a static declared in an `extern` block.
+    ForeignStaticAccess,
+    /// Using inline assembly.
+    InlineAsm,
+}
+
+/// Calls the given function for each unsafe operation performed in the given node, along with the
+/// expression performing it. This enters closures, but neither nested items nor nested `unsafe`
+/// blocks.
+pub fn for_each_unsafe_op<'tcx, B>(
+    cx: &LateContext<'tcx>,
+    node: impl Visitable<'tcx>,
+    f: impl FnMut(UnsafeOp, &'tcx Expr<'tcx>) -> ControlFlow<B>,
+) -> Option<B> {
+    struct V<'a, 'tcx, B, F> {
         cx: &'a LateContext<'tcx>,
-        is_unsafe: bool,
+        typeck: &'tcx TypeckResults<'tcx>,
+        f: F,
+        res: Option<B>,
     }
-    impl<'tcx> Visitor<'tcx> for V<'_, 'tcx> {
-        type NestedFilter = nested_filter::OnlyBodies;
-        fn nested_visit_map(&mut self) -> Self::Map {
-            self.cx.tcx.hir()
-        }
-        fn visit_expr(&mut self, e: &'tcx Expr<'_>) {
-            if self.is_unsafe {
-                return;
-            }
+    impl<'tcx, B, F: FnMut(UnsafeOp, &'tcx Expr<'tcx>) -> ControlFlow<B>> V<'_, 'tcx, B, F> {
+        fn unsafe_op(&self, e: &'tcx Expr<'tcx>) -> Option<UnsafeOp> {
             match e.kind {
-                ExprKind::Unary(UnOp::Deref, e) if self.cx.typeck_results().expr_ty(e).is_unsafe_ptr() => {
-                    self.is_unsafe = true;
+                ExprKind::Unary(UnOp::Deref, e) if self.typeck.expr_ty(e).is_unsafe_ptr() => {
+                    Some(UnsafeOp::RawPtrDeref)
                 },
                 ExprKind::MethodCall(..)
                     if self
-                        .cx
-                        .typeck_results()
+                        .typeck
                         .type_dependent_def_id(e.hir_id)
                         .map_or(false, |id| self.cx.tcx.fn_sig(id).unsafety() == Unsafety::Unsafe) =>
                 {
-                    self.is_unsafe = true;
+                    Some(UnsafeOp::UnsafeCall)
                 },
-                ExprKind::Call(func, _) => match *self.cx.typeck_results().expr_ty(func).peel_refs().kind() {
-                    ty::FnDef(id, _) if self.cx.tcx.fn_sig(id).unsafety() == Unsafety::Unsafe => self.is_unsafe = true,
-                    ty::FnPtr(sig) if sig.unsafety() == Unsafety::Unsafe => self.is_unsafe = true,
-                    _ => walk_expr(self, e),
+                ExprKind::Call(func, _) => match *self.typeck.expr_ty(func).peel_refs().kind() {
+                    ty::FnDef(id, _) if self.cx.tcx.fn_sig(id).unsafety() == Unsafety::Unsafe => {
+                        if self.cx.tcx.is_foreign_item(id) {
+                            Some(UnsafeOp::ForeignCall)
+                        } else {
+                            Some(UnsafeOp::UnsafeCall)
+                        }
+                    },
+                    ty::FnPtr(sig) if sig.unsafety() == Unsafety::Unsafe => Some(UnsafeOp::UnsafeCall),
+                    _ => None,
                 },
-                ExprKind::Path(ref p)
-                    if self
-                        .cx
-                        .qpath_res(p, e.hir_id)
-                        .opt_def_id()
-                        .map_or(false, |id| self.cx.tcx.is_mutable_static(id)) =>
-                {
-                    self.is_unsafe = true;
+                ExprKind::Field(base, _) if self.typeck.expr_ty(base).peel_refs().is_union() => {
+                    Some(UnsafeOp::UnionFieldAccess)
+                },
+                ExprKind::Path(ref p) => match self.typeck.qpath_res(p, e.hir_id) {
+                    Res::Def(DefKind::Static(_), id) if self.cx.tcx.is_foreign_item(id) => {
+                        Some(UnsafeOp::ForeignStaticAccess)
+                    },
+                    Res::Def(DefKind::Static(_), id) if self.cx.tcx.is_mutable_static(id) => {
+                        Some(UnsafeOp::StaticMutAccess)
+                    },
+                    _ => None,
                 },
-                _ => walk_expr(self, e),
+                ExprKind::InlineAsm(_) => Some(UnsafeOp::InlineAsm),
+                _ => None,
             }
         }
-        fn visit_block(&mut self, b: &'tcx Block<'_>) {
-            if !matches!(b.rules, BlockCheckMode::UnsafeBlock(_)) {
-                walk_block(self, b);
+    }
+    impl<'tcx, B, F: FnMut(UnsafeOp, &'tcx Expr<'tcx>) -> ControlFlow<B>> Visitor<'tcx> for V<'_, 'tcx, B, F> {
+        type NestedFilter = nested_filter::OnlyBodies;
+        fn nested_visit_map(&mut self) -> Self::Map {
+            self.cx.tcx.hir()
+        }
+        fn visit_expr(&mut self, e: &'tcx Expr<'tcx>) {
+            if self.res.is_some() {
+                return;
+            }
+            if let Some(op) = self.unsafe_op(e)
+                && let ControlFlow::Break(b) = (self.f)(op, e)
+            {
+                self.res = Some(b);
+                return;
+            }
+            // Assigning to a field of a union is safe.
+            if let ExprKind::Assign(lhs, rhs, _) = e.kind
+                && let ExprKind::Field(base, _) = lhs.kind
+                && self.typeck.expr_ty(base).peel_refs().is_union()
+            {
+                self.visit_expr(base);
+                self.visit_expr(rhs);
+            } else {
+                walk_expr(self, e);
             }
         }
-        fn visit_nested_item(&mut self, id: ItemId) {
-            if let ItemKind::Impl(i) = &self.cx.tcx.hir().item(id).kind {
-                self.is_unsafe = i.unsafety == Unsafety::Unsafe;
+        fn visit_block(&mut self, b: &'tcx Block<'tcx>) {
+            if !matches!(b.rules, BlockCheckMode::UnsafeBlock(_)) {
+                walk_block(self, b);
             }
         }
     }
-    let mut v = V { cx, is_unsafe: false };
-    v.visit_expr(e);
-    v.is_unsafe
+    let mut v = V {
+        cx,
+        typeck: cx.typeck_results(),
+        f,
+        res: None,
+    };
+    node.visit(&mut v);
+    v.res
+}
+
+/// Checks if the given expression performs an unsafe operation outside of an unsafe block.
+pub fn is_expr_unsafe<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> bool {
+    for_each_unsafe_op(cx, e, |_, _| ControlFlow::Break(())).is_some()
 }
 
-/// Checks if the given expression contains an unsafe block
-pub fn contains_unsafe_block<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> bool {
+/// Checks if the given node contains an unsafe block
+pub fn contains_unsafe_block<'tcx>(cx: &LateContext<'tcx>, node: impl Visitable<'tcx>) -> bool {
     struct V<'cx, 'tcx> {
         cx: &'cx LateContext<'tcx>,
         found_unsafe: bool,
@@ -470,7 +531,7 @@ pub fn contains_unsafe_block<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>)
         cx,
         found_unsafe: false,
     };
-    v.visit_expr(e);
+    node.visit(&mut v);
     v.found_unsafe
 }
 
@@ -71,6 +71,15 @@ fn track_clippy_args(parse_sess: &mut ParseSess, args_env_var: &Option<String>)
     ));
 }
 
+/// Makes the reports dependencies of the crate. `cargo clippy` empties the reports before running
+/// cargo, so that cargo checks the crate again instead of skipping it as fresh.
+fn track_report_files(parse_sess: &mut ParseSess, paths: &[PathBuf]) {
+    let file_depinfo = parse_sess.file_depinfo.get_mut();
+    for path in paths {
+        file_depinfo.insert(Symbol::intern(&path.to_string_lossy()));
+    }
+}
+
 struct DefaultCallbacks;
 impl rustc_driver::Callbacks for DefaultCallbacks {}
 
@@ -91,14 +100,20 @@ impl rustc_driver::Callbacks for RustcCallbacks {
 
 struct ClippyCallbacks {
     clippy_args_var: Option<String>,
+    report_suppressions: Option<PathBuf>,
+    report_unsafe: Option<PathBuf>,
 }
 
 impl rustc_driver::Callbacks for ClippyCallbacks {
     fn config(&mut self, config: &mut interface::Config) {
         let previous = config.register_lints.take();
         let clippy_args_var = self.clippy_args_var.take();
+        let report_suppressions = self.report_suppressions.take();
+        let report_unsafe = self.report_unsafe.take();
+        let report_paths: Vec<PathBuf> = report_suppressions.iter().chain(&report_unsafe).cloned().collect();
         config.parse_sess_created = Some(Box::new(move |parse_sess| {
             track_clippy_args(parse_sess, &clippy_args_var);
+            track_report_files(parse_sess, &report_paths);
         }));
         config.register_lints = Some(Box::new(move |sess, lint_store| {
             // technically we're ~guaranteed that this is none but might as well call anything that
@@ -111,6 +126,12 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
             clippy_lints::register_plugins(lint_store, sess, &conf);
             clippy_lints::register_pre_expansion_lints(lint_store, sess, &conf);
             clippy_lints::register_renamed(lint_store);
+            if let Some(path) = &report_suppressions {
+                clippy_lints::register_suppression_report(lint_store, &conf, path.clone());
+            }
+            if let Some(path) = &report_unsafe {
+                clippy_lints::register_unsafe_report(lint_store, path.clone());
+            }
         }));
 
         // FIXME: #4825; This is required, because Clippy lints that are based on MIR have to be
@@ -132,6 +153,10 @@ Usage:
 Common options:
     -h, --help               Print this message
         --rustc              Pass all args to rustc
+        --report-suppressions=<FILE>
+                             Append all suppressed Clippy lints of the crate to FILE as JSON
+        --report-unsafe=<FILE>
+                             Append all unsafe code of the crate to FILE as JSON
     -V, --version            Print version info and exit
 
 Other options are the same as `cargo check`.
@@ -315,6 +340,8 @@ pub fn main() {
         };
 
         let mut no_deps = false;
+        let mut report_suppressions = None;
+        let mut report_unsafe = None;
         let clippy_args_var = env::var("CLIPPY_ARGS").ok();
         let clippy_args = clippy_args_var
             .as_deref()
@@ -326,7 +353,17 @@ pub fn main() {
                     no_deps = true;
                     None
                 },
-                _ => Some(s.to_string()),
+                _ => {
+                    if let Some(path) = s.strip_prefix("--report-suppressions=") {
+                        report_suppressions = Some(PathBuf::from(path));
+                        None
+                    } else if let Some(path) = s.strip_prefix("--report-unsafe=") {
+                        report_unsafe = Some(PathBuf::from(path));
+                        None
+                    } else {
+                        Some(s.to_string())
+                    }
+                },
             })
             .chain(vec!["--cfg".into(), r#"feature="cargo-clippy""#.into()])
             .collect::<Vec<String>>();
@@ -343,7 +380,15 @@ pub fn main() {
         let clippy_enabled = !cap_lints_allow && (!no_deps || in_primary_package);
         if clippy_enabled {
             args.extend(clippy_args);
-            rustc_driver::RunCompiler::new(&args, &mut ClippyCallbacks { clippy_args_var }).run()
+            rustc_driver::RunCompiler::new(
+                &args,
+                &mut ClippyCallbacks {
+                    clippy_args_var,
+                    report_suppressions,
+                    report_unsafe,
+                },
+            )
+            .run()
         } else {
             rustc_driver::RunCompiler::new(&args, &mut RustcCallbacks { clippy_args_var }).run()
         }
@@ -4,6 +4,7 @@
 
 use rustc_tools_util::VersionInfo;
 use std::env;
+use std::fs;
 use std::path::PathBuf;
 use std::process::{self, Command};
 
@@ -15,6 +16,12 @@ Usage:
 Common options:
     --no-deps                Run Clippy only on the given crate, without linting the dependencies
     --fix                    Automatically apply lint suggestions. This flag implies `--no-deps`
+    --report-suppressions=<FILE>
+                             Write all suppressed Clippy lints of each checked crate to FILE as JSON, one
+                             line per crate
+    --report-unsafe=<FILE>   Write all unsafe blocks, functions, impls and traits of each checked crate,
+                             with their safety comments and unsafe operations, to FILE as JSON, one
+                             line per crate
     -h, --help               Print this message
     -V, --version            Print version info and exit
 
@@ -63,6 +70,9 @@ struct ClippyCmd {
     cargo_subcommand: &'static str,
     args: Vec<String>,
     clippy_args: Vec<String>,
+    /// The files the reports are written to, which are emptied before running cargo. This also
+    /// makes cargo check the crates writing to them again, see `track_report_files` in the driver.
+    report_paths: Vec<PathBuf>,
 }
 
 impl ClippyCmd {
@@ -73,8 +83,19 @@ impl ClippyCmd {
         let mut cargo_subcommand = "check";
         let mut args = vec![];
         let mut clippy_args: Vec<String> = vec![];
+        let mut report_paths = vec![];
 
         for arg in old_args.by_ref() {
+            if let Some((option, path)) = arg
+                .split_once('=')
+                .filter(|(option, _)| matches!(*option, "--report-suppressions" | "--report-unsafe"))
+            {
+                // The path is passed to `clippy-driver`, which may run in another directory.
+                let path = env::current_dir().map_or_else(|_| PathBuf::from(path), |dir| dir.join(path));
+                clippy_args.push(format!("{}={}", option, path.display()));
+                report_paths.push(path);
+                continue;
+            }
             match arg.as_str() {
                 "--fix" => {
                     cargo_subcommand = "fix";
@@ -100,6 +121,7 @@ impl ClippyCmd {
             cargo_subcommand,
             args,
             clippy_args,
+            report_paths,
         }
     }
 
@@ -142,6 +164,13 @@ where
 {
     let cmd = ClippyCmd::new(old_args);
 
+    for path in &cmd.report_paths {
+        if let Err(e) = fs::File::create(path) {
+            eprintln!("error: failed to create the report file `{}`: {}", path.display(), e);
+            return Err(1);
+        }
+    }
+
     let mut cmd = cmd.into_std_cmd();
 
     let exit_status = cmd
@@ -185,6 +214,38 @@ mod tests {
         assert_eq!(cmd.clippy_args.iter().filter(|arg| *arg == "--no-deps").count(), 1);
     }
 
+    #[test]
+    fn report_suppressions() {
+        let args = "cargo clippy --report-suppressions=report.json -p example"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        let path = std::env::current_dir().unwrap().join("report.json");
+        assert!(
+            cmd.clippy_args
+                .iter()
+                .any(|arg| *arg == format!("--report-suppressions={}", path.display()))
+        );
+        assert_eq!(cmd.report_paths, [path]);
+        assert!(!cmd.args.iter().any(|arg| arg.starts_with("--report-suppressions")));
+    }
+
+    #[test]
+    fn report_unsafe() {
+        let args = "cargo clippy --report-unsafe=unsafe.json"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        let path = std::env::current_dir().unwrap().join("unsafe.json");
+        assert!(
+            cmd.clippy_args
+                .iter()
+                .any(|arg| *arg == format!("--report-unsafe={}", path.display()))
+        );
+        assert_eq!(cmd.report_paths, [path]);
+        assert!(!cmd.args.iter().any(|arg| arg.starts_with("--report-unsafe")));
+    }
+
     #[test]
     fn check() {
         let args = "cargo clippy".split_whitespace().map(ToString::to_string);
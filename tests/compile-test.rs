@@ -26,15 +26,18 @@ static TEST_DEPENDENCIES: &[&str] = &[
     "clippy_lints",
     "clippy_utils",
     "derive_new",
+    "fancy_regex",
     "futures",
     "if_chain",
     "itertools",
+    "log",
     "quote",
     "regex",
     "serde",
     "serde_derive",
     "syn",
     "tokio",
+    "tracing",
     "parking_lot",
     "rustc_semver",
 ];
@@ -48,12 +51,16 @@ extern crate clippy_utils;
 #[allow(unused_extern_crates)]
 extern crate derive_new;
 #[allow(unused_extern_crates)]
+extern crate fancy_regex;
+#[allow(unused_extern_crates)]
 extern crate futures;
 #[allow(unused_extern_crates)]
 extern crate if_chain;
 #[allow(unused_extern_crates)]
 extern crate itertools;
 #[allow(unused_extern_crates)]
+extern crate log;
+#[allow(unused_extern_crates)]
 extern crate parking_lot;
 #[allow(unused_extern_crates)]
 extern crate quote;
@@ -63,6 +70,8 @@ extern crate rustc_semver;
 extern crate syn;
 #[allow(unused_extern_crates)]
 extern crate tokio;
+#[allow(unused_extern_crates)]
+extern crate tracing;
 
 /// Produces a string with an `--extern` flag for all UI test crate
 /// dependencies.
@@ -449,8 +458,14 @@ fn rustfix_coverage_known_exceptions_accuracy() {
 fn ui_cargo_toml_metadata() {
     let ui_cargo_path = Path::new("tests/ui-cargo");
     let cargo_common_metadata_path = ui_cargo_path.join("cargo_common_metadata");
-    let publish_exceptions =
-        ["fail_publish", "fail_publish_true", "pass_publish_empty"].map(|path| cargo_common_metadata_path.join(path));
+    let publish_exceptions = [
+        "fail_publish",
+        "fail_publish_true",
+        "pass_publish_empty",
+        "fail_custom",
+        "pass_custom",
+    ]
+    .map(|path| cargo_common_metadata_path.join(path));
 
     for entry in walkdir::WalkDir::new(ui_cargo_path) {
         let entry = entry.unwrap();
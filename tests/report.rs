@@ -0,0 +1,59 @@
+#![feature(once_cell)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use test_utils::{CARGO_CLIPPY_PATH, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+/// Runs `cargo clippy` on `tests/report_test` with the given report option twice, and compares
+/// the written reports to the expected one in `tests/report_test`. The second run checks that
+/// the crate isn't skipped as fresh, which would leave the report empty.
+fn check_report(option: &str, expected: &str) {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // Each report gets its own target directory, as the tests may run in parallel
+    let target_dir = root.join("target/report_test").join(option.trim_start_matches('-'));
+    let cwd = root.join("tests/report_test");
+    let report = target_dir.join(expected);
+    let expected = fs::read_to_string(cwd.join(expected)).unwrap();
+    fs::create_dir_all(&target_dir).unwrap();
+
+    for _ in 0..2 {
+        run_clippy(&cwd, &target_dir, &format!("{}={}", option, report.display()));
+        let actual = fs::read_to_string(&report).unwrap();
+        assert_eq!(actual, expected);
+    }
+}
+
+fn run_clippy(cwd: &Path, target_dir: &Path, option: &str) {
+    let output = Command::new(&*CARGO_CLIPPY_PATH)
+        .current_dir(cwd)
+        .env("CARGO_INCREMENTAL", "0")
+        .env("CARGO_TARGET_DIR", target_dir)
+        .arg("clippy")
+        .arg(option)
+        .args(&["--", "-A", "clippy::similar_names"])
+        .output()
+        .unwrap();
+    println!("status: {}", output.status);
+    println!("stdout: {}", String::from_utf8_lossy(&output.stdout));
+    println!("stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(output.status.success());
+}
+
+#[test]
+fn report_suppressions() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    check_report("--report-suppressions", "suppressions.json");
+}
+
+#[test]
+fn report_unsafe() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    check_report("--report-unsafe", "unsafe.json");
+}
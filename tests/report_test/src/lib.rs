@@ -0,0 +1,50 @@
+#![feature(lint_reasons)]
+#![allow(clippy::module_name_repetitions)]
+
+#[allow(clippy::needless_return, clippy::style)]
+pub fn early_return() -> u32 {
+    return 1;
+}
+
+pub mod nested {
+    #[expect(clippy::needless_return, reason = "kept for the example")]
+    pub fn early_return() -> u32 {
+        return 2;
+    }
+}
+
+#[allow(dead_code)]
+fn not_a_clippy_lint() {}
+
+/// # Safety
+///
+/// `ptr` must be valid for reads.
+pub unsafe fn read(ptr: *const u32) -> u32 {
+    *ptr
+}
+
+pub fn read_first(values: &[u32]) -> u32 {
+    assert!(!values.is_empty());
+    // SAFETY: `values` is not empty
+    unsafe { read(values.as_ptr()) }
+}
+
+pub union Bits {
+    pub int: u32,
+    pub float: f32,
+}
+
+pub fn to_bits(value: f32) -> u32 {
+    let bits = Bits { float: value };
+    unsafe { bits.int }
+}
+
+pub struct Token;
+
+// SAFETY: `Token` holds no data
+unsafe impl Send for Token {}
+
+/// # Safety
+///
+/// Implementors must be plain old data.
+pub unsafe trait Pod {}
@@ -0,0 +1,4 @@
+// compile-flags: --crate-name=disallowed_dependencies
+#![warn(clippy::disallowed_dependencies)]
+
+fn main() {}
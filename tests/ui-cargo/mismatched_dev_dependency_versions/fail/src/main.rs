@@ -0,0 +1,4 @@
+// compile-flags: --crate-name=mismatched_dev_dependency_versions
+#![warn(clippy::mismatched_dev_dependency_versions)]
+
+fn main() {}
@@ -0,0 +1,4 @@
+// compile-flags: --crate-name=missing_workspace_inheritance
+#![warn(clippy::missing_workspace_inheritance)]
+
+fn main() {}
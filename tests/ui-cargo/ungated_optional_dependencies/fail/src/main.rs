@@ -0,0 +1,4 @@
+// compile-flags: --crate-name=ungated_optional_dependencies
+#![warn(clippy::ungated_optional_dependencies)]
+
+fn main() {}
@@ -0,0 +1,6 @@
+// compile-flags: --crate-name=unused_dependencies
+#![warn(clippy::unused_dependencies)]
+
+fn main() {
+    let _ = regex::Regex::new("a+");
+}
@@ -0,0 +1,3 @@
+fn main() {
+    let _ = regex::Regex::new("a+");
+}
@@ -0,0 +1,10 @@
+// compile-flags: --crate-name=unused_dependencies --crate-type=lib
+#![warn(clippy::unused_dependencies)]
+
+// `regex` is only used by the binary
+
+use itertools::Itertools;
+
+pub fn join(values: &[u32]) -> String {
+    values.iter().join(", ")
+}
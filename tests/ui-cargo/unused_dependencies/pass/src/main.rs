@@ -0,0 +1,9 @@
+// compile-flags: --crate-name=unused_dependencies
+#![warn(clippy::unused_dependencies)]
+
+use itertools::Itertools;
+
+fn main() {
+    let _ = regex::Regex::new("a+");
+    let _ = [1, 2].iter().join(", ");
+}
@@ -0,0 +1,27 @@
+// edition:2018
+#![warn(clippy::blocking_in_async)]
+
+pub fn wait() {}
+
+pub async fn wait_async() {}
+
+pub fn block() {}
+
+pub fn spawn_with<R>(f: impl FnOnce() -> R) -> R {
+    f()
+}
+
+pub async fn call_blocking() {
+    wait();
+    block();
+    // ok, not configured
+    std::thread::sleep(std::time::Duration::from_millis(10));
+}
+
+pub fn call_blocking_in_spawned_closure() {
+    spawn_with(|| {
+        wait();
+    });
+}
+
+fn main() {}
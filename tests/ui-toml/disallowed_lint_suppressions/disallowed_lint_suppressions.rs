@@ -0,0 +1,38 @@
+// rustc-env:__CLIPPY_INTERNAL_TODAY=2022-07-20
+#![feature(lint_reasons)]
+#![warn(clippy::disallowed_lint_suppressions)]
+
+#[allow(clippy::needless_return, reason = "until=2000-01-01 ticket=ABC-1")]
+fn expired() {}
+
+#[allow(clippy::needless_return, reason = "until=9999-12-31 ticket=ABC-2")]
+fn not_expired() {}
+
+#[allow(clippy::needless_return, reason = "until=2022-07-19 ticket=ABC-1")]
+fn expired_yesterday() {}
+
+#[allow(clippy::needless_return, reason = "until=2022-07-20 ticket=ABC-2")]
+fn expires_today() {}
+
+#[allow(clippy::needless_return, reason = "until=9999-13-01 ticket=ABC-3")]
+fn invalid_date() {}
+
+#[allow(clippy::needless_return, reason = "until=2023-04-31 ticket=ABC-3")]
+fn invalid_day() {}
+
+#[allow(clippy::needless_return, reason = "until=2023-02-29 ticket=ABC-3")]
+fn invalid_leap_day() {}
+
+#[allow(clippy::needless_return, reason = "until=2024-02-29 ticket=ABC-3")]
+fn leap_day() {}
+
+#[allow(clippy::needless_return, reason = "until=9999-12-31, see the tracking issue")]
+fn missing_ticket() {}
+
+#[allow(clippy::needless_return)]
+fn missing_reason() {}
+
+#[allow(clippy::unwrap_used, reason = "ticket=ABC-4")]
+fn non_suppressible() {}
+
+fn main() {}
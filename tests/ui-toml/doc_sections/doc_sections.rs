@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+#![warn(clippy::missing_doc_sections)]
+
+/// Parses the number.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(parse("1"), Ok(1));
+/// ```
+///
+/// # Errors
+///
+/// Fails if the string isn't a number.
+pub fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    s.parse()
+}
+
+/// Reads the next message.
+pub async fn next_message() {}
+
+/// Reads the next message.
+///
+/// # Cancel Safety
+///
+/// No message is lost if the future is dropped.
+///
+/// # Examples
+///
+/// ```ignore
+/// // needs a running server
+/// next_message_with_examples().await;
+/// ```
+pub async fn next_message_with_examples() {}
+
+/// A marker for types which can be sent to the server.
+///
+/// # Safety
+///
+/// The type must be `Send`.
+pub unsafe trait Message {}
+
+/// A private struct doesn't need any sections.
+struct Private;
+
+fn main() {}
@@ -0,0 +1,10 @@
+#![allow(dead_code)]
+#![warn(clippy::doc_spelling)]
+
+/// Splits the input, see the frobnicator for details. Every Rustacean knows it.
+fn split() {}
+
+/// Words missing from the dictionary are still checked, like recieve.
+fn receive() {}
+
+fn main() {}
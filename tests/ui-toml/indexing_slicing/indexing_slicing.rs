@@ -0,0 +1,83 @@
+#![warn(clippy::indexing_slicing)]
+#![allow(clippy::needless_range_loop)]
+
+pub fn guard(v: &[u8], i: usize) -> u8 {
+    if i < v.len() { v[i] } else { 0 }
+}
+
+pub fn negated_guard(v: &[u8], i: usize, j: usize) -> u8 {
+    if i >= v.len() || v.len() <= j {
+        return 0;
+    }
+    v[i] + v[j]
+}
+
+pub fn assert(v: &[u8], n: usize) -> &[u8] {
+    assert!(v.len() >= n);
+    &v[..n]
+}
+
+pub fn constants(v: &[u8]) -> (u8, &[u8]) {
+    assert!(v.len() > 3);
+    (v[3], &v[1..4])
+}
+
+pub fn not_empty(v: &[u8]) -> u8 {
+    if !v.is_empty() { v[0] } else { 0 }
+}
+
+pub fn for_range(v: &[u8]) -> u32 {
+    let mut sum = 0;
+    for i in 0..v.len() {
+        sum += u32::from(v[i]);
+    }
+    sum
+}
+
+pub fn enumerate(v: &[u8]) -> u8 {
+    let mut max = 0;
+    for (i, _) in v.iter().enumerate() {
+        max = max.max(v[i]);
+    }
+    max
+}
+
+pub fn chunks(v: &[u8]) -> u32 {
+    let mut sum = 0;
+    for chunk in v.chunks_exact(4) {
+        sum += u32::from(chunk[0]) + u32::from(chunk[3]);
+    }
+    sum
+}
+
+pub fn unchecked(v: &[u8], i: usize) -> u8 {
+    // Still lint these
+    if i <= v.len() {
+        return v[i];
+    }
+    assert!(v.len() > 2);
+    v[3]
+}
+
+pub fn mutable(mut v: Vec<u8>, i: usize) -> u8 {
+    if i < v.len() {
+        v.clear();
+        return v[i];
+    }
+    0
+}
+
+pub fn chunks_too_small(v: &[u8]) -> u8 {
+    let mut max = 0;
+    for chunk in v.chunks_exact(2) {
+        max = max.max(chunk[2]);
+    }
+    max
+}
+
+pub fn string(s: &str, i: usize) -> &str {
+    // The index may not be on a `char` boundary
+    if i < s.len() { &s[..i] } else { s }
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+// edition:2018
+#![warn(clippy::large_futures)]
+
+use std::future::Future;
+
+fn spawn<F: Future>(_future: F) {}
+
+async fn fut(_arg: [u8; 1024]) {}
+
+async fn small_fut(_arg: [u8; 512]) {}
+
+// exactly at the threshold
+async fn max_fut(_arg: [u8; 1023]) {}
+
+pub async fn awaited() {
+    fut([0u8; 1024]).await;
+    small_fut([0u8; 512]).await;
+    max_fut([0u8; 1023]).await;
+}
+
+pub fn spawned() {
+    spawn(fut([0u8; 1024]));
+    spawn(small_fut([0u8; 512]));
+    spawn(Box::pin(fut([0u8; 1024])));
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![warn(clippy::panic_in_no_panic_fn)]
+
+fn configured(x: Option<u32>) -> u32 {
+    x.expect("no value")
+}
+
+fn not_configured(x: Option<u32>) -> u32 {
+    x.expect("no value")
+}
+
+fn main() {
+    configured(Some(1));
+    not_configured(Some(1));
+}
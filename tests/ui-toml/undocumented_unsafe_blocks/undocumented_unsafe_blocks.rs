@@ -0,0 +1,37 @@
+#![warn(clippy::undocumented_unsafe_blocks)]
+#![allow(clippy::missing_safety_doc)]
+
+static mut COUNTER: u32 = 0;
+
+unsafe fn read(p: *const u32) -> u32 {
+    // SAFETY: the caller guarantees `p` is valid for reads
+    *p
+}
+
+struct Wrapper {
+    ptr: *const u32,
+}
+
+fn main() {
+    let x = 42u32;
+    let p = &x as *const u32;
+    let w = Wrapper { ptr: p };
+
+    // SAFETY: `p` points to `x`
+    let _ = unsafe { *p };
+
+    // SAFETY: `p` points to `x`
+    let _ = unsafe { *p + read(p) };
+
+    // SAFETY: `read` is called with `p`, which points to `x`
+    let _ = unsafe { read(p) + *w.ptr };
+
+    // SAFETY: `ptr` points to `x`,
+    // and `COUNTER` is only accessed from the main thread
+    unsafe {
+        COUNTER += *w.ptr;
+    }
+
+    // SAFETY: `pp` and `p2` are not mentions of the pointer
+    let _ = unsafe { *p };
+}
@@ -0,0 +1,47 @@
+// edition:2018
+#![warn(clippy::blocking_in_async)]
+#![allow(clippy::manual_async_fn)]
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+pub async fn sleep_in_async_fn() {
+    std::thread::sleep(Duration::from_millis(10));
+}
+
+pub async fn read_in_async_fn() -> std::io::Result<String> {
+    std::fs::read_to_string("config.toml")
+}
+
+pub fn sleep_in_async_block() -> impl std::future::Future<Output = ()> {
+    async {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+pub async fn lock_in_async_fn(mutex: &Mutex<u32>) -> u32 {
+    *mutex.lock().unwrap()
+}
+
+pub async fn read_in_closure(names: &[&str]) -> Vec<std::io::Result<Vec<u8>>> {
+    names
+        .iter()
+        .map(|name| std::fs::read(format!("{}.toml", name)))
+        .collect()
+}
+
+pub async fn join_in_async_fn(handle: std::thread::JoinHandle<()>) {
+    handle.join().unwrap();
+}
+
+// ok, the blocking call runs on another thread
+pub async fn sleep_on_thread() {
+    std::thread::spawn(|| std::thread::sleep(Duration::from_millis(10)));
+}
+
+// ok, not in an async context
+pub fn sleep_in_sync_fn() {
+    std::thread::sleep(Duration::from_millis(10));
+}
+
+fn main() {}
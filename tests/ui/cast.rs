@@ -260,3 +260,26 @@ fn avoid_subtract_overflow(q: u32) {
     let c = (q / 1000) as u8;
     c as usize;
 }
+
+pub fn value_range(x: u32, y: i32, arr: [u8; 4]) {
+    // Don't lint, the value provably fits
+    x.min(255) as u8;
+    x.clamp(10, 20) as u8;
+    (x % 200) as u8;
+    if x < 256 {
+        x as u8;
+    }
+    arr.len() as u8;
+    y.max(0) as u32;
+    if y >= 0 {
+        y as u32;
+    }
+    let z = y.clamp(-100, 100);
+    (z / 2) as i8;
+
+    // Should still be linted
+    if x <= 256 {
+        x as u8;
+    }
+    y.min(0) as u32;
+}
@@ -0,0 +1,86 @@
+#![allow(dead_code)]
+#![warn(clippy::doc_unresolved_links, clippy::doc_links_to_private_items)]
+
+pub mod shapes {
+    /// A circle, see also [`Square`] and [`super::Polygon`].
+    pub struct Circle {
+        pub radius: f64,
+    }
+
+    /// A square, see also [`Circle::radius`] and [`Circle::area`].
+    pub struct Square;
+
+    impl Circle {
+        /// Computes the area, using [`Self::radius`].
+        pub fn area(&self) -> f64 {
+            self.radius * self.radius * std::f64::consts::PI
+        }
+    }
+}
+
+/// A polygon, see also [`shapes::Triangle`] and [`Polgon::sides`].
+pub struct Polygon {
+    sides: usize,
+}
+
+/// Links to [`Helper`], which isn't exported.
+pub fn public() {}
+
+/// Links to [`Helper`], but this function is private too.
+fn private() {}
+
+struct Helper;
+
+/// Links to items outside the crate: [`Vec`], [`std::collections::HashMap`], [`Option::map`],
+/// [`str`], [`u32::MAX`], [`Clone::clone`] and [`Iterator::next()`].
+pub fn outside() {}
+
+/// Links with disambiguators and anchors: [struct@Polygon], [`fn@public`], [`Polygon#fields`]
+/// and [the crate root](crate).
+pub fn disambiguated() {}
+
+/// Links which aren't intra-doc links: [rust](https://www.rust-lang.org), [some text] and
+/// [`T`], which is a generic parameter.
+pub fn not_intra_doc<T>(_: T) {}
+
+/// Link to a missing item: [`crate::nonexistent`].
+pub fn missing() {}
+
+pub trait Describe {
+    fn describe(&self) -> String;
+}
+
+impl<T: std::fmt::Debug> Describe for T {
+    fn describe(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// Links to methods of blanket implementations: [`Polygon::describe`] and [`Polygon::clone_into`],
+/// but [`Polygon::undescribed`] doesn't exist.
+pub fn blanket() {}
+
+pub struct Wrapper<T>(T);
+
+impl<T> Wrapper<T> {
+    /// Returns the wrapped [`T`], see also [`Wrapper::get`].
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Returns a reference to the wrapped [`T`] or [`U`], which is unknown.
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+}
+
+/// Links to macros: [`vec!`], [`format!`], [`assert_eq!`], [`macro@println`], [`local_macro!`]
+/// and [`missing_macro!`].
+pub fn macros() {}
+
+#[macro_export]
+macro_rules! local_macro {
+    () => {};
+}
+
+fn main() {}
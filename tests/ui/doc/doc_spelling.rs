@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+#![warn(clippy::doc_spelling)]
+
+/// Returns the lenght of the message.
+fn length() {}
+
+/// Teh message is sent when it's ready, which may never happen.
+fn send() {}
+
+/// Splits the input, see the frobnicator for details.
+fn split() {}
+
+/// Code like `recieve()`, identifiers like `foo_bar`, GitHub and HTTP, paths like std::vec::Vec
+/// and URLs like <https://www.rust-lang.org> aren't checked.
+///
+/// ```
+/// let recieve = 1;
+/// ```
+fn skipped() {}
+
+/// The crate's and crates' forms, as well as hyphenated words like well-known, are fine.
+fn possessives() {}
+
+fn main() {}
@@ -0,0 +1,35 @@
+#![allow(dead_code)]
+#![warn(clippy::doctest_attributes_without_reason)]
+
+/// ```ignore
+/// connect("localhost");
+/// ```
+fn ignored() {}
+
+/// ```no_run
+/// loop {}
+/// ```
+fn not_run() {}
+
+/// ```rust,ignore-windows
+/// ```
+fn empty() {}
+
+/// ```ignore
+/// // needs a running server
+/// connect("localhost");
+/// ```
+fn ignored_with_reason() {}
+
+/// ```no_run
+/// # // runs forever
+/// loop {}
+/// ```
+fn not_run_with_hidden_reason() {}
+
+/// ```should_panic
+/// panic!();
+/// ```
+fn should_panic() {}
+
+fn main() {}
@@ -0,0 +1,92 @@
+#![allow(dead_code)]
+#![warn(clippy::unnecessary_doc_sections)]
+
+/// Returns the first element.
+///
+/// # Panics
+///
+/// Panics if the slice is empty.
+pub fn first(values: &[u32]) -> Option<u32> {
+    values.first().copied()
+}
+
+/// Returns the first element.
+///
+/// # Panics
+///
+/// Panics if the slice is empty.
+pub fn first_or_panic(values: &[u32]) -> u32 {
+    values.first().copied().unwrap()
+}
+
+/// Returns the first element.
+///
+/// # Panics
+///
+/// Panics if the slice is empty.
+pub fn first_or_expect(values: &[u32]) -> u32 {
+    values.first().copied().expect("empty slice")
+}
+
+/// Returns the element at the index.
+///
+/// # Panics
+///
+/// Panics if the index is out of bounds.
+pub fn get(values: &[u32], i: usize) -> u32 {
+    values[i]
+}
+
+/// Returns the average.
+///
+/// # Panics
+///
+/// Panics if `count` is zero.
+pub fn average(sum: u32, count: u32) -> u32 {
+    sum / count
+}
+
+/// Parses the number.
+///
+/// # Errors
+///
+/// Returns `None` if the string isn't a number.
+pub fn parse(s: &str) -> Option<u32> {
+    s.parse().ok()
+}
+
+/// Parses the number.
+///
+/// # Errors
+///
+/// Fails if the string isn't a number.
+pub async fn parse_async(s: &str) -> Result<u32, std::num::ParseIntError> {
+    s.parse()
+}
+
+/// Reads the value.
+///
+/// # Safety
+///
+/// The value is always valid.
+pub fn read(value: &u32) -> u32 {
+    *value
+}
+
+/// Reads the value.
+///
+/// # Safety
+///
+/// The pointer must be valid.
+pub unsafe fn read_unchecked(ptr: *const u32) -> u32 {
+    *ptr
+}
+
+/// Private functions aren't checked.
+///
+/// # Panics
+///
+/// Never.
+fn private() {}
+
+fn main() {}
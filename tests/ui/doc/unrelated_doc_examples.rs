@@ -0,0 +1,72 @@
+#![allow(dead_code)]
+#![warn(clippy::unrelated_doc_examples)]
+
+/// Doubles the number.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(triple(2), 6);
+/// # fn triple(x: i32) -> i32 { x * 3 }
+/// ```
+pub fn double(x: i32) -> i32 {
+    x * 2
+}
+
+/// Triples the number.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(triple(2), 6);
+/// # fn triple(x: i32) -> i32 { x * 3 }
+/// ```
+pub fn triple(x: i32) -> i32 {
+    x * 3
+}
+
+/// A counter.
+///
+/// # Example
+///
+/// ```
+/// let counter = CounterBuilder::new().build();
+/// ```
+pub struct Counter;
+
+/// A counter builder.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// let builder = CounterBuilder::new();
+/// ```
+///
+/// # Panics
+///
+/// ```
+/// let counter = Counter;
+/// ```
+pub struct CounterBuilder;
+
+/// The name must be a whole word.
+///
+/// # Examples
+///
+/// ```
+/// let x = parse_all("1 2 3");
+/// ```
+pub fn parse(_: &str) {}
+
+/// Examples without Rust code aren't checked.
+///
+/// # Examples
+///
+/// ```text
+/// $ cargo run
+/// ```
+pub fn no_rust_code() {}
+
+fn main() {}
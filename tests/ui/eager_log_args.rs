@@ -0,0 +1,80 @@
+#![warn(clippy::eager_log_args)]
+
+use log::{debug, log_enabled, Level};
+use std::collections::HashMap;
+
+struct Request {
+    path: String,
+}
+
+impl Request {
+    fn summarize(&self) -> String {
+        format!("request to {}", self.path)
+    }
+
+    fn len(&self) -> usize {
+        self.path.len()
+    }
+}
+
+fn eager(request: &Request) {
+    let summary = request.summarize();
+    debug!("received {}", summary);
+
+    let size = format!("{} bytes", request.len());
+    tracing::debug!(size = %size, "received");
+
+    let logged_twice = request.summarize();
+    debug!("received {}", logged_twice);
+    debug!("queued {}", logged_twice);
+}
+
+fn not_eager(request: &Request) {
+    // used outside of logging macros
+    let summary = request.summarize();
+    debug!("received {}", summary);
+    println!("{}", summary);
+
+    // `Copy` values
+    let len = request.len();
+    debug!("received {} bytes", len);
+
+    // not computed
+    let path = &request.path;
+    debug!("received {}", path);
+
+    // not used
+    let _summary = request.summarize();
+
+    // the removal has to happen even when nothing is logged
+    let mut cache: HashMap<String, String> = HashMap::new();
+    let removed = cache.remove(&request.path);
+    debug!("evicted {:?}", removed);
+
+    if log_enabled!(Level::Debug) {
+        let summary = request.summarize();
+        debug!("received {}", summary);
+    }
+
+    if tracing::enabled!(tracing::Level::DEBUG) {
+        let summary = request.summarize();
+        tracing::debug!("received {}", summary);
+    }
+}
+
+fn early_return(request: &Request) {
+    if !log_enabled!(Level::Debug) {
+        return;
+    }
+    let summary = request.summarize();
+    debug!("received {}", summary);
+}
+
+fn main() {
+    let request = Request {
+        path: String::from("/"),
+    };
+    eager(&request);
+    not_eager(&request);
+    early_return(&request);
+}
@@ -64,6 +64,12 @@ where
 
 async fn unclear_future<T>(t: T) {}
 
+pub async fn param_and_local(rc: Rc<u8>) {
+    let pair = (Rc::new(1u16), Rc::new(2u32));
+    async {}.await;
+    drop((rc, pair));
+}
+
 fn main() {
     let rc = Rc::new([1, 2, 3]);
     private_future(rc.clone(), &Cell::new(42));
@@ -0,0 +1,40 @@
+#![warn(clippy::inconsistent_tracing_field_names)]
+
+use tracing::{event, info, warn, Level};
+
+fn login(id: u64, addr: &str) {
+    info!(user_id = id, %addr, "logged in");
+}
+
+fn logout(id: u64, addr: &str) {
+    info!(userId = id, ?addr, "logged out");
+}
+
+fn failure(id: u64, addr: &str) {
+    warn!(target: "auth", remote_addr = addr, user.id = id, "login failed");
+    event!(Level::ERROR, userid = id, "locked out");
+    event!(target: "auth", Level::WARN, user_id = id, "retrying {}", "userId");
+}
+
+#[allow(non_snake_case)]
+fn reconnect(remoteAddr: &str) {
+    // shorthands are pointed at
+    warn!(%remoteAddr, "reconnecting");
+}
+
+mod admin {
+    use tracing::info;
+
+    // fields are compared within a module
+    pub fn login(id: u64) {
+        info!(userId = id, "admin logged in");
+    }
+}
+
+fn main() {
+    login(1, "::1");
+    logout(1, "::1");
+    failure(1, "::1");
+    reconnect("::1");
+    admin::login(0);
+}
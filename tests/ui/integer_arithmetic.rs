@@ -12,7 +12,7 @@ fn main() {
     i / 2; // no error, this is part of the expression in the preceding line
     i - 2 + 2 - i;
     -i;
-    i >> 1;
+    i >> 1; // no error, can't overflow
     i << 1;
 
     // no error, overflows are checked by `overflowing_literals`
@@ -37,7 +37,7 @@ fn main() {
     i %= var1;
     i %= var2;
     i <<= 3;
-    i >>= 2;
+    i >>= 2; // no error, can't overflow
 
     // no errors
     i |= 1;
@@ -100,3 +100,16 @@ pub fn baz(x: i32, y: &i32) -> i32 {
 pub fn qux(x: i32, y: i32) -> i32 {
     (&x + &y)
 }
+
+pub fn value_range(x: u8) -> u8 {
+    // no error, the result provably fits
+    let a = x.min(100) + 100;
+    let b = (x % 16) * 4;
+    let c = if x < 10 { x + 1 } else { 0 };
+    a + b + c
+}
+
+pub fn next_index(i: usize, v: &[u32]) -> usize {
+    // no error, `i` is smaller than `usize::MAX`
+    if i < v.len() { i + 1 } else { 0 }
+}
@@ -1,6 +1,6 @@
 // run-rustfix
 
-#![allow(unused)]
+#![allow(unused, clippy::redundant_clone)]
 
 use std::collections::HashSet;
 use std::collections::VecDeque;
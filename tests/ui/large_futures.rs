@@ -0,0 +1,21 @@
+// edition:2018
+#![warn(clippy::large_futures)]
+
+async fn big_fut(_arg: [u8; 1024 * 16]) {}
+
+pub async fn wait() {
+    let f = async {
+        big_fut([0u8; 1024 * 16]).await;
+    };
+    f.await
+}
+
+async fn small_fut(_arg: [u8; 1024]) {}
+
+pub async fn not_linted() {
+    small_fut([0u8; 1024]).await;
+    // already boxed, only the pointer is moved
+    Box::pin(big_fut([0u8; 1024 * 16])).await;
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+// run-rustfix
+
+#![warn(clippy::to_string_in_log_args)]
+
+use std::net::{IpAddr, Ipv4Addr};
+
+fn main() {
+    let addr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let name = "server";
+
+    log::info!("listening on {}", addr.to_string());
+    log::warn!(target: "net", "connection to {} lost", addr.to_string());
+    log::log!(log::Level::Error, "{} stopped", name.to_string());
+    tracing::info!("listening on {}", addr.to_string());
+    tracing::debug!(port = 80, "listening on {}", addr.to_string());
+
+    // not linted
+    log::info!("listening on {:>20}", addr.to_string());
+    log::info!("listening on {:?}", addr.to_string());
+    tracing::info!(addr = %addr.to_string(), "listening");
+}
@@ -0,0 +1,15 @@
+#![warn(clippy::format_in_log_args)]
+
+fn main() {
+    let method = "GET";
+    let path = "/";
+
+    log::info!("request: {}", format!("{} {}", method, path));
+    log::debug!(target: "http", "request: {}", format!("{} {}", method, path));
+    tracing::info!("request: {}", format!("{} {}", method, path));
+    tracing::warn!(status = 404, "request: {}", format!("{} {}", method, path));
+
+    // not linted
+    log::info!("request: {:>20}", format!("{} {}", method, path));
+    log::info!("request: {}", format_args!("{} {}", method, path));
+}
@@ -0,0 +1,69 @@
+#![warn(clippy::panic_in_no_panic_fn)]
+
+fn helper(v: &[u32]) -> u32 {
+    v[0]
+}
+
+fn leaf(x: Option<u32>) -> u32 {
+    x.unwrap()
+}
+
+fn middle(x: Option<u32>) -> u32 {
+    leaf(x)
+}
+
+fn safe(v: &[u32]) -> u32 {
+    v.first().copied().unwrap_or(0)
+}
+
+#[clippy::no_panic]
+fn direct(a: u32, b: u32) -> u32 {
+    if a == 0 {
+        panic!("zero");
+    }
+    a + b
+}
+
+#[clippy::no_panic]
+fn indirect(v: &[u32], x: Option<u32>) -> u32 {
+    let first = helper(v);
+    let second = middle(x);
+    first.wrapping_add(second).wrapping_add(safe(v))
+}
+
+// shares `middle` with `indirect`
+#[clippy::no_panic]
+fn also_indirect(x: Option<u32>) -> u32 {
+    middle(x)
+}
+
+struct Wrapper(Option<u32>);
+
+impl From<Wrapper> for u32 {
+    fn from(w: Wrapper) -> u32 {
+        w.0.unwrap()
+    }
+}
+
+#[clippy::no_panic]
+fn through_trait(v: &[u32], w: Wrapper) -> u32 {
+    let mut acc = 0;
+    for x in v {
+        acc ^= x;
+    }
+    acc ^ u32::from(w)
+}
+
+// not reachable from a `no_panic` function
+fn unchecked(v: &[u32]) -> u32 {
+    v[1] + 1
+}
+
+fn main() {
+    let v = [1, 2];
+    direct(1, 2);
+    indirect(&v, Some(1));
+    also_indirect(Some(1));
+    through_trait(&v, Wrapper(Some(1)));
+    unchecked(&v);
+}
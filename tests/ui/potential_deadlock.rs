@@ -0,0 +1,118 @@
+#![warn(clippy::potential_deadlock)]
+
+use std::sync::{Mutex, RwLock};
+
+static CONFIG: RwLock<u32> = RwLock::new(0);
+static LOG: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+pub struct Bank {
+    checking: Mutex<u64>,
+    savings: Mutex<u64>,
+}
+
+impl Bank {
+    pub fn to_savings(&self, amount: u64) {
+        let mut checking = self.checking.lock().unwrap();
+        let mut savings = self.savings.lock().unwrap();
+        *checking -= amount;
+        *savings += amount;
+    }
+
+    pub fn to_checking(&self, amount: u64) {
+        let mut savings = self.savings.lock().unwrap();
+        let mut checking = self.checking.lock().unwrap();
+        *savings -= amount;
+        *checking += amount;
+    }
+
+    // ok, the first guard is dropped at the end of the statement
+    pub fn total(&self) -> u64 {
+        let savings = *self.savings.lock().unwrap();
+        let checking = *self.checking.lock().unwrap();
+        savings + checking
+    }
+
+    fn log_savings(&self) {
+        let savings = self.savings.lock().unwrap();
+        LOG.lock().unwrap().push(*savings as u32);
+    }
+
+    pub fn log_while_locked(&self) {
+        let _savings = self.savings.lock().unwrap();
+        self.log_savings();
+    }
+
+    pub fn lock_twice(&self) {
+        let checking = self.checking.lock().unwrap();
+        let _checking_again = self.checking.lock().unwrap();
+        drop(checking);
+    }
+
+    // ok, the first guard is dropped explicitly
+    pub fn lock_after_drop(&self) {
+        let checking = self.checking.lock().unwrap();
+        drop(checking);
+        let _checking = self.checking.lock().unwrap();
+    }
+}
+
+pub fn log_config() {
+    let mut log = LOG.lock().unwrap();
+    log.push(*CONFIG.read().unwrap());
+}
+
+// a writer waiting for `CONFIG` blocks `log_config` while it holds `LOG`
+pub fn read_config_and_log() {
+    let config = CONFIG.read().unwrap();
+    LOG.lock().unwrap().push(*config);
+}
+
+// ok, two readers don't block each other
+pub fn read_config_twice() {
+    let _config = CONFIG.read().unwrap();
+    let _config_again = CONFIG.read().unwrap();
+}
+
+mod parking_lot_locks {
+    use parking_lot::{Mutex, RwLock};
+
+    pub struct Accounts {
+        balance: Mutex<u64>,
+        history: RwLock<Vec<u64>>,
+    }
+
+    impl Accounts {
+        pub fn deposit(&self, amount: u64) {
+            let mut balance = self.balance.lock();
+            *balance += amount;
+            self.history.write().push(amount);
+        }
+
+        pub fn replay(&self) {
+            let history = self.history.read();
+            let mut balance = self.balance.lock();
+            *balance = history.iter().sum();
+        }
+
+        pub fn lock_twice(&self) {
+            let _balance = self.balance.lock();
+            let _balance_again = self.balance.lock();
+        }
+
+        // ok, two readers don't block each other
+        pub fn read_twice(&self) {
+            let _history = self.history.read();
+            let _history_again = self.history.read();
+        }
+
+        // ok, the balances of two different accounts
+        pub fn transfer(from: &Accounts, to: &Accounts, amount: u64) {
+            let mut from_balance = from.balance.lock();
+            let mut to_balance = to.balance.lock();
+            *from_balance -= amount;
+            *to_balance += amount;
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,38 @@
+#![warn(clippy::provable_overflow)]
+
+pub fn max(x: u8) -> u8 {
+    x.max(200) + 100
+}
+
+pub fn min(x: u8) -> u8 {
+    x.min(10) - 20
+}
+
+pub fn mask(x: u8) -> u8 {
+    (x | 0x80) * 2
+}
+
+pub fn guard(x: u8) -> u8 {
+    if x > 250 { x + 10 } else { x }
+}
+
+pub fn early_return(x: u16) -> u16 {
+    if x < 60_000 {
+        return 0;
+    }
+    x + 10_000
+}
+
+pub fn may_overflow(x: u8, y: u8) -> u8 {
+    // Don't lint, these only overflow for some values.
+    let a = x.max(100) + 100;
+    let b = y.min(a) * 2;
+    a + b
+}
+
+pub fn fits(x: u32) -> u64 {
+    // Don't lint, the mathematical result fits in `u64`.
+    u64::from(x) * u64::from(x)
+}
+
+fn main() {}
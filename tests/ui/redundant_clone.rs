@@ -62,6 +62,9 @@ fn main() {
     clone_then_move_cloned();
     hashmap_neg();
     false_negative_5707();
+    to_vec();
+    clone_field();
+    clone_in_loop();
 }
 
 #[derive(Clone)]
@@ -239,3 +242,47 @@ fn false_negative_5707() {
     let _z = x.clone(); // pr 7346 can't lint on `x`
     drop(y);
 }
+
+fn to_vec() {
+    let v = vec![String::new()];
+    let _v = v.to_vec();
+}
+
+fn clone_field() {
+    struct S {
+        name: String,
+        id: u32,
+    }
+
+    let s = S {
+        name: String::new(),
+        id: 1,
+    };
+    let name = s.name.clone();
+    println!("{} {}", name, s.id);
+}
+
+fn clone_in_loop() {
+    fn takes_ref(_: &str) {}
+
+    let s = String::new();
+    for _ in 0..2 {
+        takes_ref(&s.clone());
+    }
+    drop(s);
+
+    // ok, `s` is cloned again in the next iteration
+    let s = String::new();
+    let mut v = Vec::new();
+    for _ in 0..2 {
+        v.push(s.clone());
+    }
+
+    // ok, the clone is mutated while `s` is cloned again in the next iteration
+    let s = String::new();
+    for _ in 0..2 {
+        let mut t = s.clone();
+        t.push('a');
+        takes_ref(&t);
+    }
+}
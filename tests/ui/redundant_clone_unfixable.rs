@@ -0,0 +1,15 @@
+#![warn(clippy::redundant_clone)]
+
+fn main() {
+    clone_into_as_ref();
+}
+
+fn clone_into_as_ref() {
+    fn takes_as_ref<T: AsRef<str>>(_: T) {}
+    fn takes_static<T: AsRef<str> + 'static>(_: T) {}
+
+    let s = String::new();
+    takes_as_ref(s.clone());
+    takes_static(s.clone()); // ok, `&String` isn't `'static`
+    drop(s);
+}
@@ -0,0 +1,52 @@
+#![allow(unused, clippy::never_loop)]
+#![warn(clippy::regex_creation_in_loops)]
+
+extern crate regex;
+
+use regex::{Regex, RegexSet};
+
+const PATTERN: &str = r"^\d+$";
+
+fn in_loops(lines: &[&str], patterns: &[&str]) {
+    for line in lines {
+        let re = Regex::new(r"^\d+$").unwrap();
+        let set = RegexSet::new(&[r"^\d+$", PATTERN]).unwrap();
+    }
+
+    let mut i = 0;
+    while i < 10 {
+        let re = regex::bytes::Regex::new(PATTERN).unwrap();
+        i += 1;
+    }
+
+    loop {
+        let re = regex::RegexBuilder::new(PATTERN).build().unwrap();
+        break;
+    }
+
+    // the pattern changes with every iteration
+    for pattern in patterns {
+        let re = Regex::new(pattern).unwrap();
+        let set = RegexSet::new(&[*pattern, PATTERN]).unwrap();
+    }
+}
+
+fn in_closures(lines: &[&str]) {
+    let count = lines
+        .iter()
+        .filter(|line| Regex::new(PATTERN).unwrap().is_match(line))
+        .count();
+
+    // not called for each item
+    let re = Some(PATTERN).map(|p| Regex::new(PATTERN).unwrap());
+    let make = || Regex::new(PATTERN).unwrap();
+
+    // the closure is only created, not called, in the loop
+    for line in lines {
+        let make = || Regex::new(PATTERN).unwrap();
+    }
+}
+
+fn main() {
+    let re = Regex::new(PATTERN).unwrap();
+}
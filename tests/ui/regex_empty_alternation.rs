@@ -0,0 +1,16 @@
+#![allow(unused)]
+#![warn(clippy::regex_empty_alternation)]
+
+extern crate regex;
+
+use regex::Regex;
+
+fn main() {
+    let trailing = Regex::new(r"^(foo|bar|)$");
+    let leading = Regex::new(r"|foo");
+    let middle = Regex::new(r"x(a||b)");
+
+    let ok = Regex::new(r"^(foo|bar)?$");
+    // covered by `trivial_regex`
+    let ok = Regex::new(r"|");
+}
@@ -0,0 +1,23 @@
+#![allow(unused)]
+#![warn(clippy::regex_redundant_class_ranges)]
+
+extern crate regex;
+
+use regex::Regex;
+
+fn main() {
+    let typo = Regex::new("[a-zA-z]+");
+    let duplicate = Regex::new("[abca]");
+    let contained = Regex::new(r"[0-9a-f5]");
+    let nested = Regex::new(r"x[a[b-db]]");
+
+    let word = Regex::new("[a-zA-Z0-9_]+");
+    let word_reordered = Regex::new("^[_0-9A-Za-z]*$");
+
+    // not overlapping
+    let ok = Regex::new("[a-zA-Z]+");
+    let ok = Regex::new("[a-c][c-d]");
+    // not the word class
+    let ok = Regex::new("[^a-zA-Z0-9_]");
+    let ok = Regex::new("[a-zA-Z0-9_-]");
+}
@@ -0,0 +1,54 @@
+#![allow(unused)]
+#![warn(clippy::invalid_regex, clippy::trivial_regex)]
+
+extern crate fancy_regex;
+extern crate regex;
+
+use regex::bytes::Regex as BRegex;
+use regex::{Regex, RegexSet};
+
+static OPENING_PAREN: &str = "(";
+static VALID: &str = r"[a-z]+@[a-z]+";
+static mut MUTABLE: &str = "(";
+
+fn statics() {
+    let some_regex = Regex::new(OPENING_PAREN);
+    let some_binary_regex = BRegex::new(OPENING_PAREN);
+    let set_error = RegexSet::new(&[OPENING_PAREN, VALID]);
+
+    let valid = Regex::new(VALID);
+    // the value of a `static mut` can change
+    let not_linted = unsafe { Regex::new(MUTABLE) };
+}
+
+fn concat() {
+    let concat_error = Regex::new(concat!("[a-z]+", "("));
+    let concat_trivial = Regex::new(concat!("^", "foobar", "$"));
+    let concat_valid = Regex::new(concat!("[a-z]+", "@", "[a-z]+"));
+}
+
+fn fancy() {
+    let fancy_error = fancy_regex::Regex::new("(");
+    let fancy_range_error = fancy_regex::Regex::new("[z-a]");
+
+    // syntax only supported by `fancy_regex`
+    let look_around = fancy_regex::Regex::new(r"(?<=a)b(?!c)");
+    let backreference = fancy_regex::Regex::new(r"(\w+) \1");
+    let atomic_group = fancy_regex::Regex::new(r"(?>a+)b");
+    let possessive = fancy_regex::Regex::new(r"a++b");
+    let keep_out = fancy_regex::Regex::new(r"a\Kb");
+    let continue_from = fancy_regex::Regex::new(r"\Ga");
+    let hex_digit = fancy_regex::Regex::new(r"\h+");
+    let escape = fancy_regex::Regex::new(r"\e");
+    let named_backreference = fancy_regex::Regex::new(r"(?<word>\w+) \k<word>");
+    let python_backreference = fancy_regex::Regex::new(r"(?P<word>\w+) (?P=word)");
+
+    // other errors are still reported
+    let unknown_escape = fancy_regex::Regex::new(r"\q");
+    let unknown_flag = fancy_regex::Regex::new(r"(?z)a");
+
+    // only `fancy_regex` supports look-around
+    let not_fancy = Regex::new(r"(?<=a)b");
+}
+
+fn main() {}
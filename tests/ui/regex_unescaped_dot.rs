@@ -0,0 +1,30 @@
+#![allow(unused)]
+#![warn(clippy::regex_unescaped_dot)]
+
+extern crate regex;
+
+use regex::Regex;
+
+fn main() {
+    let host = Regex::new(r"^(www.)?example.com$");
+    let upper = Regex::new(r"EXAMPLE.ORG");
+    let ip = Regex::new(r"^\d+.\d+.\d+.\d+$");
+    let ip_literal = Regex::new(r"192.168.0.1");
+
+    // escaped
+    let ok = Regex::new(r"^(www\.)?example\.com$");
+    let ok = Regex::new(r"^\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}$");
+    // not host names or addresses
+    let ok = Regex::new(r"a.b");
+    let ok = Regex::new(r"foo.comma");
+    let ok = Regex::new(r"\d+.\d+");
+    let ok = Regex::new(r".*\.com");
+}
+
+fn escapes() {
+    // the escapes shift the positions, so the whole literal is linted
+    let tab = Regex::new("\t^example.com$");
+    let escaped = Regex::new("^(www\\.)?example.com$");
+    // raw strings keep the positions, even with backslashes
+    let raw = Regex::new(r#"^"?example.com$"#);
+}
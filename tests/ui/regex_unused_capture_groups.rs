@@ -0,0 +1,41 @@
+#![allow(unused)]
+#![warn(clippy::regex_unused_capture_groups)]
+
+extern crate regex;
+
+use regex::Regex;
+
+fn only_matching(input: &str) {
+    let re = Regex::new(r"^(foo|bar)\d+$").unwrap();
+    if re.is_match(input) {}
+    let _ = re.find(input);
+
+    let named = Regex::new(r"(?P<number>\d+)").expect("valid regex");
+    let _ = named.find_iter(input).count();
+
+    let _ = Regex::new(r"(\w+)@").unwrap().is_match(input);
+    let _ = regex::bytes::Regex::new(r"(a)(?:b)").unwrap().is_match(b"");
+}
+
+fn captures_read(input: &str) {
+    let re = Regex::new(r"^(foo|bar)\d+$").unwrap();
+    if re.is_match(input) {
+        let _ = re.captures(input);
+    }
+
+    let re = Regex::new(r"(\d+)").unwrap();
+    let _ = re.replace_all(input, "<$1>");
+
+    // passed somewhere else
+    let re = Regex::new(r"(\d+)").unwrap();
+    let re_ref = &re;
+
+    // never used
+    let re = Regex::new(r"(\d+)").unwrap();
+
+    // no capture groups
+    let re = Regex::new(r"^(?:foo|bar)\d+$").unwrap();
+    if re.is_match(input) {}
+}
+
+fn main() {}
@@ -0,0 +1,84 @@
+// edition:2018
+#![warn(clippy::significant_drop_tightening)]
+#![allow(clippy::await_holding_lock)]
+
+use std::sync::Mutex;
+
+fn do_heavy_work(_: u32) {}
+
+async fn do_async_work() {}
+
+pub fn unnecessarily_long_lock(mutex: &Mutex<u32>) {
+    let lock = mutex.lock().unwrap();
+    let value = *lock;
+    do_heavy_work(value);
+}
+
+pub async fn held_across_await(mutex: &Mutex<u32>) {
+    let lock = mutex.lock().unwrap();
+    let value = *lock;
+    do_async_work().await;
+    do_heavy_work(value);
+}
+
+pub fn lock_queue_in_while_let(queue: &Mutex<Vec<u32>>) {
+    while let Some(value) = queue.lock().unwrap().pop() {
+        do_heavy_work(value);
+    }
+}
+
+pub fn borrowed_from_lock(mutex: &Mutex<Vec<u32>>) {
+    let lock = mutex.lock().unwrap();
+    let first = lock.first();
+    let value = first.copied().unwrap_or(0);
+    do_heavy_work(value);
+}
+
+// ok, a borrow of the guard is used until the end
+pub fn borrow_used_until_the_end(mutex: &Mutex<Vec<u32>>) -> u32 {
+    let lock = mutex.lock().unwrap();
+    let first = lock.first();
+    do_heavy_work(1);
+    first.copied().unwrap_or(0)
+}
+
+// ok, the guard is needed until the end
+pub fn used_until_the_end(mutex: &Mutex<u32>) {
+    let mut lock = mutex.lock().unwrap();
+    do_heavy_work(*lock);
+    *lock += 1;
+}
+
+// ok, the guard is returned
+pub fn used_in_tail_expr(mutex: &Mutex<u32>) -> u32 {
+    let lock = mutex.lock().unwrap();
+    do_heavy_work(*lock);
+    *lock
+}
+
+// ok, the guard protects the whole block
+pub fn critical_section(mutex: &Mutex<()>) {
+    let _guard = mutex.lock().unwrap();
+    do_heavy_work(1);
+    do_heavy_work(2);
+}
+
+// ok, the guard is already dropped
+pub fn explicitly_dropped(mutex: &Mutex<u32>) {
+    let lock = mutex.lock().unwrap();
+    let value = *lock;
+    drop(lock);
+    do_heavy_work(value);
+}
+
+// ok, the temporary is dropped before the loop body
+pub fn lock_in_block_in_while_let(queue: &Mutex<Vec<u32>>) {
+    while let Some(value) = {
+        let value = queue.lock().unwrap().pop();
+        value
+    } {
+        do_heavy_work(value);
+    }
+}
+
+fn main() {}
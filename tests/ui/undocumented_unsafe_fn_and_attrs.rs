@@ -0,0 +1,56 @@
+#![warn(clippy::undocumented_unsafe_blocks)]
+#![allow(clippy::missing_safety_doc, dead_code)]
+
+unsafe fn read(p: *const u32) -> u32 {
+    *p
+}
+
+unsafe fn read_documented(p: *const u32) -> u32 {
+    // SAFETY: the caller guarantees `p` is valid for reads
+    *p
+}
+
+unsafe fn several_statements(p: *mut u32) -> u32 {
+    let value = *p;
+    // SAFETY: the caller guarantees `p` is valid for writes
+    *p = value + 1;
+    *p + read(p)
+}
+
+unsafe fn no_unsafe_operations(x: u32) -> u32 {
+    x + 1
+}
+
+#[deny(unsafe_op_in_unsafe_fn)]
+unsafe fn unsafe_op_denied(p: *const u32) -> u32 {
+    // SAFETY: the caller guarantees `p` is valid for reads
+    unsafe { *p }
+}
+
+struct S(*const u32);
+
+impl S {
+    unsafe fn get(&self) -> u32 {
+        *self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn no_comment() {}
+
+// SAFETY: there is no other symbol named `with_comment`
+#[no_mangle]
+pub extern "C" fn with_comment() {}
+
+#[export_name = "exported"]
+pub extern "C" fn export_name() {}
+
+#[link_section = ".custom"]
+static IN_SECTION: u32 = 0;
+
+/// Documented.
+// SAFETY: `.other` is not used by anything else
+#[link_section = ".other"]
+static IN_OTHER_SECTION: u32 = 0;
+
+fn main() {}
@@ -0,0 +1,75 @@
+// run-rustfix
+
+#![feature(custom_inner_attributes)]
+#![warn(clippy::uninlined_format_args)]
+
+use std::fmt::Write;
+
+macro_rules! my_println {
+    ($x:expr) => {
+        println!("{}", $x)
+    };
+}
+
+fn main() {
+    let local_i32 = 1;
+    let local_f64 = 2.0;
+    let width = 5;
+    let prec = 2;
+    let name = "world";
+    let mut buf = String::new();
+
+    println!("val='{}'", local_i32);
+    println!("{} {}", local_i32, local_f64);
+    println!("{:?}", name);
+    println!("{0} {0}", local_i32);
+    println!("{1} {0}", local_i32, local_f64);
+    println!("{:>1$}", name, width);
+    println!("{:.1$}", local_f64, prec);
+    println!("{:01$}", local_i32, width);
+    println!("{:>width$.prec$}", local_f64, width = width, prec = prec);
+    println!("{}\t|", local_i32);
+    println!("\u{1F600} {}", local_i32);
+    println!("\u{e9}{}", local_i32);
+    println!("\x41 {} é {}", local_i32, local_f64);
+    println!(
+        "{} \
+        {}",
+        local_i32, local_f64
+    );
+    let _ = format!("hello {}", name);
+    write!(buf, "{}", local_i32).unwrap();
+    writeln!(buf, "{}", local_i32).unwrap();
+    assert!(local_i32 > 0, "val={}", local_i32);
+    assert_eq!(local_i32, 1, "{}", local_i32);
+    if local_i32 == 0 {
+        panic!("val={}", local_i32);
+    }
+
+    // only the local variables are inlined
+    println!("{} {}", local_i32, local_f64 + 1.0);
+    println!("{} {}", local_f64 + 1.0, local_i32);
+    println!("{1} {} {}", local_i32, local_i32 + 1);
+    println!("{:1$}", local_i32 + 1, width);
+
+    // not linted
+    println!("{}", local_i32 + 1);
+    println!("{}", std::f64::consts::PI);
+    println!("{local_i32}");
+    println!("{:.*}", prec, local_f64);
+    println!(r"{}", local_i32);
+    println!(concat!("{}", "!"), local_i32);
+    my_println!(local_i32);
+}
+
+fn _under_msrv() {
+    #![clippy::msrv = "1.57"]
+    let local_i32 = 1;
+    println!("{}", local_i32);
+}
+
+fn _meets_msrv() {
+    #![clippy::msrv = "1.58"]
+    let local_i32 = 1;
+    println!("{}", local_i32);
+}
@@ -0,0 +1,15 @@
+// run-rustfix
+// edition:2018
+
+#![warn(clippy::uninlined_format_args)]
+
+fn main() {
+    let local_i32 = 1;
+    println!("{}", local_i32);
+
+    // `panic!("{local_i32}")` doesn't format its message before Rust 2021
+    if local_i32 == 0 {
+        panic!("{}", local_i32);
+    }
+    assert!(local_i32 > 0, "{}", local_i32);
+}
@@ -0,0 +1,36 @@
+#![warn(clippy::unnecessary_safety_comment)]
+#![allow(clippy::let_unit_value, clippy::missing_safety_doc, unused_unsafe)]
+
+unsafe fn read(p: *const u32) -> u32 {
+    // SAFETY: the caller guarantees `p` is valid for reads
+    *p
+}
+
+fn main() {
+    let x = 42u32;
+    let p = &x as *const u32;
+
+    // SAFETY: `p` points to `x`
+    let a = unsafe { *p };
+
+    // SAFETY: `p` points to `x`
+    let b = unsafe { read(p) };
+
+    // SAFETY: this doesn't do anything unsafe
+    let c = x + 1;
+
+    // SAFETY: nothing to see here
+    let d = unsafe { x + 1 };
+
+    // SAFETY: `p` points to `x`
+    let e = unsafe {
+        // SAFETY: `p` points to `x`
+        let v = *p;
+        v + 1
+    };
+
+    // This is not a safety comment
+    let f = x + 2;
+
+    let _ = (a, b, c, d, e, f);
+}
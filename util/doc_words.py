@@ -0,0 +1,116 @@
+#!/usr/bin/env python
+
+# Generates the word list of the `doc_spelling` lint, `clippy_lints/src/doc/words.txt`, from the
+# documentation of published crates.
+#
+# Usage: util/doc_words.py <directory with .crate archives>...
+#
+# The list was generated from the crates.io cache of a local Cargo registry, i.e.
+# `~/.cargo/registry/cache/*`. Only the latest version of each crate is read. The words are taken
+# from the doc comments of the Rust sources and from the READMEs, without code blocks, code spans,
+# identifiers, paths and URLs. A word is kept if it's used by at least `MIN_CRATES` crates and isn't
+# in `util/etc/doc-words-misspellings.txt`. The list is sorted alphabetically, and only has single
+# words, no text of the crates, so it doesn't carry their licenses.
+
+import glob
+import multiprocessing
+import os
+import re
+import sys
+import tarfile
+import logging as log
+log.basicConfig(level=log.INFO, format='%(levelname)s: %(message)s')
+
+MIN_CRATES = 5
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+MISSPELLINGS = os.path.join(ROOT, 'util', 'etc', 'doc-words-misspellings.txt')
+OUTPUT = os.path.join(ROOT, 'clippy_lints', 'src', 'doc', 'words.txt')
+HEADER = '''\
+# English words for `DOC_SPELLING`, one per line, generated by `util/doc_words.py`: the words used
+# in the documentation of at least {} crates of the crates.io registry, without the misspellings in
+# `util/etc/doc-words-misspellings.txt`. See the script for the details, and rerun it instead of
+# editing this file.
+'''
+
+CRATE = re.compile(r'^(.*)-(\d+)\.(\d+)\.(\d+)(.*)\.crate$')
+CODE_BLOCK = re.compile(r'```.*?```', re.S)
+CODE_SPAN = re.compile(r'`[^`\n]*`')
+LINK_TARGET = re.compile(r'\]\([^)]*\)')
+NOT_PROSE = re.compile(r'[0-9_:/\\@#<>=`{}\[\]()&$%|~^+]')
+WORD = re.compile(r"[A-Za-z]+(?:'[A-Za-z]+)?")
+
+
+def latest_versions(dirs):
+    crates = {}
+    for path in (path for d in dirs for path in glob.glob(os.path.join(d, '*.crate'))):
+        m = CRATE.match(os.path.basename(path))
+        if not m:
+            continue
+        name, major, minor, patch, rest = m.groups()
+        version = (int(major), int(minor), int(patch), rest)
+        if name not in crates or crates[name][0] < version:
+            crates[name] = (version, path)
+    return sorted(path for _, path in crates.values())
+
+
+def words_of(text):
+    text = CODE_SPAN.sub(' ', CODE_BLOCK.sub(' ', text))
+    words = set()
+    for token in text.split():
+        token = token.strip('.,;:!?()[]{}"*_`<>')
+        # Identifiers, paths, URLs and mixed case names like `GitHub`
+        if not token or NOT_PROSE.search(token) or any(c.isupper() for c in token[1:]):
+            continue
+        for part in token.split('-'):
+            if len(part) > 1 and WORD.fullmatch(part):
+                words.add(part.lower())
+    return words
+
+
+def crate_words(path):
+    words = set()
+    try:
+        with tarfile.open(path) as archive:
+            for member in archive.getmembers():
+                if not member.isfile() or member.size > 400000:
+                    continue
+                name = member.name.lower()
+                if name.endswith('.rs'):
+                    source = archive.extractfile(member).read().decode('utf8', 'ignore')
+                    docs = (line.strip() for line in source.split('\n'))
+                    words |= words_of('\n'.join(line[3:] for line in docs if line.startswith(('///', '//!'))))
+                elif re.search(r'(^|/)readme(\.md)?$', name):
+                    readme = archive.extractfile(member).read().decode('utf8', 'ignore')
+                    words |= words_of(LINK_TARGET.sub(']', readme))
+    except (tarfile.TarError, OSError) as e:
+        log.warning('skipping %s: %s', path, e)
+    return words
+
+
+def main():
+    if len(sys.argv) < 2:
+        log.error('specify the directories with the .crate archives')
+        return
+
+    crates = latest_versions(sys.argv[1:])
+    log.info('reading %d crates', len(crates))
+    counts = {}
+    with multiprocessing.Pool() as pool:
+        for words in pool.imap_unordered(crate_words, crates, chunksize=20):
+            for word in words:
+                counts[word] = counts.get(word, 0) + 1
+
+    with open(MISSPELLINGS) as f:
+        misspellings = {line.strip() for line in f if line.strip() and not line.startswith('#')}
+    words = sorted(
+        word for word, count in counts.items()
+        if count >= MIN_CRATES and word.isascii() and word not in misspellings
+    )
+    with open(OUTPUT, 'w') as f:
+        f.write(HEADER.format(MIN_CRATES))
+        f.write('\n'.join(words) + '\n')
+    log.info('wrote %d words to %s', len(words), OUTPUT)
+
+
+if __name__ == '__main__':
+    main()